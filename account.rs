@@ -0,0 +1,65 @@
+use std::rand::{Rng, OSRng};
+use std::vec;
+use crypto;
+
+static SALT_LENGTH: uint = 16;
+static HASH_LENGTH: uint = 32;
+static DEFAULT_ITERATIONS: uint = 10000;
+
+/// A user allowed to log in from a remote. Passwords are stored as salted
+/// PBKDF2-HMAC-SHA256 hashes.
+#[deriving(Clone)]
+pub struct Account {
+    id: u64,
+    name: ~str,
+    password_hash: ~[u8],
+    salt: ~[u8],
+    iterations: uint,
+    certificate_fingerprint: Option<~[u8]>, // logs remotes presenting this certificate in
+    unix_uid: Option<u32> // logs remotes connecting over the Unix socket as this user in
+}
+
+impl Account {
+    /// Creates an account with a freshly salted hash of `password`. The id is assigned
+    /// when it is stored.
+    pub fn new(name: ~str, password: &str) -> Account {
+        let mut salt = vec::from_elem(SALT_LENGTH, 0u8);
+        OSRng::new().fill_bytes(salt);
+        Account {
+            id: 0,
+            name: name,
+            password_hash: crypto::pbkdf2_sha256(password.as_bytes(), salt, DEFAULT_ITERATIONS, HASH_LENGTH),
+            salt: salt,
+            iterations: DEFAULT_ITERATIONS,
+            certificate_fingerprint: None,
+            unix_uid: None
+        }
+    }
+
+    pub fn check_password(&self, password: &str) -> bool {
+        let hash = crypto::pbkdf2_sha256(password.as_bytes(), self.salt, self.iterations,
+                                         self.password_hash.len());
+        crypto::constant_time_eq(hash, self.password_hash)
+    }
+}
+
+/// Checks a login as the account found by its name, if any. Logins as unknown names are
+/// hashed against a dummy salt, so they take as long to fail as wrong passwords do.
+pub fn check_login(account: Option<&Account>, password: &str) -> bool {
+    match account {
+        Some(account) => account.check_password(password),
+        None => {
+            let salt = [0u8, ..SALT_LENGTH];
+            crypto::pbkdf2_sha256(password.as_bytes(), salt, DEFAULT_ITERATIONS, HASH_LENGTH);
+            false
+        }
+    }
+}
+
+#[test]
+fn test_check_login() {
+    let account = Account::new(~"q2", "hunter2");
+    assert!(check_login(Some(&account), "hunter2"));
+    assert!(!check_login(Some(&account), "hunter3"));
+    assert!(!check_login(None, "hunter2"));
+}
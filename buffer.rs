@@ -11,7 +11,11 @@ pub enum Role {
 #[deriving(Clone)]
 pub struct Message {
     id: u64,
-    time_ns: u64,
+    time_ns: u64, // server time if known, otherwise receive time
+    received_ns: u64, // local receive time
+    msgid: Option<~str>, // IRCv3 msgid tag
+    account: Option<~str>, // services account of the sender
+    prefix: Option<~str>, // nick!user@host of the sender
     contents: MessageContents
 }
 
@@ -22,9 +26,26 @@ fn time_now() -> u64 {
 
 impl Message {
     pub fn now(cont: MessageContents) -> Message {
+        let now = time_now();
         Message {
             id: 0,
-            time_ns: time_now(),
+            time_ns: now,
+            received_ns: now,
+            msgid: None,
+            account: None,
+            prefix: None,
+            contents: cont
+        }
+    }
+
+    pub fn at(time_ns: u64, cont: MessageContents) -> Message {
+        Message {
+            id: 0,
+            time_ns: time_ns,
+            received_ns: time_now(),
+            msgid: None,
+            account: None,
+            prefix: None,
             contents: cont
         }
     }
@@ -87,25 +108,58 @@ pub struct Buffer {
     id: u64,
     role: Role,
     stored_messages: u64,
+    autojoin: bool, // rejoin this channel on connect
+    key: Option<~str>, // channel key
+    last_read: Option<u64>, // id of the last message a remote has seen
     db: database::Handle
 }
 
 impl Buffer {
-    pub fn create_repr(id: u64, role: Role, stored_messages: u64, db: database::Handle) -> Buffer {
+    pub fn create_repr(id: u64, role: Role, stored_messages: u64, autojoin: bool, key: Option<~str>,
+                       last_read: Option<u64>, db: database::Handle) -> Buffer {
         Buffer {
             id: id,
             role: role,
             stored_messages: stored_messages,
+            autojoin: autojoin,
+            key: key,
+            last_read: last_read,
             db: db
         }
     }
 
+    pub fn set_autojoin(&mut self, autojoin: bool, key: Option<~str>) {
+        self.db.update_buffer_autojoin(self.id, autojoin, &key);
+        self.autojoin = autojoin;
+        self.key = key;
+    }
+
+    pub fn set_last_read(&mut self, last_read: u64) {
+        self.db.update_buffer_last_read(self.id, last_read);
+        self.last_read = Some(last_read);
+    }
+
+    /// Number of messages after the last read one
+    pub fn unread_count(&mut self) -> u64 {
+        self.db.count_messages_after(self.id, self.last_read)
+    }
+
     pub fn add(&mut self, msg: Message, cb: |Message|) {
         let msg = self.db.create_message(self.id, msg);
         cb(msg);
         self.stored_messages += 1;
     }
 
+    /// Time of the newest stored message
+    pub fn latest_time(&mut self) -> Option<u64> {
+        self.db.latest_message_time(self.id)
+    }
+
+    /// Whether an equal message is already stored, for deduplicating server history
+    pub fn contains(&mut self, msg: &Message) -> bool {
+        self.db.message_exists(self.id, msg)
+    }
+
     pub fn fetch_message_range(&mut self, count: uint, before_id: Option<u64>) -> ~[Message] {
         match before_id {
             Some(id) => self.db.fetch_messages_before(self.id, id, count),
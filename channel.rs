@@ -0,0 +1,67 @@
+use collections::HashMap;
+
+/// What we know about another user sharing a channel with us
+#[deriving(Clone)]
+pub struct User {
+    nick: ~str,
+    ident: Option<~str>,
+    host: Option<~str>,
+    realname: Option<~str>, // from extended-join or SETNAME
+    account: Option<~str>, // services account, None if not logged in or unknown
+    away: Option<~str> // away message, None if present
+}
+
+impl User {
+    pub fn new(nick: ~str) -> User {
+        User {
+            nick: nick,
+            ident: None,
+            host: None,
+            realname: None,
+            account: None,
+            away: None
+        }
+    }
+
+    /// Updates the nick, ident and host from a `nick!user@host` prefix. Parts missing
+    /// from the prefix are left as they were. Returns whether anything changed.
+    pub fn update_from_prefix(&mut self, prefix: &str) -> bool {
+        let (nick, ident, host) = split_prefix(prefix);
+        let changed = self.nick != nick || (ident.is_some() && ident != self.ident) ||
+                      (host.is_some() && host != self.host);
+        self.nick = nick;
+        if ident.is_some() { self.ident = ident; }
+        if host.is_some() { self.host = host; }
+        changed
+    }
+}
+
+/// A channel we are on. Members are keyed by lowercase nickname and map to their
+/// membership prefixes, e.g. "@+" with multi-prefix.
+pub struct Channel {
+    members: HashMap<~str, ~str>,
+    topic: Option<~str>,
+    names_complete: bool // false while a NAMES reply is coming in
+}
+
+impl Channel {
+    pub fn new() -> Channel {
+        Channel {
+            members: HashMap::new(),
+            topic: None,
+            names_complete: true
+        }
+    }
+}
+
+/// Splits a `nick!user@host` prefix into its parts.
+pub fn split_prefix(prefix: &str) -> (~str, Option<~str>, Option<~str>) {
+    let (rest, host) = match prefix.find('@') {
+        Some(i) => (prefix.slice_to(i), Some(prefix.slice_from(i + 1).to_owned())),
+        None    => (prefix, None)
+    };
+    match rest.find('!') {
+        Some(i) => (rest.slice_to(i).to_owned(), Some(rest.slice_from(i + 1).to_owned()), host),
+        None    => (rest.to_owned(), None, host)
+    }
+}
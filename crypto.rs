@@ -0,0 +1,231 @@
+// SHA-256 (FIPS 180-4), HMAC (RFC 2104) and PBKDF2 (RFC 2898) for password hashing, and
+// SHA-1 for the WebSocket handshake.
+//
+// The standard library of this compiler has no hashing beyond SipHash, and these are
+// short and fully specified, so they live here rather than in more bindings to OpenSSL,
+// which stays an implementation detail of tls.rs. None of it handles secrets in a way
+// that needs to be constant time except the final comparison, `constant_time_eq`. Each
+// function is checked against the published test vectors below.
+
+static K: [u32, ..64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2
+];
+
+static BLOCK_SIZE: uint = 64;
+
+fn rotr(x: u32, n: u32) -> u32 {
+    (x >> n) | (x << (32 - n))
+}
+
+pub fn sha256(data: &[u8]) -> ~[u8] {
+    let mut h: [u32, ..8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
+                             0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+    let msg = pad(data);
+    for chunk in msg.chunks(BLOCK_SIZE) {
+        let mut w = [0u32, ..64];
+        for i in range(0u, 16) {
+            w[i] = (chunk[i * 4] as u32 << 24) | (chunk[i * 4 + 1] as u32 << 16) |
+                   (chunk[i * 4 + 2] as u32 << 8) | chunk[i * 4 + 3] as u32;
+        }
+        for i in range(16u, 64) {
+            let s0 = rotr(w[i - 15], 7) ^ rotr(w[i - 15], 18) ^ (w[i - 15] >> 3);
+            let s1 = rotr(w[i - 2], 17) ^ rotr(w[i - 2], 19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16] + s0 + w[i - 7] + s1;
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh) =
+            (h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7]);
+        for i in range(0u, 64) {
+            let s1 = rotr(e, 6) ^ rotr(e, 11) ^ rotr(e, 25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = hh + s1 + ch + K[i] + w[i];
+            let s0 = rotr(a, 2) ^ rotr(a, 13) ^ rotr(a, 22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0 + maj;
+            hh = g;
+            g = f;
+            f = e;
+            e = d + t1;
+            d = c;
+            c = b;
+            b = a;
+            a = t1 + t2;
+        }
+        h[0] += a; h[1] += b; h[2] += c; h[3] += d;
+        h[4] += e; h[5] += f; h[6] += g; h[7] += hh;
+    }
+
+    let mut out = ~[];
+    for &x in h.iter() {
+        out.push_all([(x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8]);
+    }
+    out
+}
+
+// Pads a message for SHA-1 and SHA-256, ending with its length in bits
+fn pad(data: &[u8]) -> ~[u8] {
+    let mut msg = data.to_owned();
+    let bit_len = data.len() as u64 * 8;
+    msg.push(0x80);
+    while msg.len() % BLOCK_SIZE != BLOCK_SIZE - 8 {
+        msg.push(0);
+    }
+    for i in range(0u64, 8) {
+        msg.push((bit_len >> (56 - i * 8)) as u8);
+    }
+    msg
+}
+
+/// SHA-1 (FIPS 180-4). Only for protocols that require it, it is not collision resistant.
+pub fn sha1(data: &[u8]) -> ~[u8] {
+    let mut h: [u32, ..5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let msg = pad(data);
+    for chunk in msg.chunks(BLOCK_SIZE) {
+        let mut w = [0u32, ..80];
+        for i in range(0u, 16) {
+            w[i] = (chunk[i * 4] as u32 << 24) | (chunk[i * 4 + 1] as u32 << 16) |
+                   (chunk[i * 4 + 2] as u32 << 8) | chunk[i * 4 + 3] as u32;
+        }
+        for i in range(16u, 80) {
+            w[i] = rotr(w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16], 31);
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e) = (h[0], h[1], h[2], h[3], h[4]);
+        for i in range(0u, 80) {
+            let (f, k) = match i {
+                0..19  => ((b & c) | (!b & d), 0x5a827999),
+                20..39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _      => (b ^ c ^ d, 0xca62c1d6)
+            };
+            let t = rotr(a, 27) + f + e + k + w[i];
+            e = d;
+            d = c;
+            c = rotr(b, 2);
+            b = a;
+            a = t;
+        }
+        h[0] += a; h[1] += b; h[2] += c; h[3] += d; h[4] += e;
+    }
+
+    let mut out = ~[];
+    for &x in h.iter() {
+        out.push_all([(x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8]);
+    }
+    out
+}
+
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> ~[u8] {
+    let mut key = if key.len() > BLOCK_SIZE { sha256(key) } else { key.to_owned() };
+    key.grow(BLOCK_SIZE - key.len(), &0u8);
+
+    let mut inner: ~[u8] = key.iter().map(|&b| b ^ 0x36).collect();
+    inner.push_all(message);
+    let mut outer: ~[u8] = key.iter().map(|&b| b ^ 0x5c).collect();
+    outer.push_all(sha256(inner));
+    sha256(outer)
+}
+
+/// Derives a `length` byte key from `password` with PBKDF2-HMAC-SHA256.
+pub fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: uint, length: uint) -> ~[u8] {
+    let mut out = ~[];
+    let mut block = 1u32;
+    while out.len() < length {
+        let mut s = salt.to_owned();
+        s.push_all([(block >> 24) as u8, (block >> 16) as u8, (block >> 8) as u8, block as u8]);
+        let mut u = hmac_sha256(password, s);
+        let mut t = u.clone();
+        for _ in range(1, iterations) {
+            u = hmac_sha256(password, u);
+            for (x, y) in t.mut_iter().zip(u.iter()) {
+                *x ^= *y;
+            }
+        }
+        out.push_all(t);
+        block += 1;
+    }
+    out.truncate(length);
+    out
+}
+
+/// Compares in time depending only on the length, for checking secrets.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (&x, &y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+fn hex(data: &[u8]) -> ~str {
+    use serialize::hex::ToHex;
+    data.to_hex()
+}
+
+#[test]
+fn test_sha256() {
+    assert_eq!(hex(sha256(bytes!(""))), ~"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert_eq!(hex(sha256(bytes!("abc"))), ~"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(hex(sha256(bytes!("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"))),
+               ~"248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+}
+
+#[test]
+fn test_sha1() {
+    assert_eq!(hex(sha1(bytes!(""))), ~"da39a3ee5e6b4b0d3255bfef95601890afd80709");
+    assert_eq!(hex(sha1(bytes!("abc"))), ~"a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(hex(sha1(bytes!("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"))),
+               ~"84983e441c3bd26ebaae4aa1f95129e5e54670f1");
+}
+
+#[test]
+fn test_hmac_sha256() {
+    // RFC 4231 test cases 1, 2 and 6, the last with a key longer than a block
+    assert_eq!(hex(hmac_sha256([0x0bu8, ..20], bytes!("Hi There"))),
+               ~"b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7");
+    assert_eq!(hex(hmac_sha256(bytes!("Jefe"), bytes!("what do ya want for nothing?"))),
+               ~"5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+    assert_eq!(hex(hmac_sha256([0xaau8, ..131], bytes!("Test Using Larger Than Block-Size Key - Hash Key First"))),
+               ~"60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+}
+
+#[test]
+fn test_pbkdf2_sha256() {
+    assert_eq!(hex(pbkdf2_sha256(bytes!("password"), bytes!("salt"), 1, 32)),
+               ~"120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b");
+    assert_eq!(hex(pbkdf2_sha256(bytes!("password"), bytes!("salt"), 2, 32)),
+               ~"ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43");
+    assert_eq!(hex(pbkdf2_sha256(bytes!("password"), bytes!("salt"), 4096, 20)),
+               ~"c5e478d59288c841aa530db6845c4c8d962893a0");
+    assert_eq!(hex(pbkdf2_sha256(bytes!("password"), bytes!("salt"), 4096, 32)),
+               ~"c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a");
+    // The RFC 6070 inputs, with output spanning two blocks and with NUL bytes
+    assert_eq!(hex(pbkdf2_sha256(bytes!("passwordPASSWORDpassword"), bytes!("saltSALTsaltSALTsaltSALTsaltSALTsalt"),
+                                 4096, 40)),
+               ~"348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9");
+    assert_eq!(hex(pbkdf2_sha256(bytes!("pass", 0, "word"), bytes!("sa", 0, "lt"), 4096, 16)),
+               ~"89b69d0516f829893c696226650a8687");
+    // RFC 7914 section 11
+    assert_eq!(hex(pbkdf2_sha256(bytes!("passwd"), bytes!("salt"), 1, 64)),
+               ~"55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+                 49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783");
+    assert_eq!(hex(pbkdf2_sha256(bytes!("Password"), bytes!("NaCl"), 80000, 64)),
+               ~"4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56\
+                 a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d");
+}
+
+#[test]
+fn test_constant_time_eq() {
+    assert!(constant_time_eq(bytes!("abc"), bytes!("abc")));
+    assert!(!constant_time_eq(bytes!("abc"), bytes!("abd")));
+    assert!(!constant_time_eq(bytes!("abc"), bytes!("ab")));
+}
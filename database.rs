@@ -4,6 +4,10 @@ use remotecontrol;
 use session;
 use network;
 use buffer;
+use identity::Identity;
+use account::Account;
+use irc;
+use serialize::hex::{ToHex, FromHex};
 
 /*
     let db = sqlite3::open("asd.db").unwrap();
@@ -31,6 +35,39 @@ use buffer;
     }
     */
 
+// Columns added to tables that already existed, as (table, column, definition, SQL run
+// after adding it). Opening a database made by an earlier version adds whichever of
+// these it is missing.
+static ADDED_COLUMNS: &'static [(&'static str, &'static str, &'static str, &'static str)] = &[
+    ("buffer", "autojoin", "INTEGER NOT NULL DEFAULT 0", ""),
+    ("buffer", "channel_key", "STRING", ""),
+    ("network", "perform_delay", "INTEGER NOT NULL DEFAULT 0", ""),
+    ("network", "auth_service", "STRING", ""),
+    ("network", "auth_command", "STRING", ""),
+    ("network", "auth_password", "STRING", ""),
+    ("network", "identity_id", "INTEGER REFERENCES identity(id)", ""),
+    ("identity", "auto_away", "INTEGER NOT NULL DEFAULT 0", ""),
+    ("message", "received_ns", "INTEGER NOT NULL DEFAULT 0", "UPDATE message SET received_ns = time_ns;"),
+    ("message", "msgid", "STRING", ""),
+    ("message", "account", "STRING", ""),
+    ("message", "prefix", "STRING", ""),
+    ("network", "proxy_type", "INTEGER", ""),
+    ("network", "proxy_host", "STRING", ""),
+    ("network", "proxy_port", "INTEGER", ""),
+    ("network", "proxy_username", "STRING", ""),
+    ("network", "proxy_password", "STRING", ""),
+    ("network", "bind_address", "STRING", ""),
+    ("network", "webirc_password", "STRING", ""),
+    ("network", "webirc_gateway", "STRING", ""),
+    ("session", "webirc_hostname", "STRING", ""),
+    ("session", "webirc_ip", "STRING", ""),
+    ("session", "owner_id", "INTEGER REFERENCES user(id)", ""),
+    // ALTER TABLE can't add UNIQUE columns
+    ("user", "certificate_fingerprint", "STRING",
+     "CREATE UNIQUE INDEX user_certificate_fingerprint_index ON user(certificate_fingerprint);"),
+    ("user", "unix_uid", "INTEGER", "CREATE UNIQUE INDEX user_unix_uid_index ON user(unix_uid);")
+];
+
 pub struct Database {
     db: sqlite3::Database
 }
@@ -42,7 +79,50 @@ impl Database {
             Err(x) => return Err(~"failed to open database")
         };
 
-        Ok(Database { db: db })
+        let mut db = Database { db: db };
+        try!(db.migrate());
+        Ok(db)
+    }
+
+    /// Brings the tables of a database made by an earlier version up to date. Tables
+    /// that don't exist yet are left to `create_tables`.
+    fn migrate(&mut self) -> Result<(), ~str> {
+        let mut steps = ~[];
+        for &(table, column, definition, then) in ADDED_COLUMNS.iter() {
+            let columns = self.table_columns(table);
+            if !columns.is_empty() && !columns.iter().any(|c| c.as_slice() == column) {
+                steps.push(format!("ALTER TABLE {} ADD COLUMN {} {};", table, column, definition));
+                if !then.is_empty() {
+                    steps.push(then.to_owned());
+                }
+            }
+        }
+        if steps.is_empty() {
+            return Ok(());
+        }
+
+        self.db.exec("BEGIN;").unwrap();
+        for step in steps.iter() {
+            match self.db.exec(*step) {
+                Ok(_) => (),
+                Err(_) => {
+                    let error = format!("Migrating database failed: {}: {}", *step, self.db.get_errmsg());
+                    self.db.exec("ROLLBACK;").unwrap();
+                    return Err(error);
+                }
+            }
+        }
+        self.db.exec("COMMIT;").unwrap();
+        Ok(())
+    }
+
+    fn table_columns(&mut self, table: &str) -> ~[~str] {
+        let cursor = self.db.prepare(format!("PRAGMA table_info({});", table), &None).unwrap();
+        let mut columns = ~[];
+        while cursor.step() == sqlite3::SQLITE_ROW {
+            columns.push(cursor.get_text(1));
+        }
+        columns
     }
 
     pub fn create_tables(&mut self) {
@@ -58,28 +138,122 @@ impl Database {
         self.db.exec(q).unwrap();
     }
 
+    /// Stores a new account and makes it the owner of `sessions`. Nothing is stored if
+    /// any step fails, e.g. because the name is taken.
+    pub fn create_account(&mut self, account: &Account, sessions: &[u64]) -> Result<(), ~str> {
+        self.db.exec("BEGIN;").unwrap();
+
+        let cursor = self.db.prepare(
+            "INSERT INTO user (name, password_hash, salt, iterations) VALUES (?, ?, ?, ?);", &None
+            ).unwrap();
+        cursor.bind_param(1, &sqlite3::Text(account.name.clone()));
+        cursor.bind_param(2, &sqlite3::Text(account.password_hash.to_hex()));
+        cursor.bind_param(3, &sqlite3::Text(account.salt.to_hex()));
+        cursor.bind_param(4, &sqlite3::Integer(account.iterations as int));
+        if cursor.step() != sqlite3::SQLITE_DONE {
+            let error = format!("Creating account failed: {}", self.db.get_errmsg());
+            self.db.exec("ROLLBACK;").unwrap();
+            return Err(error);
+        }
+        let id = self.db.get_last_insert_rowid();
+
+        for &sid in sessions.iter() {
+            let cursor = self.db.prepare(
+                "UPDATE session SET owner_id = ? WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(id));
+            cursor.bind_param(2, &sqlite3::Integer64(sid as i64));
+            if cursor.step() != sqlite3::SQLITE_DONE {
+                let error = format!("Assigning session {} failed: {}", sid, self.db.get_errmsg());
+                self.db.exec("ROLLBACK;").unwrap();
+                return Err(error);
+            }
+        }
+
+        self.db.exec("COMMIT;").unwrap();
+        Ok(())
+    }
+
+    /// Sets or clears the client certificate that logs a remote in as account `name`.
+    /// Returns false if there is no such account.
+    pub fn set_certificate_fingerprint(&mut self, name: &str, fingerprint: Option<&[u8]>) -> bool {
+        let cursor = self.db.prepare(
+            "UPDATE user SET certificate_fingerprint = ? WHERE name = ?;", &None
+            ).unwrap();
+        match fingerprint {
+            Some(f) => cursor.bind_param(1, &sqlite3::Text(f.to_hex())),
+            None    => cursor.bind_param(1, &sqlite3::Null)
+        };
+        cursor.bind_param(2, &sqlite3::Text(name.to_owned()));
+        cursor.step();
+        self.db.get_changes() > 0
+    }
+
+    /// Sets or clears the local user whose connections to the Unix socket are logged in
+    /// as account `name`. Returns false if there is no such account.
+    pub fn set_unix_uid(&mut self, name: &str, uid: Option<u32>) -> bool {
+        let cursor = self.db.prepare(
+            "UPDATE user SET unix_uid = ? WHERE name = ?;", &None
+            ).unwrap();
+        match uid {
+            Some(uid) => cursor.bind_param(1, &sqlite3::Integer64(uid as i64)),
+            None      => cursor.bind_param(1, &sqlite3::Null)
+        };
+        cursor.bind_param(2, &sqlite3::Text(name.to_owned()));
+        cursor.step();
+        self.db.get_changes() > 0
+    }
+
     pub fn load_core(self) -> remotecontrol::RemoteControl {
         let mut handle = Handle { db: sync::RWArc::new(self) };
 
         let mut rc = remotecontrol::RemoteControl::new();
 
         handle.db.write(|db| {
+            {
+                let cursor = db.db.prepare(
+                    "SELECT id, name, password_hash, salt, iterations, certificate_fingerprint, unix_uid FROM user;", &None
+                    ).unwrap();
+                while cursor.step() == sqlite3::SQLITE_ROW {
+                    rc.add_account(Account {
+                        id: cursor.get_i64(0) as u64,
+                        name: cursor.get_text(1),
+                        password_hash: cursor.get_text(2).from_hex().unwrap(),
+                        salt: cursor.get_text(3).from_hex().unwrap(),
+                        iterations: cursor.get_int(4) as uint,
+                        certificate_fingerprint: match cursor.get_column_type(5) {
+                            sqlite3::SQLITE_NULL => None,
+                            _                    => cursor.get_text(5).from_hex().ok()
+                        },
+                        unix_uid: match cursor.get_column_type(6) {
+                            sqlite3::SQLITE_NULL => None,
+                            _                    => Some(cursor.get_i64(6) as u32)
+                        }
+                    });
+                }
+            }
+
             let mut ids = ~[];
             {
-                let cursor = db.db.prepare("SELECT id FROM session;", &None).unwrap();
+                let cursor = db.db.prepare("SELECT id, owner_id FROM session;", &None).unwrap();
                 // TODO error handling!!
                 while cursor.step() == sqlite3::SQLITE_ROW {
                     let session_id = cursor.get_i64(0) as u64;
-                    ids.push(session_id);
+                    let owner = match cursor.get_column_type(1) {
+                        sqlite3::SQLITE_NULL => None,
+                        _                    => Some(cursor.get_i64(1) as u64)
+                    };
+                    ids.push((session_id, owner));
                 }
             }
 
-            for id in ids.move_iter() {
-                let (mut session, session_tx, session_rx) = session::Session::new(handle.clone());
+            for (id, owner) in ids.move_iter() {
+                let (mut session, session_tx, session_rx) = session::Session::new(id, handle.clone());
 
                 load_session(&mut db.db, handle.clone(), id, &mut session);
 
                 rc.add_session(id, remotecontrol::SessionData { session: Some(session),
+                                                                owner: owner,
                                                                 tx: session_tx,
                                                                 rx: session_rx });
             }
@@ -92,9 +266,27 @@ impl Database {
 fn load_session(db: &mut sqlite3::Database, handle: Handle, id: u64, session: &mut session::Session) {
     let mut nets = ~[];
 
+    load_identities(db, id, session);
+
     {
         let cursor = db.prepare(
-            "SELECT id, server, nickname FROM network WHERE session_id = ?;", &None
+            "SELECT webirc_hostname, webirc_ip FROM session WHERE id = ?;", &None
+            ).unwrap();
+        cursor.bind_param(1, &sqlite3::Integer64(id as i64));
+        if cursor.step() == sqlite3::SQLITE_ROW {
+            session.client_address = match (cursor.get_column_type(0), cursor.get_column_type(1)) {
+                (sqlite3::SQLITE_NULL, _) | (_, sqlite3::SQLITE_NULL) => None,
+                _ => Some((cursor.get_text(0), cursor.get_text(1)))
+            };
+        }
+    }
+
+    {
+        let cursor = db.prepare(
+            "SELECT id, server, nickname, perform_delay, auth_service, auth_command, auth_password,
+                    identity_id, proxy_type, proxy_host, proxy_port, proxy_username, proxy_password,
+                    bind_address, webirc_password, webirc_gateway
+             FROM network WHERE session_id = ?;", &None
             ).unwrap();
         cursor.bind_param(1, &sqlite3::Integer64(id as i64));
 
@@ -109,11 +301,56 @@ fn load_session(db: &mut sqlite3::Database, handle: Handle, id: u64, session: &m
                 _                    => Some(cursor.get_text(2))
             };
 
+            let perform_delay = cursor.get_i64(3) as u64;
+            let service_auth = match cursor.get_column_type(4) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => Some(network::ServiceAuth { service: cursor.get_text(4),
+                                                                    command: cursor.get_text(5),
+                                                                    password: cursor.get_text(6) })
+            };
+            let identity_id = match cursor.get_column_type(7) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => Some(cursor.get_i64(7) as u64)
+            };
+            let proxy = match cursor.get_column_type(8) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => {
+                    let credentials = match cursor.get_column_type(11) {
+                        sqlite3::SQLITE_NULL => None,
+                        _                    => Some((cursor.get_text(11), cursor.get_text(12)))
+                    };
+                    Some(irc::proxy::Proxy {
+                        kind: if cursor.get_int(8) == 1 { irc::proxy::HttpConnect } else { irc::proxy::Socks5 },
+                        host: cursor.get_text(9),
+                        port: cursor.get_int(10) as u16,
+                        credentials: credentials
+                    })
+                }
+            };
+
+            let bind_address = match cursor.get_column_type(13) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => from_str(cursor.get_text(13))
+            };
+
+            let webirc = match cursor.get_column_type(14) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => Some(network::WebIrc { password: cursor.get_text(14),
+                                                               gateway: cursor.get_text(15) })
+            };
+
             let mut network = network::Network::new(network_id, handle.clone());
+            network.perform.delay_ms = perform_delay;
+            network.identity = identity_id.and_then(|iid| session.identities.find(&iid).map(|i| i.clone()));
             match (server, nickname) {
                 (Some(server), Some(nickname)) =>
                     network.config = Some(network::Configuration { server: server,
-                                                                    nickname: nickname }),
+                                                                    nickname: nickname,
+                                                                    service_auth: service_auth,
+                                                                    identity_id: identity_id,
+                                                                    proxy: proxy,
+                                                                    bind_address: bind_address,
+                                                                    webirc: webirc }),
                     _ => ()
             }
             nets.push(network);
@@ -122,11 +359,35 @@ fn load_session(db: &mut sqlite3::Database, handle: Handle, id: u64, session: &m
 
     for mut net in nets.move_iter() {
         load_buffers(db, handle.clone(), net.id, &mut net);
+        load_perform(db, net.id, &mut net);
+        load_buddies(db, net.id, &mut net);
 
         session.networks.insert(net.id, net);
     }
 }
 
+fn load_identities(db: &mut sqlite3::Database, sid: u64, session: &mut session::Session) {
+    let cursor = db.prepare(
+        "SELECT id, ident, realname, alternate_nicks, quit_message, part_message, away_message, auto_away
+         FROM identity WHERE session_id = ?;", &None
+        ).unwrap();
+    cursor.bind_param(1, &sqlite3::Integer64(sid as i64));
+
+    while cursor.step() == sqlite3::SQLITE_ROW {
+        let identity = Identity {
+            id: cursor.get_i64(0) as u64,
+            ident: cursor.get_text(1),
+            realname: cursor.get_text(2),
+            alternate_nicks: cursor.get_text(3).words().map(|n| n.to_owned()).collect(),
+            quit_message: cursor.get_text(4),
+            part_message: cursor.get_text(5),
+            away_message: cursor.get_text(6),
+            auto_away: cursor.get_int(7) != 0
+        };
+        session.identities.insert(identity.id, identity);
+    }
+}
+
 fn load_buffers(db: &mut sqlite3::Database, handle: Handle, nid: u64, network: &mut network::Network) {
     let cursor = db.prepare(
         include_str!("query_load_buffers.sql"), &None
@@ -138,6 +399,15 @@ fn load_buffers(db: &mut sqlite3::Database, handle: Handle, nid: u64, network: &
         let role = cursor.get_int(1);
         let name = cursor.get_text(2);
         let stored = cursor.get_i64(3);
+        let autojoin = cursor.get_int(4) != 0;
+        let key = match cursor.get_column_type(5) {
+            sqlite3::SQLITE_NULL => None,
+            _                    => Some(cursor.get_text(5))
+        };
+        let last_read = match cursor.get_column_type(6) {
+            sqlite3::SQLITE_NULL => None,
+            _                    => Some(cursor.get_i64(6) as u64)
+        };
 
         let role = match role {
             0 => buffer::Status,
@@ -146,11 +416,34 @@ fn load_buffers(db: &mut sqlite3::Database, handle: Handle, nid: u64, network: &
             _ => fail!("Invalid value in role enumeration in database")
         };
 
-        let buffer = buffer::Buffer::create_repr(buffer_id, role, stored as u64, handle.clone());
+        let buffer = buffer::Buffer::create_repr(buffer_id, role, stored as u64, autojoin, key, last_read,
+                                                 handle.clone());
         network.buffers.push(buffer);
     }
 }
 
+fn load_perform(db: &mut sqlite3::Database, nid: u64, network: &mut network::Network) {
+    let cursor = db.prepare(
+        "SELECT command FROM network_perform WHERE network_id = ? ORDER BY position;", &None
+        ).unwrap();
+    cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
+
+    while cursor.step() == sqlite3::SQLITE_ROW {
+        network.perform.commands.push(cursor.get_text(0));
+    }
+}
+
+fn load_buddies(db: &mut sqlite3::Database, nid: u64, network: &mut network::Network) {
+    let cursor = db.prepare(
+        "SELECT nick FROM network_buddy WHERE network_id = ?;", &None
+        ).unwrap();
+    cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
+
+    while cursor.step() == sqlite3::SQLITE_ROW {
+        network.buddies.push(cursor.get_text(0));
+    }
+}
+
 #[deriving(Clone)]
 pub struct Handle {
     db: sync::RWArc<Database>
@@ -177,23 +470,70 @@ impl Handle {
             }
             // Clone of role is superfluous :( can be removed once we get update and get mutexes
             // ^ FIXME
-            buffer::Buffer::create_repr(db.db.get_last_insert_rowid() as u64, role.clone(), 0, self.clone())
+            buffer::Buffer::create_repr(db.db.get_last_insert_rowid() as u64, role.clone(), 0, false, None,
+                                        None, self.clone())
+        })
+    }
+
+    pub fn update_buffer_autojoin(&mut self, bid: u64, autojoin: bool, key: &Option<~str>) {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "UPDATE buffer SET autojoin = ?, channel_key = ? WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer(if autojoin { 1 } else { 0 }));
+            match *key {
+                Some(ref key) => cursor.bind_param(2, &sqlite3::Text(key.clone())),
+                None          => cursor.bind_param(2, &sqlite3::Null)
+            };
+            cursor.bind_param(3, &sqlite3::Integer64(bid as i64));
+            cursor.step();
+        });
+    }
+
+    pub fn update_buffer_last_read(&mut self, bid: u64, last_read: u64) {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "UPDATE buffer SET last_read = ? WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(last_read as i64));
+            cursor.bind_param(2, &sqlite3::Integer64(bid as i64));
+            cursor.step();
+        });
+    }
+
+    /// Number of messages in the buffer newer than `last_read`, or all of them
+    pub fn count_messages_after(&mut self, bid: u64, last_read: Option<u64>) -> u64 {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "SELECT COUNT(*) FROM message WHERE buffer_id = ? AND id > ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(bid as i64));
+            cursor.bind_param(2, &sqlite3::Integer64(last_read.unwrap_or(0) as i64));
+            if cursor.step() == sqlite3::SQLITE_ROW { cursor.get_i64(0) as u64 } else { 0 }
         })
     }
 
     pub fn create_message(&mut self, bid: u64, mut message: buffer::Message) -> buffer::Message {
         self.db.write(|db| {
             let cursor = db.db.prepare(
-                "INSERT INTO message (buffer_id, time_ns, type) VALUES (?, ?, ?);", &None
+                "INSERT INTO message (buffer_id, time_ns, received_ns, type, msgid, account, prefix)
+                 VALUES (?, ?, ?, ?, ?, ?, ?);", &None
                 ).unwrap();
             cursor.bind_param(1, &sqlite3::Integer64(bid as i64));
             cursor.bind_param(2, &sqlite3::Integer64(message.time_ns as i64));
+            cursor.bind_param(3, &sqlite3::Integer64(message.received_ns as i64));
             let msg_type = match message.contents {
                 buffer::Information(..) => 0,
                 buffer::Join(..) => 1,
                 buffer::Privmsg(..) => 2
             };
-            cursor.bind_param(3, &sqlite3::Integer64(msg_type));
+            cursor.bind_param(4, &sqlite3::Integer64(msg_type));
+            for (i, field) in [&message.msgid, &message.account, &message.prefix].iter().enumerate() {
+                match **field {
+                    Some(ref text) => cursor.bind_param(5 + i as int, &sqlite3::Text(text.clone())),
+                    None           => cursor.bind_param(5 + i as int, &sqlite3::Null)
+                };
+            }
             cursor.step();
 
             let msg_id = db.db.get_last_insert_rowid();
@@ -232,15 +572,239 @@ impl Handle {
         message
     }
 
+    pub fn latest_message_time(&mut self, bid: u64) -> Option<u64> {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "SELECT MAX(time_ns) FROM message WHERE buffer_id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(bid as i64));
+            if cursor.step() != sqlite3::SQLITE_ROW {
+                return None;
+            }
+            match cursor.get_column_type(0) {
+                sqlite3::SQLITE_NULL => None,
+                _                    => Some(cursor.get_i64(0) as u64)
+            }
+        })
+    }
+
+    pub fn message_exists(&mut self, bid: u64, message: &buffer::Message) -> bool {
+        self.db.write(|db| {
+            match message.msgid {
+                Some(ref msgid) => {
+                    let cursor = db.db.prepare(
+                        "SELECT COUNT(*) FROM message WHERE buffer_id = ? AND msgid = ?;", &None
+                        ).unwrap();
+                    cursor.bind_param(1, &sqlite3::Integer64(bid as i64));
+                    cursor.bind_param(2, &sqlite3::Text(msgid.clone()));
+                    return cursor.step() == sqlite3::SQLITE_ROW && cursor.get_i64(0) > 0;
+                },
+                None => ()
+            }
+
+            let cursor = match message.contents {
+                buffer::Privmsg(ref who, ref msg) => {
+                    let cursor = db.db.prepare(
+                        "SELECT COUNT(*) FROM message
+                         JOIN message_privmsg privmsg ON privmsg.message_id = message.id
+                         WHERE buffer_id = ? AND time_ns = ? AND privmsg.who = ? AND privmsg.message = ?;", &None
+                        ).unwrap();
+                    cursor.bind_param(3, &sqlite3::Text(who.clone()));
+                    cursor.bind_param(4, &sqlite3::Text(msg.clone()));
+                    cursor
+                },
+                _ => db.db.prepare(
+                        "SELECT COUNT(*) FROM message WHERE buffer_id = ? AND time_ns = ?;", &None
+                        ).unwrap()
+            };
+            cursor.bind_param(1, &sqlite3::Integer64(bid as i64));
+            cursor.bind_param(2, &sqlite3::Integer64(message.time_ns as i64));
+            cursor.step() == sqlite3::SQLITE_ROW && cursor.get_i64(0) > 0
+        })
+    }
+
     pub fn update_network_configuration(&mut self, nid: u64, config: &network::Configuration) {
         self.db.write(|db| {
             let cursor = db.db.prepare(
-                "UPDATE network SET server = ?, nickname = ? WHERE id = ?;", &None
+                "UPDATE network SET server = ?, nickname = ?,
+                 auth_service = ?, auth_command = ?, auth_password = ?, identity_id = ?,
+                 proxy_type = ?, proxy_host = ?, proxy_port = ?, proxy_username = ?, proxy_password = ?,
+                 bind_address = ?, webirc_password = ?, webirc_gateway = ? WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Text(config.server.clone()));
+            cursor.bind_param(2, &sqlite3::Text(config.nickname.clone()));
+            match config.service_auth {
+                Some(ref auth) => {
+                    cursor.bind_param(3, &sqlite3::Text(auth.service.clone()));
+                    cursor.bind_param(4, &sqlite3::Text(auth.command.clone()));
+                    cursor.bind_param(5, &sqlite3::Text(auth.password.clone()));
+                },
+                None => {
+                    cursor.bind_param(3, &sqlite3::Null);
+                    cursor.bind_param(4, &sqlite3::Null);
+                    cursor.bind_param(5, &sqlite3::Null);
+                }
+            }
+            match config.identity_id {
+                Some(iid) => cursor.bind_param(6, &sqlite3::Integer64(iid as i64)),
+                None      => cursor.bind_param(6, &sqlite3::Null)
+            };
+            match config.proxy {
+                Some(ref proxy) => {
+                    let kind = match proxy.kind { irc::proxy::Socks5 => 0, irc::proxy::HttpConnect => 1 };
+                    cursor.bind_param(7, &sqlite3::Integer(kind));
+                    cursor.bind_param(8, &sqlite3::Text(proxy.host.clone()));
+                    cursor.bind_param(9, &sqlite3::Integer(proxy.port as int));
+                    match proxy.credentials {
+                        Some((ref username, ref password)) => {
+                            cursor.bind_param(10, &sqlite3::Text(username.clone()));
+                            cursor.bind_param(11, &sqlite3::Text(password.clone()));
+                        },
+                        None => {
+                            cursor.bind_param(10, &sqlite3::Null);
+                            cursor.bind_param(11, &sqlite3::Null);
+                        }
+                    }
+                },
+                None => {
+                    for i in range(7, 12) {
+                        cursor.bind_param(i, &sqlite3::Null);
+                    }
+                }
+            }
+            match config.bind_address {
+                Some(ip) => cursor.bind_param(12, &sqlite3::Text(ip.to_str())),
+                None     => cursor.bind_param(12, &sqlite3::Null)
+            };
+            match config.webirc {
+                Some(ref webirc) => {
+                    cursor.bind_param(13, &sqlite3::Text(webirc.password.clone()));
+                    cursor.bind_param(14, &sqlite3::Text(webirc.gateway.clone()));
+                },
+                None => {
+                    cursor.bind_param(13, &sqlite3::Null);
+                    cursor.bind_param(14, &sqlite3::Null);
+                }
+            }
+            cursor.bind_param(15, &sqlite3::Integer64(nid as i64));
+            cursor.step();
+        });
+    }
+
+    pub fn create_identity(&mut self, sid: u64, identity: &Identity) -> u64 {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "INSERT INTO identity (session_id, ident, realname, alternate_nicks,
+                                       quit_message, part_message, away_message, auto_away)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?);", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(sid as i64));
+            cursor.bind_param(2, &sqlite3::Text(identity.ident.clone()));
+            cursor.bind_param(3, &sqlite3::Text(identity.realname.clone()));
+            cursor.bind_param(4, &sqlite3::Text(identity.alternate_nicks.connect(" ")));
+            cursor.bind_param(5, &sqlite3::Text(identity.quit_message.clone()));
+            cursor.bind_param(6, &sqlite3::Text(identity.part_message.clone()));
+            cursor.bind_param(7, &sqlite3::Text(identity.away_message.clone()));
+            cursor.bind_param(8, &sqlite3::Integer(if identity.auto_away { 1 } else { 0 }));
+            let err = cursor.step();
+            if err != sqlite3::SQLITE_DONE {
+                fail!("Identity insertion failed ({}): {}", err, db.db.get_errmsg());
+            }
+            db.db.get_last_insert_rowid() as u64
+        })
+    }
+
+    pub fn update_identity(&mut self, identity: &Identity) {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "UPDATE identity SET ident = ?, realname = ?, alternate_nicks = ?,
+                                     quit_message = ?, part_message = ?, away_message = ?,
+                                     auto_away = ?
+                 WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Text(identity.ident.clone()));
+            cursor.bind_param(2, &sqlite3::Text(identity.realname.clone()));
+            cursor.bind_param(3, &sqlite3::Text(identity.alternate_nicks.connect(" ")));
+            cursor.bind_param(4, &sqlite3::Text(identity.quit_message.clone()));
+            cursor.bind_param(5, &sqlite3::Text(identity.part_message.clone()));
+            cursor.bind_param(6, &sqlite3::Text(identity.away_message.clone()));
+            cursor.bind_param(7, &sqlite3::Integer(if identity.auto_away { 1 } else { 0 }));
+            cursor.bind_param(8, &sqlite3::Integer64(identity.id as i64));
+            cursor.step();
+        });
+    }
+
+    pub fn update_session_client_address(&mut self, sid: u64, address: &Option<(~str, ~str)>) {
+        self.db.write(|db| {
+            let cursor = db.db.prepare(
+                "UPDATE session SET webirc_hostname = ?, webirc_ip = ? WHERE id = ?;", &None
+                ).unwrap();
+            match *address {
+                Some((ref hostname, ref ip)) => {
+                    cursor.bind_param(1, &sqlite3::Text(hostname.clone()));
+                    cursor.bind_param(2, &sqlite3::Text(ip.clone()));
+                },
+                None => {
+                    cursor.bind_param(1, &sqlite3::Null);
+                    cursor.bind_param(2, &sqlite3::Null);
+                }
+            }
+            cursor.bind_param(3, &sqlite3::Integer64(sid as i64));
+            cursor.step();
+        });
+    }
+
+    pub fn update_network_perform(&mut self, nid: u64, perform: &network::PerformList) {
+        self.db.write(|db| {
+            db.db.exec("BEGIN;").unwrap();
+
+            let cursor = db.db.prepare(
+                "UPDATE network SET perform_delay = ? WHERE id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(perform.delay_ms as i64));
+            cursor.bind_param(2, &sqlite3::Integer64(nid as i64));
+            cursor.step();
+
+            let cursor = db.db.prepare(
+                "DELETE FROM network_perform WHERE network_id = ?;", &None
                 ).unwrap();
             cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
-            cursor.bind_param(2, &sqlite3::Text(config.server.clone()));
-            cursor.bind_param(3, &sqlite3::Text(config.nickname.clone()));
             cursor.step();
+
+            for (i, command) in perform.commands.iter().enumerate() {
+                let cursor = db.db.prepare(
+                    "INSERT INTO network_perform (network_id, position, command) VALUES (?, ?, ?);", &None
+                    ).unwrap();
+                cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
+                cursor.bind_param(2, &sqlite3::Integer(i as int));
+                cursor.bind_param(3, &sqlite3::Text(command.clone()));
+                cursor.step();
+            }
+
+            db.db.exec("COMMIT;").unwrap();
+        });
+    }
+
+    pub fn update_network_buddies(&mut self, nid: u64, buddies: &[~str]) {
+        self.db.write(|db| {
+            db.db.exec("BEGIN;").unwrap();
+
+            let cursor = db.db.prepare(
+                "DELETE FROM network_buddy WHERE network_id = ?;", &None
+                ).unwrap();
+            cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
+            cursor.step();
+
+            for nick in buddies.iter() {
+                let cursor = db.db.prepare(
+                    "INSERT INTO network_buddy (network_id, nick) VALUES (?, ?);", &None
+                    ).unwrap();
+                cursor.bind_param(1, &sqlite3::Integer64(nid as i64));
+                cursor.bind_param(2, &sqlite3::Text(nick.clone()));
+                cursor.step();
+            }
+
+            db.db.exec("COMMIT;").unwrap();
         });
     }
 
@@ -259,11 +823,20 @@ impl Handle {
             // cols:   id, time_ns, type,
   //information.message,
   //'join'.who,
-  //privmsg.who, privmsg.message
+  //privmsg.who, privmsg.message,
+  //received_ns, msgid, account, prefix
             while cursor.step() == sqlite3::SQLITE_ROW {
+                let opt_text = |i| match cursor.get_column_type(i) {
+                    sqlite3::SQLITE_NULL => None,
+                    _                    => Some(cursor.get_text(i))
+                };
                 let msg = buffer::Message {
                     id: cursor.get_i64(0) as u64,
                     time_ns: cursor.get_i64(1) as u64,
+                    received_ns: cursor.get_i64(7) as u64,
+                    msgid: opt_text(8),
+                    account: opt_text(9),
+                    prefix: opt_text(10),
                     contents: match cursor.get_int(2) {
                         0 => buffer::Information(cursor.get_text(3)),
                         1 => buffer::Join(cursor.get_text(4)),
@@ -291,11 +864,20 @@ impl Handle {
             // cols:   id, time_ns, type,
   //information.message,
   //'join'.who,
-  //privmsg.who, privmsg.message
+  //privmsg.who, privmsg.message,
+  //received_ns, msgid, account, prefix
             while cursor.step() == sqlite3::SQLITE_ROW {
+                let opt_text = |i| match cursor.get_column_type(i) {
+                    sqlite3::SQLITE_NULL => None,
+                    _                    => Some(cursor.get_text(i))
+                };
                 let msg = buffer::Message {
                     id: cursor.get_i64(0) as u64,
                     time_ns: cursor.get_i64(1) as u64,
+                    received_ns: cursor.get_i64(7) as u64,
+                    msgid: opt_text(8),
+                    account: opt_text(9),
+                    prefix: opt_text(10),
                     contents: match cursor.get_int(2) {
                         0 => buffer::Information(cursor.get_text(3)),
                         1 => buffer::Join(cursor.get_text(4)),
@@ -0,0 +1,34 @@
+/// User-facing settings for a connection, shared by any number of networks in a session.
+/// Empty messages fall back to the defaults below.
+#[deriving(Clone)]
+pub struct Identity {
+    id: u64,
+    ident: ~str,
+    realname: ~str,
+    alternate_nicks: ~[~str], // tried in order when the nickname is taken
+    quit_message: ~str,
+    part_message: ~str,
+    away_message: ~str,
+    auto_away: bool // set away while no remote is attached
+}
+
+static DEFAULT_QUIT_MESSAGE: &'static str = "So long, and thanks for all the fish.";
+static DEFAULT_AWAY_MESSAGE: &'static str = "Away";
+
+impl Identity {
+    pub fn quit_message(&self) -> ~str {
+        if self.quit_message.is_empty() { DEFAULT_QUIT_MESSAGE.to_owned() } else { self.quit_message.clone() }
+    }
+
+    pub fn part_message(&self) -> Option<~str> {
+        if self.part_message.is_empty() { None } else { Some(self.part_message.clone()) }
+    }
+
+    pub fn away_message(&self) -> ~str {
+        if self.away_message.is_empty() { DEFAULT_AWAY_MESSAGE.to_owned() } else { self.away_message.clone() }
+    }
+}
+
+pub fn default_quit_message() -> ~str {
+    DEFAULT_QUIT_MESSAGE.to_owned()
+}
@@ -1,11 +1,13 @@
-use std::io::net::{tcp, ip};
+use std::io::net::ip::IpAddr;
 use std::io::{IoResult, IoError};
 use std::{vec, io};
 use std;
+use proxy::{Proxy, connect_direct};
+use socket::FdStream;
 
 #[deriving(Clone)]
 struct Connection {
-    stream: tcp::TcpStream
+    stream: FdStream
 }
 
 static SP: &'static [u8] = bytes!(" ");
@@ -17,8 +19,14 @@ fn to_u8<T: ToStr>(x: &T) -> ~[u8] {
 }
 
 impl Connection {
-    pub fn connect(addr: ip::SocketAddr) -> IoResult<Connection> {
-        tcp::TcpStream::connect(addr).map(|stream| Connection { stream: stream })
+    /// Connects to `host`:`port`, directly or through `proxy`, from the local address
+    /// `bind` if given.
+    pub fn connect(host: &str, port: u16, proxy: Option<&Proxy>, bind: Option<IpAddr>) -> IoResult<Connection> {
+        let stream = match proxy {
+            Some(proxy) => proxy.connect(host, port, bind),
+            None        => connect_direct(host, port, bind)
+        };
+        stream.map(|stream| Connection { stream: stream })
     }
 
     fn send(&mut self, strs: &[&[u8]]) -> IoResult<()> {
@@ -32,6 +40,18 @@ impl Connection {
         self.send([bytes!("PASS "), password])
     }
 
+    pub fn send_cap(&mut self, subcommand: &[u8], capabilities: Option<&[u8]>) -> IoResult<()> {
+        match capabilities {
+            Some(caps) => self.send([bytes!("CAP "), subcommand, bytes!(" :"), caps]),
+            None       => self.send([bytes!("CAP "), subcommand])
+        }
+    }
+
+    pub fn send_webirc(&mut self, password: &[u8], gateway: &[u8], hostname: &[u8],
+                       ip: &[u8]) -> IoResult<()> {
+        self.send([bytes!("WEBIRC "), password, SP, gateway, SP, hostname, SP, ip])
+    }
+
     pub fn send_nick(&mut self, nickname: &[u8]) -> IoResult<()> {
         self.send([bytes!("NICK "), nickname])
     }
@@ -40,35 +60,77 @@ impl Connection {
         self.send([bytes!("USER "), username, SP, to_u8(&mode).as_slice(), bytes!(" * :"), realname])
     }
 
+    pub fn send_raw(&mut self, line: &[u8]) -> IoResult<()> {
+        self.send([line])
+    }
+
+    pub fn send_labeled(&mut self, label: &[u8], line: &[u8]) -> IoResult<()> {
+        self.send([bytes!("@label="), label, SP, line])
+    }
+
+    pub fn send_chathistory(&mut self, subcommand: &[u8], target: &[u8], reference: &[u8],
+                            limit: uint) -> IoResult<()> {
+        self.send([bytes!("CHATHISTORY "), subcommand, SP, target, SP, reference, SP, to_u8(&limit).as_slice()])
+    }
+
+    pub fn send_monitor(&mut self, subcommand: &[u8], targets: Option<&[u8]>) -> IoResult<()> {
+        match targets {
+            Some(targets) => self.send([bytes!("MONITOR "), subcommand, SP, targets]),
+            None          => self.send([bytes!("MONITOR "), subcommand])
+        }
+    }
+
+    pub fn send_ison(&mut self, nicknames: &[u8]) -> IoResult<()> {
+        self.send([bytes!("ISON "), nicknames])
+    }
+
     pub fn send_pong(&mut self, target: &[u8]) -> IoResult<()> {
         self.send([bytes!("PONG "), target])
     }
 
-    pub fn send_join(&mut self, channel: &[u8]) -> IoResult<()> {
-        self.send([bytes!("JOIN "), channel])
+    pub fn send_join(&mut self, channel: &[u8], key: Option<&[u8]>) -> IoResult<()> {
+        match key {
+            Some(key) => self.send([bytes!("JOIN "), channel, SP, key]),
+            None      => self.send([bytes!("JOIN "), channel])
+        }
+    }
+
+    pub fn send_part(&mut self, channel: &[u8], message: Option<&[u8]>) -> IoResult<()> {
+        match message {
+            Some(message) => self.send([bytes!("PART "), channel, bytes!(" :"), message]),
+            None          => self.send([bytes!("PART "), channel])
+        }
     }
 
     pub fn send_privmsg(&mut self, target: &[u8], message: &[u8]) -> IoResult<()> {
         self.send([bytes!("PRIVMSG "), target, bytes!(" :"), message])
     }
 
+    pub fn send_away(&mut self, message: Option<&[u8]>) -> IoResult<()> {
+        match message {
+            Some(message) => self.send([bytes!("AWAY :"), message]),
+            None          => self.send([bytes!("AWAY")])
+        }
+    }
+
     pub fn send_quit(&mut self, message: &[u8]) -> IoResult<()> {
         self.send([bytes!("QUIT :"), message])
     }
 
-    pub fn take_stream(self) -> tcp::TcpStream {
+    pub fn take_stream(self) -> FdStream {
         self.stream
     }
 }
 
 pub enum ClientMessage {
     ConnectionError(IoError),
-    Message(::parser::Message)
+    Message(::parser::Tags, ::parser::Message, ~[u8]), // tags, message, raw line
+    Timer(u64) // id given to set_timer
 }
 
 pub struct Client {
     priv conn_out: Option<Connection>,
-    priv pipe: Option<Sender<ClientMessage>>
+    priv pipe: Sender<ClientMessage>
 }
 
 impl Client {
@@ -76,19 +138,19 @@ impl Client {
         let (tx, rx) = channel();
         (Client {
             conn_out: None,
-            pipe: Some(tx)
+            pipe: tx
          }, rx)
     }
 
-    pub fn connect(&mut self, addr: ip::SocketAddr) {
+    pub fn connect(&mut self, host: &str, port: u16, proxy: Option<&Proxy>, bind: Option<IpAddr>) {
         if self.conn_out.is_some() {
             println!("irc.client: tried to connect with connection already active");
             return;
         }
 
-        match Connection::connect(addr) {
+        match Connection::connect(host, port, proxy, bind) {
             Ok(c) => self.run(c),
-            Err(e) => self.pipe.as_ref().unwrap().send(ConnectionError(e))
+            Err(e) => self.pipe.send(ConnectionError(e))
         }
     }
 
@@ -103,7 +165,7 @@ impl Client {
 
     fn run(&mut self, conn: Connection) {
         self.conn_out = Some(conn.clone());
-        let tx = self.pipe.take_unwrap();
+        let tx = self.pipe.clone();
         std::task::task().named("irc.client.ReaderTask").spawn(proc() {
             let mut conn_in = io::BufferedReader::new(conn.take_stream());
             let mut buf = vec::with_capacity(512);
@@ -121,8 +183,8 @@ impl Client {
 
                 /* Message handling */
 
-                match ::parser::parse_message(buf.as_slice()) {
-                    Some(msg) => tx.send(Message(msg)),
+                match ::parser::parse_tagged_message(buf.as_slice()) {
+                    Some((tags, msg)) => tx.send(Message(tags, msg, buf.clone())),
                     _         => ()
                 };
             }
@@ -142,9 +204,58 @@ impl Client {
         }
     }
 
-    pub fn register(&mut self, nickname: &[u8], username: &[u8], realname: &[u8]) {
+    /// Delivers `Timer(id)` through the message pipe after `msecs` milliseconds.
+    /// Timers still pending when the client is dropped are discarded.
+    pub fn set_timer(&mut self, msecs: u64, id: u64) {
+        let tx = self.pipe.clone();
+        std::task::task().named("irc.client.TimerTask").spawn(proc() {
+            std::io::timer::sleep(msecs);
+            tx.try_send(Timer(id));
+        });
+    }
+
+    /// Starts registration. Capability negotiation is opened with CAP LS, so the user of
+    /// the client must finish it with `cap(bytes!("END"), None)` once it has received the
+    /// capability list.
+    ///
+    /// `webirc` is the (password, gateway, hostname, ip) of a WEBIRC line sent first,
+    /// for gateways that forward the address of their own client.
+    pub fn register(&mut self, nickname: &[u8], username: &[u8], realname: &[u8],
+                    webirc: Option<(&[u8], &[u8], &[u8], &[u8])>) {
+        self.with_conn(|c| {
+            let sent = match webirc {
+                Some((password, gateway, hostname, ip)) => c.send_webirc(password, gateway, hostname, ip),
+                None => Ok(())
+            };
+            sent.and_then(|_| c.send_cap(bytes!("LS 302"), None))
+             .and_then(|_| c.send_nick(nickname))
+             .and_then(|_| c.send_user(username, 0, realname))
+        })
+    }
+
+    pub fn cap(&mut self, subcommand: &[u8], capabilities: Option<&[u8]>) {
+        self.with_conn(|c| {
+            c.send_cap(subcommand, capabilities)
+        })
+    }
+
+    pub fn nick(&mut self, nickname: &[u8]) {
+        self.with_conn(|c| {
+            c.send_nick(nickname)
+        })
+    }
+
+    pub fn send_raw(&mut self, line: &[u8]) {
+        self.with_conn(|c| {
+            c.send_raw(line)
+        })
+    }
+
+    /// Sends a raw line tagged with an IRCv3 label. Requires the labeled-response
+    /// capability; the server then tags its reply, or a batch of replies, with `label`.
+    pub fn send_labeled(&mut self, label: &[u8], line: &[u8]) {
         self.with_conn(|c| {
-            c.send_nick(nickname).and_then(|_| c.send_user(username, 0, realname))
+            c.send_labeled(label, line)
         })
     }
 
@@ -154,9 +265,41 @@ impl Client {
         })
     }
 
-    pub fn join(&mut self, channel: &[u8]) {
+    /// Joins `channel`, which may also be a comma-separated list of channels. In that
+    /// case `key` is a matching comma-separated list of keys for the leading channels.
+    pub fn join(&mut self, channel: &[u8], key: Option<&[u8]>) {
+        self.with_conn(|c| {
+            c.send_join(channel, key)
+        })
+    }
+
+    pub fn part(&mut self, channel: &[u8], message: Option<&[u8]>) {
         self.with_conn(|c| {
-            c.send_join(channel)
+            c.send_part(channel, message)
+        })
+    }
+
+    /// Requests up to `limit` messages sent to `target` after the server-time
+    /// timestamp `since`.
+    pub fn chathistory_after(&mut self, target: &[u8], since: &[u8], limit: uint) {
+        let mut reference = bytes!("timestamp=").to_owned();
+        reference.push_all(since);
+        self.with_conn(|c| {
+            c.send_chathistory(bytes!("AFTER"), target, reference, limit)
+        })
+    }
+
+    /// Sends a MONITOR command, `targets` being a comma-separated list of nicknames.
+    pub fn monitor(&mut self, subcommand: &[u8], targets: Option<&[u8]>) {
+        self.with_conn(|c| {
+            c.send_monitor(subcommand, targets)
+        })
+    }
+
+    /// Asks which of a space-separated list of nicknames are online.
+    pub fn ison(&mut self, nicknames: &[u8]) {
+        self.with_conn(|c| {
+            c.send_ison(nicknames)
         })
     }
 
@@ -164,6 +307,13 @@ impl Client {
         self.with_conn(|c| { c.send_pong(recipient) } );
     }
 
+    /// Marks us away with `message`, or back if `None`.
+    pub fn away(&mut self, message: Option<&[u8]>) {
+        self.with_conn(|c| {
+            c.send_away(message)
+        });
+    }
+
     pub fn quit(&mut self, msg: &[u8]) {
         self.with_conn(|c| {
             c.send_quit(msg)
@@ -6,6 +6,11 @@
 
 // Rust IRC stuffs
 
+extern crate serialize;
+extern crate sync;
+
 pub mod client;
 pub mod parser;
+pub mod proxy;
+pub mod socket;
 
@@ -1,46 +1,394 @@
+use std;
+use std::vec;
+
 pub enum Message {
     Welcome(~[u8]),
     Ping(~[u8]),
-    Join(~[u8], ~[u8]),
+    Cap(~[u8], ~[~[u8]], bool), // subcommand, capabilities, more to follow
+    BatchStart(~[u8], ~[u8], ~[~[u8]]), // reference, type, parameters
+    BatchEnd(~[u8]), // reference
+    Isupport(~[~[u8]]), // tokens
+    NicknameInUse(~[u8]), // nickname
+    Join(~[u8], ~[u8], Option<~[u8]>, Option<~[u8]>), // who, channel, account, realname (extended-join)
+    Part(~[u8], ~[u8], Option<~[u8]>), // who, channel, message
+    Quit(~[u8], Option<~[u8]>), // who, message
+    Nick(~[u8], ~[u8]), // who, new nickname
+    Kick(~[u8], ~[u8], ~[u8], Option<~[u8]>), // who, channel, kicked nickname, message
+    Names(~[u8], ~[~[u8]]), // channel, entries with prefixes
+    EndOfNames(~[u8]), // channel
+    Account(~[u8], Option<~[u8]>), // who, account or None when logged out
+    AwayNotify(~[u8], Option<~[u8]>), // who, message or None when back
+    Chghost(~[u8], ~[u8], ~[u8]), // who, new user, new host
+    Setname(~[u8], ~[u8]), // who, new realname
+    Topic(~[u8], ~[u8], Option<~[u8]>), // who, channel, topic or None when cleared
+    TopicIs(~[u8], ~[u8]), // channel, topic (RPL_TOPIC when joining)
+    EndOfMotd,
+    MonOnline(~[~[u8]]), // targets, possibly nick!user@host
+    MonOffline(~[~[u8]]), // targets
+    Ison(~[~[u8]]), // nicknames that are online
     Privmsg(~[u8], ~[u8], ~[u8]),
+    Notice(~[u8], ~[u8], ~[u8]), // who, target, message
+    LoggedIn(~[u8]), // account
+    Away(~[u8], ~[u8]), // nickname, message
+    Unaway,
+    NowAway,
     Unknown(RawMessage)
 }
 
-/* I hate these clones but whatever for now */
 pub fn parse_message(message: &[u8]) -> Option<Message> {
-    parse_message_raw(message).and_then(|msg| {
-        let RawMessage { prefix, command, parameters } = msg;
-        if command.as_slice() == bytes!("001") {
-            Some(Welcome(parameters[0]))
-        }
-        else if command.as_slice() == bytes!("PING") {
-            Some(Ping(parameters[0]))
-        }
-        else if command.as_slice() == bytes!("JOIN") {
-            let mut it = parameters.move_iter();
-            match (prefix, it.next()) {
-                (Some(prefix), Some(a)) => Some(Join(prefix, a)),
-                _ => None
-            }
+    parse_tagged_message(message).map(|(_, msg)| msg)
+}
+
+pub fn parse_tagged_message(message: &[u8]) -> Option<(Tags, Message)> {
+    parse_message_raw(message).and_then(|mut msg| {
+        let tags = std::mem::replace(&mut msg.tags, Tags::empty());
+        message_from_raw(msg).map(|msg| (tags, msg))
+    })
+}
+
+/* I hate these clones but whatever for now */
+fn message_from_raw(msg: RawMessage) -> Option<Message> {
+    let RawMessage { prefix, command, parameters, .. } = msg;
+    if command.as_slice() == bytes!("001") {
+        Some(Welcome(parameters[0]))
+    }
+    else if command.as_slice() == bytes!("CAP") {
+        // CAP <target> <subcommand> [*] :<capabilities>
+        let len = parameters.len();
+        if len < 3 { return None }
+        let more = len > 3 && parameters[2].as_slice() == bytes!("*");
+        let caps = parameters[len - 1].split(|&b| b == ' ' as u8)
+                                      .filter(|c| c.len() > 0)
+                                      .map(|c| c.to_owned()).collect();
+        Some(Cap(parameters[1].clone(), caps, more))
+    }
+    else if command.as_slice() == bytes!("BATCH") {
+        // BATCH +<reference> <type> <parameters...> / BATCH -<reference>
+        let mut it = parameters.move_iter();
+        match it.next() {
+            Some(reference) => {
+                if reference.len() < 2 { return None }
+                let name = reference.slice_from(1).to_owned();
+                if reference[0] == '+' as u8 {
+                    match it.next() {
+                        Some(kind) => Some(BatchStart(name, kind, it.collect())),
+                        None       => None
+                    }
+                } else if reference[0] == '-' as u8 {
+                    Some(BatchEnd(name))
+                } else {
+                    None
+                }
+            },
+            None => None
         }
-        else if command.as_slice() == bytes!("PRIVMSG") {
-            let mut it = parameters.move_iter();
-            match (prefix, it.next(), it.next()) {
-                (Some(prefix), Some(a), Some(b)) => Some(Privmsg(prefix, a, b)),
-                _ => None
-            }
+    }
+    else if command.as_slice() == bytes!("005") {
+        // Drop our own nickname and the trailing "are supported by this server"
+        let len = parameters.len();
+        if len < 2 { return None }
+        Some(Isupport(parameters.slice(1, len - 1).to_owned()))
+    }
+    else if command.as_slice() == bytes!("301") {
+        // RPL_AWAY <client> <nick> :<message>
+        let mut it = parameters.move_iter().skip(1);
+        match (it.next(), it.next()) {
+            (Some(nick), Some(message)) => Some(Away(nick, message)),
+            _ => None
         }
-        else { Some(Unknown(RawMessage { prefix: prefix, command: command, parameters: parameters })) }
-    })
+    }
+    else if command.as_slice() == bytes!("305") {
+        Some(Unaway)
+    }
+    else if command.as_slice() == bytes!("306") {
+        Some(NowAway)
+    }
+    else if command.as_slice() == bytes!("303") {
+        // RPL_ISON <client> :<nick>{ <nick>}
+        parameters.move_iter().skip(1).next().map(|nicks| Ison(split_list(nicks, ' ' as u8)))
+    }
+    else if command.as_slice() == bytes!("376") || command.as_slice() == bytes!("422") {
+        // RPL_ENDOFMOTD or ERR_NOMOTD, the last reply of registration
+        Some(EndOfMotd)
+    }
+    else if command.as_slice() == bytes!("730") {
+        // RPL_MONONLINE <client> :target[!user@host]{,target[!user@host]}
+        parameters.move_iter().skip(1).next().map(|targets| MonOnline(split_list(targets, ',' as u8)))
+    }
+    else if command.as_slice() == bytes!("731") {
+        // RPL_MONOFFLINE <client> :target{,target}
+        parameters.move_iter().skip(1).next().map(|targets| MonOffline(split_list(targets, ',' as u8)))
+    }
+    else if command.as_slice() == bytes!("353") {
+        // RPL_NAMREPLY <client> <symbol> <channel> :[prefix]<nick>{ [prefix]<nick>}
+        let mut it = parameters.move_iter().skip(2);
+        match (it.next(), it.next()) {
+            (Some(channel), Some(names)) => Some(Names(channel, split_list(names, ' ' as u8))),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("366") {
+        // RPL_ENDOFNAMES <client> <channel> :End of /NAMES list
+        let mut it = parameters.move_iter().skip(1);
+        it.next().map(|channel| EndOfNames(channel))
+    }
+    else if command.as_slice() == bytes!("332") {
+        // RPL_TOPIC <client> <channel> :<topic>
+        let mut it = parameters.move_iter().skip(1);
+        match (it.next(), it.next()) {
+            (Some(channel), Some(topic)) => Some(TopicIs(channel, topic)),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("433") {
+        // ERR_NICKNAMEINUSE <client> <nick> :Nickname is already in use
+        let mut it = parameters.move_iter().skip(1);
+        it.next().map(|nick| NicknameInUse(nick))
+    }
+    else if command.as_slice() == bytes!("900") {
+        // RPL_LOGGEDIN <nick> <nick>!<ident>@<host> <account> :You are now logged in as <user>
+        let mut it = parameters.move_iter().skip(2);
+        it.next().map(|account| LoggedIn(account))
+    }
+    else if command.as_slice() == bytes!("PING") {
+        Some(Ping(parameters[0]))
+    }
+    else if command.as_slice() == bytes!("JOIN") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            // extended-join adds the account ("*" if none) and realname
+            (Some(prefix), Some(a)) => {
+                let account = it.next().filtered(|a| a.as_slice() != bytes!("*"));
+                Some(Join(prefix, a, account, it.next()))
+            },
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("PART") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            (Some(prefix), Some(a)) => Some(Part(prefix, a, it.next())),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("QUIT") {
+        match prefix {
+            Some(prefix) => Some(Quit(prefix, parameters.move_iter().next())),
+            None => None
+        }
+    }
+    else if command.as_slice() == bytes!("NICK") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            (Some(prefix), Some(a)) => Some(Nick(prefix, a)),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("KICK") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next(), it.next()) {
+            (Some(prefix), Some(a), Some(b)) => Some(Kick(prefix, a, b, it.next())),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("ACCOUNT") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            (Some(prefix), Some(a)) => {
+                let account = if a.as_slice() == bytes!("*") { None } else { Some(a) };
+                Some(Account(prefix, account))
+            },
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("AWAY") {
+        match prefix {
+            Some(prefix) => Some(AwayNotify(prefix, parameters.move_iter().next())),
+            None => None
+        }
+    }
+    else if command.as_slice() == bytes!("CHGHOST") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next(), it.next()) {
+            (Some(prefix), Some(a), Some(b)) => Some(Chghost(prefix, a, b)),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("SETNAME") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            (Some(prefix), Some(a)) => Some(Setname(prefix, a)),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("TOPIC") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next()) {
+            (Some(prefix), Some(a)) => Some(Topic(prefix, a, it.next().filtered(|t| t.len() > 0))),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("PRIVMSG") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next(), it.next()) {
+            (Some(prefix), Some(a), Some(b)) => Some(Privmsg(prefix, a, b)),
+            _ => None
+        }
+    }
+    else if command.as_slice() == bytes!("NOTICE") {
+        let mut it = parameters.move_iter();
+        match (prefix, it.next(), it.next()) {
+            (Some(prefix), Some(a), Some(b)) => Some(Notice(prefix, a, b)),
+            _ => None
+        }
+    }
+    else { Some(Unknown(RawMessage { tags: Tags::empty(), prefix: prefix, command: command, parameters: parameters })) }
+}
+
+fn split_list(list: &[u8], separator: u8) -> ~[~[u8]] {
+    list.split(|&b| b == separator).filter(|x| x.len() > 0).map(|x| x.to_owned()).collect()
+}
+
+/// Returns the nickname part of a `nick!user@host` prefix.
+pub fn prefix_nick<'a>(prefix: &'a [u8]) -> &'a [u8] {
+    match prefix.iter().position(|&b| b == '!' as u8 || b == '@' as u8) {
+        Some(i) => prefix.slice_to(i),
+        None    => prefix
+    }
+}
+
+/// IRCv3 message tags
+pub struct Tags {
+    tags: ~[(~[u8], ~[u8])] // key, unescaped value
+}
+
+impl Tags {
+    pub fn empty() -> Tags {
+        Tags { tags: ~[] }
+    }
+
+    pub fn get<'a>(&'a self, key: &[u8]) -> Option<&'a [u8]> {
+        self.tags.iter().find(|&&(ref k, _)| k.as_slice() == key).map(|&(_, ref v)| v.as_slice())
+    }
+}
+
+fn parse_tags(tags: &[u8]) -> Tags {
+    let tags = tags.split(|&b| b == ';' as u8).filter(|t| t.len() > 0).map(|tag| {
+        match tag.iter().position(|&b| b == '=' as u8) {
+            Some(i) => (tag.slice_to(i).to_owned(), unescape_tag_value(tag.slice_from(i + 1))),
+            None    => (tag.to_owned(), ~[])
+        }
+    }).collect();
+    Tags { tags: tags }
+}
+
+fn unescape_tag_value(value: &[u8]) -> ~[u8] {
+    let mut out = vec::with_capacity(value.len());
+    let mut escaped = false;
+    for &b in value.iter() {
+        if escaped {
+            out.push(match b as char {
+                ':' => ';' as u8,
+                's' => ' ' as u8,
+                'r' => '\r' as u8,
+                'n' => '\n' as u8,
+                _   => b
+            });
+            escaped = false;
+        } else if b == '\\' as u8 {
+            escaped = true;
+        } else {
+            out.push(b);
+        }
+    }
+    out
+}
+
+/// Parses an IRCv3 server-time timestamp (YYYY-MM-DDThh:mm:ss.sssZ) into nanoseconds
+/// since the Unix epoch.
+pub fn parse_server_time(time: &[u8]) -> Option<u64> {
+    fn number(s: &[u8]) -> Option<i64> {
+        if s.len() == 0 || !s.iter().all(|&b| b >= '0' as u8 && b <= '9' as u8) {
+            return None;
+        }
+        Some(s.iter().fold(0i64, |n, &b| n * 10 + (b - '0' as u8) as i64))
+    }
+
+    if time.len() < 20 || time[4] != '-' as u8 || time[7] != '-' as u8 || time[10] != 'T' as u8 ||
+       time[13] != ':' as u8 || time[16] != ':' as u8 || *time.last().unwrap() != 'Z' as u8 {
+        return None;
+    }
+    let (y, m, d) = match (number(time.slice(0, 4)), number(time.slice(5, 7)), number(time.slice(8, 10))) {
+        (Some(y), Some(m), Some(d)) if m >= 1 && m <= 12 && d >= 1 && d <= 31 => (y, m, d),
+        _ => return None
+    };
+    let (hh, mm, ss) = match (number(time.slice(11, 13)), number(time.slice(14, 16)), number(time.slice(17, 19))) {
+        (Some(hh), Some(mm), Some(ss)) => (hh, mm, ss),
+        _ => return None
+    };
+    let mut nsec = 0;
+    if time[19] == '.' as u8 {
+        let frac = time.slice(20, time.len() - 1);
+        if frac.len() > 9 { return None }
+        nsec = match number(frac) {
+            Some(n) => range(0, 9 - frac.len()).fold(n, |n, _| n * 10),
+            None    => return None
+        };
+    } else if time.len() != 20 {
+        return None;
+    }
+
+    // Days since the epoch, counting years from March so leap days fall at year end
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let secs = days * 86400 + hh * 3600 + mm * 60 + ss;
+    if secs < 0 { return None }
+    Some(secs as u64 * 1000000000 + nsec as u64)
+}
+
+/// Formats nanoseconds since the Unix epoch as an IRCv3 server-time timestamp.
+pub fn format_server_time(time_ns: u64) -> ~str {
+    let secs = (time_ns / 1000000000) as i64;
+    let msecs = (time_ns % 1000000000) / 1000000;
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // Inverse of the day count in parse_server_time
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+
+    format!("{:04d}-{:02d}-{:02d}T{:02d}:{:02d}:{:02d}.{:03u}Z",
+            y, m, d, rem / 3600, rem % 3600 / 60, rem % 60, msecs)
 }
 
 pub struct RawMessage {
+    tags: Tags,
     prefix: Option<~[u8]>,
     command: ~[u8],
     parameters: ~[~[u8]]
 }
 
 pub fn parse_message_raw(message: &[u8]) -> Option<RawMessage> {
+    let (tags, message) = if message.len() > 0 && message[0] == '@' as u8 {
+        match message.iter().position(|&b| b == ' ' as u8) {
+            Some(i) => (parse_tags(message.slice(1, i)), message.slice_from(i + 1)),
+            None    => return None
+        }
+    } else {
+        (Tags::empty(), message)
+    };
+
     let mut prefix = None;
     let mut command = None;
     let mut parameters: ~[~[u8]] = ~[];
@@ -87,7 +435,8 @@ pub fn parse_message_raw(message: &[u8]) -> Option<RawMessage> {
     }
 
     match command {
-        Some(command) => Some(RawMessage { prefix: prefix,
+        Some(command) => Some(RawMessage { tags: tags,
+                                           prefix: prefix,
                                            command: command,
                                            parameters: parameters }),
         None => None
@@ -138,3 +487,170 @@ fn test_ping() {
         _ => fail!("no")
     }
 }
+
+#[test]
+fn test_isupport() {
+    let m = parse_message(bytes!(":irc.example.com 005 nick CHANTYPES=# TARGMAX=JOIN:4,PART: :are supported by this server")).unwrap();
+
+    match m {
+        Isupport(ref tokens) => {
+            assert_eq!(tokens.len(), 2);
+            assert_eq!(tokens[1].as_slice(), bytes!("TARGMAX=JOIN:4,PART:"));
+        },
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_prefix_nick() {
+    assert_eq!(prefix_nick(bytes!("WiZ!jto@tolsun.oulu.fi")), bytes!("WiZ"));
+    assert_eq!(prefix_nick(bytes!("WiZ")), bytes!("WiZ"));
+}
+
+#[test]
+fn test_tags() {
+    let (tags, m) = parse_tagged_message(bytes!("@time=2012-06-30T23:59:60.419Z;msgid=a\\sb\\:c :nick!u@h PRIVMSG #chan :hi")).unwrap();
+
+    assert_eq!(tags.get(bytes!("time")), Some(bytes!("2012-06-30T23:59:60.419Z")));
+    assert_eq!(tags.get(bytes!("msgid")), Some(bytes!("a b;c")));
+    assert!(tags.get(bytes!("account")).is_none());
+    match m {
+        Privmsg(ref who, _, ref msg) if who.as_slice() == bytes!("nick!u@h") && msg.as_slice() == bytes!("hi") => (),
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_server_time() {
+    assert_eq!(parse_server_time(bytes!("1970-01-01T00:00:00Z")), Some(0));
+    assert_eq!(parse_server_time(bytes!("2011-10-19T16:40:51.620Z")), Some(1319042451620000000));
+    assert_eq!(parse_server_time(bytes!("2000-03-01T00:00:00.000000001Z")), Some(951868800000000001));
+    assert!(parse_server_time(bytes!("2011-10-19 16:40:51Z")).is_none());
+}
+
+#[test]
+fn test_format_server_time() {
+    assert_eq!(format_server_time(0), ~"1970-01-01T00:00:00.000Z");
+    assert_eq!(format_server_time(1319042451620000000), ~"2011-10-19T16:40:51.620Z");
+    assert_eq!(format_server_time(951868800000000000), ~"2000-03-01T00:00:00.000Z");
+}
+
+#[test]
+fn test_batch() {
+    match parse_message(bytes!(":irc.host BATCH +yXNAbvnRHTRBv chathistory #channel")).unwrap() {
+        BatchStart(ref r, ref kind, ref params) => {
+            assert_eq!(r.as_slice(), bytes!("yXNAbvnRHTRBv"));
+            assert_eq!(kind.as_slice(), bytes!("chathistory"));
+            assert_eq!(params.len(), 1);
+        },
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":irc.host BATCH -yXNAbvnRHTRBv")).unwrap() {
+        BatchEnd(ref r) if r.as_slice() == bytes!("yXNAbvnRHTRBv") => (),
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_netsplit_batch() {
+    match parse_message(bytes!(":irc.host BATCH +yXNAbvnRHTRBv netsplit irc.hub other.host")).unwrap() {
+        BatchStart(_, ref kind, ref params) => {
+            assert_eq!(kind.as_slice(), bytes!("netsplit"));
+            assert_eq!(params, &~[bytes!("irc.hub").to_owned(), bytes!("other.host").to_owned()]);
+        },
+        _ => fail!("no")
+    }
+    match parse_tagged_message(bytes!("@batch=yXNAbvnRHTRBv :aji!a@a QUIT :irc.hub other.host")).unwrap() {
+        (ref tags, Quit(ref who, Some(_))) => {
+            assert_eq!(tags.get(bytes!("batch")), Some(bytes!("yXNAbvnRHTRBv")));
+            assert_eq!(who.as_slice(), bytes!("aji!a@a"));
+        },
+        _ => fail!("no")
+    }
+    match parse_tagged_message(bytes!("@batch=4lMB :nenolod!a@a JOIN #atheme")).unwrap() {
+        (ref tags, Join(_, ref chan, None, None)) => {
+            assert_eq!(tags.get(bytes!("batch")), Some(bytes!("4lMB")));
+            assert_eq!(chan.as_slice(), bytes!("#atheme"));
+        },
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_extended_join() {
+    match parse_message(bytes!(":nick!u@h JOIN #chan accountname :Real Name")).unwrap() {
+        Join(_, ref chan, Some(ref account), Some(ref realname)) => {
+            assert_eq!(chan.as_slice(), bytes!("#chan"));
+            assert_eq!(account.as_slice(), bytes!("accountname"));
+            assert_eq!(realname.as_slice(), bytes!("Real Name"));
+        },
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":nick!u@h JOIN #chan * :Real Name")).unwrap() {
+        Join(_, _, None, Some(_)) => (),
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":nick!u@h JOIN #chan")).unwrap() {
+        Join(_, _, None, None) => (),
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_names() {
+    match parse_message(bytes!(":irc.host 353 me = #chan :@+op!u@h +voice plain")).unwrap() {
+        Names(ref chan, ref names) => {
+            assert_eq!(chan.as_slice(), bytes!("#chan"));
+            assert_eq!(names.len(), 3);
+            assert_eq!(names[0].as_slice(), bytes!("@+op!u@h"));
+        },
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_user_tracking() {
+    match parse_message(bytes!(":nick!u@h ACCOUNT *")).unwrap() {
+        Account(_, None) => (),
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":nick!u@h AWAY")).unwrap() {
+        AwayNotify(_, None) => (),
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":nick!u@h CHGHOST newuser new.host")).unwrap() {
+        Chghost(_, ref user, ref host) if user.as_slice() == bytes!("newuser") && host.as_slice() == bytes!("new.host") => (),
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_monitor() {
+    match parse_message(bytes!(":irc.host 730 me :alice!a@host,bob!b@host")).unwrap() {
+        MonOnline(ref targets) => {
+            assert_eq!(targets.len(), 2);
+            assert_eq!(targets[1].as_slice(), bytes!("bob!b@host"));
+        },
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":irc.host 303 me :alice bob ")).unwrap() {
+        Ison(ref nicks) => assert_eq!(nicks.len(), 2),
+        _ => fail!("no")
+    }
+}
+
+#[test]
+fn test_topic() {
+    match parse_message(bytes!(":nick!u@h TOPIC #chan :new topic")).unwrap() {
+        Topic(_, ref chan, Some(ref topic)) if chan.as_slice() == bytes!("#chan") && topic.as_slice() == bytes!("new topic") => (),
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":nick!u@h TOPIC #chan :")).unwrap() {
+        Topic(_, _, None) => (),
+        _ => fail!("no")
+    }
+    match parse_message(bytes!(":irc.host 332 me #chan :the topic")).unwrap() {
+        TopicIs(ref chan, ref topic) if chan.as_slice() == bytes!("#chan") && topic.as_slice() == bytes!("the topic") => (),
+        _ => fail!("no")
+    }
+}
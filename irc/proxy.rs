@@ -0,0 +1,266 @@
+use std::io::{IoResult, IoError, OtherIoError, BufferedReader};
+use std::io::net::ip::{SocketAddr, IpAddr, Ipv4Addr, Ipv6Addr};
+use std::io::net::addrinfo::get_host_addresses;
+use socket;
+use socket::FdStream;
+use serialize::base64::{ToBase64, STANDARD};
+
+#[deriving(Clone, Eq)]
+pub enum ProxyType {
+    Socks5,
+    HttpConnect
+}
+
+/// A proxy that outbound connections are tunneled through
+#[deriving(Clone)]
+pub struct Proxy {
+    kind: ProxyType,
+    host: ~str,
+    port: u16,
+    credentials: Option<(~str, ~str)> // username, password
+}
+
+fn proxy_error(desc: &'static str, detail: Option<~str>) -> IoError {
+    IoError { kind: OtherIoError, desc: desc, detail: detail }
+}
+
+/// Resolves `host` and connects to the first of its addresses that accepts, from the
+/// local address `bind` if given. Only addresses of the same family as `bind` are tried.
+pub fn connect_direct(host: &str, port: u16, bind: Option<IpAddr>) -> IoResult<FdStream> {
+    let addresses = match from_str::<IpAddr>(host) {
+        Some(ip) => ~[ip],
+        None     => try!(get_host_addresses(host))
+    };
+    let mut last_error = proxy_error("no addresses found for host", Some(host.to_owned()));
+    for &ip in addresses.iter().filter(|&&ip| bind.map_or(true, |b| same_family(b, ip))) {
+        match socket::connect(SocketAddr { ip: ip, port: port }, bind) {
+            Ok(stream) => return Ok(stream),
+            Err(e)     => last_error = e
+        }
+    }
+    Err(last_error)
+}
+
+fn same_family(a: IpAddr, b: IpAddr) -> bool {
+    match (a, b) {
+        (Ipv4Addr(..), Ipv4Addr(..)) | (Ipv6Addr(..), Ipv6Addr(..)) => true,
+        _ => false
+    }
+}
+
+impl Proxy {
+    /// Connects to `host`:`port` through the proxy. Hostnames are resolved by the proxy
+    /// rather than locally. `bind` applies to the connection to the proxy.
+    pub fn connect(&self, host: &str, port: u16, bind: Option<IpAddr>) -> IoResult<FdStream> {
+        let mut stream = try!(connect_direct(self.host, self.port, bind));
+        match self.kind {
+            Socks5      => try!(socks5_handshake(&mut stream, host, port, &self.credentials)),
+            HttpConnect => try!(http_connect_handshake(&mut stream, host, port, &self.credentials))
+        }
+        Ok(stream)
+    }
+}
+
+// RFC 1928, with username/password authentication from RFC 1929
+fn socks5_handshake<S: Reader + Writer>(stream: &mut S, host: &str, port: u16,
+                                        credentials: &Option<(~str, ~str)>) -> IoResult<()> {
+    // Greeting: version, number of methods, methods (0 = none, 2 = username/password)
+    match *credentials {
+        Some(_) => try!(stream.write([5, 2, 0, 2])),
+        None    => try!(stream.write([5, 1, 0]))
+    }
+    let reply = try!(stream.read_bytes(2));
+    if reply[0] != 5 {
+        return Err(proxy_error("not a SOCKS5 proxy", None));
+    }
+    match (reply[1], credentials) {
+        (0, _) => (),
+        (2, &Some((ref username, ref password))) => {
+            if username.len() > 255 || password.len() > 255 {
+                return Err(proxy_error("SOCKS5 credentials too long", None));
+            }
+            let mut auth = ~[1u8, username.len() as u8];
+            auth.push_all(username.as_bytes());
+            auth.push(password.len() as u8);
+            auth.push_all(password.as_bytes());
+            try!(stream.write(auth));
+            let reply = try!(stream.read_bytes(2));
+            if reply[1] != 0 {
+                return Err(proxy_error("SOCKS5 authentication failed", None));
+            }
+        },
+        _ => return Err(proxy_error("no acceptable SOCKS5 authentication method", None))
+    }
+
+    // Connect request, passing hostnames on for the proxy to resolve and IP literals as
+    // addresses
+    let mut request = ~[5u8, 1, 0];
+    match from_str::<IpAddr>(host) {
+        Some(Ipv4Addr(a, b, c, d)) => {
+            request.push(1);
+            request.push_all([a, b, c, d]);
+        },
+        Some(Ipv6Addr(a, b, c, d, e, f, g, h)) => {
+            request.push(4);
+            for &x in [a, b, c, d, e, f, g, h].iter() {
+                request.push((x >> 8) as u8);
+                request.push(x as u8);
+            }
+        },
+        None => {
+            if host.len() > 255 {
+                return Err(proxy_error("hostname too long for SOCKS5", None));
+            }
+            request.push(3);
+            request.push(host.len() as u8);
+            request.push_all(host.as_bytes());
+        }
+    }
+    request.push((port >> 8) as u8);
+    request.push(port as u8);
+    try!(stream.write(request));
+
+    let reply = try!(stream.read_bytes(4));
+    if reply[1] != 0 {
+        return Err(proxy_error("SOCKS5 connect failed", Some(format!("reply code {}", reply[1]))));
+    }
+    // Skip the bound address and port
+    let address_length = match reply[3] {
+        1 => 4,
+        3 => try!(stream.read_byte()) as uint,
+        4 => 16,
+        _ => return Err(proxy_error("invalid SOCKS5 address type", None))
+    };
+    try!(stream.read_bytes(address_length + 2));
+    Ok(())
+}
+
+fn http_connect_handshake<S: Reader + Writer>(stream: &mut S, host: &str, port: u16,
+                                              credentials: &Option<(~str, ~str)>) -> IoResult<()> {
+    let target = if host.contains_char(':') { format!("[{}]:{}", host, port) } else { format!("{}:{}", host, port) };
+    let mut request = format!("CONNECT {} HTTP/1.1\r\nHost: {}\r\n", target, target);
+    match *credentials {
+        Some((ref username, ref password)) => {
+            let encoded = format!("{}:{}", *username, *password).as_bytes().to_base64(STANDARD);
+            request.push_str(format!("Proxy-Authorization: Basic {}\r\n", encoded));
+        },
+        None => ()
+    }
+    request.push_str("\r\n");
+    try!(stream.write(request.as_bytes()));
+
+    // Read the response header byte by byte so nothing after it is consumed
+    let mut reader = BufferedReader::with_capacity(1, stream.by_ref());
+    let status = try!(reader.read_line());
+    if !(status.starts_with("HTTP/1.") && status.len() >= 12 && status.slice(9, 12) == "200") {
+        return Err(proxy_error("HTTP proxy refused to connect", Some(status.trim().to_owned())));
+    }
+    loop {
+        let line = try!(reader.read_line());
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+// Starts a one-shot proxy stand-in on a local port, running `f` on the accepted
+// connection after the tunnel is set up
+#[cfg(test)]
+fn stand_in(f: proc(::std::io::net::tcp::TcpStream)) -> u16 {
+    use std::io::net::tcp::TcpListener;
+    use std::io::{Acceptor, Listener};
+
+    let mut listener = TcpListener::bind(from_str::<SocketAddr>("127.0.0.1:0").unwrap()).unwrap();
+    let port = listener.socket_name().unwrap().port;
+    let mut acceptor = listener.listen().unwrap();
+    spawn(proc() {
+        let stream = acceptor.accept().unwrap();
+        f(stream);
+    });
+    port
+}
+
+#[test]
+fn test_socks5() {
+    let port = stand_in(proc(mut s) {
+        assert_eq!(s.read_bytes(4).unwrap(), ~[5u8, 2, 0, 2]);
+        s.write([5, 2]).unwrap();
+        let auth = s.read_bytes(11).unwrap();
+        assert_eq!(auth, ~[1u8, 4, 'u' as u8, 's' as u8, 'e' as u8, 'r' as u8, 4,
+                           'p' as u8, 'a' as u8, 's' as u8, 's' as u8]);
+        s.write([1, 0]).unwrap();
+        let request = s.read_bytes(5 + 15 + 2).unwrap();
+        assert_eq!(request.slice(0, 5), &[5u8, 1, 0, 3, 15]);
+        assert_eq!(request.slice(5, 20), bytes!("irc.example.com"));
+        assert_eq!(request.slice(20, 22), &[0x1au8, 0x0b]);
+        s.write([5, 0, 0, 1, 127, 0, 0, 1, 0, 0]).unwrap();
+        s.write(bytes!("hello")).unwrap();
+    });
+    let proxy = Proxy { kind: Socks5, host: ~"127.0.0.1", port: port,
+                        credentials: Some((~"user", ~"pass")) };
+    let mut stream = proxy.connect("irc.example.com", 6667, None).unwrap();
+    assert_eq!(stream.read_bytes(5).unwrap().as_slice(), bytes!("hello"));
+}
+
+#[test]
+fn test_socks5_ipv6() {
+    let port = stand_in(proc(mut s) {
+        assert_eq!(s.read_bytes(3).unwrap(), ~[5u8, 1, 0]);
+        s.write([5, 0]).unwrap();
+        let request = s.read_bytes(4 + 16 + 2).unwrap();
+        assert_eq!(request.slice(0, 4), &[5u8, 1, 0, 4]);
+        assert_eq!(request.slice(4, 20), &[0x20u8, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(request.slice(20, 22), &[0x1au8, 0x0b]);
+        s.write([5, 0, 0, 1, 127, 0, 0, 1, 0, 0]).unwrap();
+        s.write(bytes!("hello")).unwrap();
+    });
+    let proxy = Proxy { kind: Socks5, host: ~"127.0.0.1", port: port, credentials: None };
+    let mut stream = proxy.connect("2001:db8::1", 6667, None).unwrap();
+    assert_eq!(stream.read_bytes(5).unwrap().as_slice(), bytes!("hello"));
+}
+
+#[test]
+fn test_socks5_refused() {
+    let port = stand_in(proc(mut s) {
+        s.read_bytes(3).unwrap();
+        s.write([5, 0]).unwrap();
+        s.read_bytes(5 + 15 + 2).unwrap();
+        s.write([5, 5, 0, 1, 0, 0, 0, 0, 0, 0]).unwrap();
+    });
+    let proxy = Proxy { kind: Socks5, host: ~"127.0.0.1", port: port, credentials: None };
+    assert!(proxy.connect("irc.example.com", 6667, None).is_err());
+}
+
+#[test]
+fn test_http_connect() {
+    let port = stand_in(proc(s) {
+        let mut reader = BufferedReader::new(s.clone());
+        assert_eq!(reader.read_line().unwrap(), ~"CONNECT irc.example.com:6697 HTTP/1.1\r\n");
+        let mut authorized = false;
+        loop {
+            let line = reader.read_line().unwrap();
+            if line == ~"\r\n" { break }
+            // base64 of "user:pass"
+            if line == ~"Proxy-Authorization: Basic dXNlcjpwYXNz\r\n" { authorized = true }
+        }
+        assert!(authorized);
+        let mut s = s;
+        s.write(bytes!("HTTP/1.1 200 Connection established\r\n\r\nhello")).unwrap();
+    });
+    let proxy = Proxy { kind: HttpConnect, host: ~"127.0.0.1", port: port,
+                        credentials: Some((~"user", ~"pass")) };
+    let mut stream = proxy.connect("irc.example.com", 6697, None).unwrap();
+    assert_eq!(stream.read_bytes(5).unwrap().as_slice(), bytes!("hello"));
+}
+
+#[test]
+fn test_http_connect_refused() {
+    let port = stand_in(proc(s) {
+        let mut reader = BufferedReader::new(s.clone());
+        while reader.read_line().unwrap() != ~"\r\n" {}
+        let mut s = s;
+        s.write(bytes!("HTTP/1.1 403 Forbidden\r\n\r\n")).unwrap();
+    });
+    let proxy = Proxy { kind: HttpConnect, host: ~"127.0.0.1", port: port, credentials: None };
+    assert!(proxy.connect("irc.example.com", 6697, None).is_err());
+}
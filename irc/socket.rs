@@ -0,0 +1,194 @@
+// Outbound TCP sockets created through libc, as std's TcpStream can't be bound to a
+// local address before connecting
+
+use std::io::{IoResult, IoError, EndOfFile, standard_error};
+use std::io::net::ip::{SocketAddr, IpAddr, Ipv4Addr, Ipv6Addr};
+use std::libc;
+use std::mem;
+use std::os;
+use sync::Arc;
+
+#[allow(non_camel_case_types)]
+mod ffi {
+    use std::libc::{c_int, c_short, c_uint, c_void, socklen_t};
+
+    pub static POLLOUT: c_short = 4;
+    #[cfg(target_os = "linux")]
+    pub static SO_ERROR: c_int = 4;
+    #[cfg(not(target_os = "linux"))]
+    pub static SO_ERROR: c_int = 0x1007;
+
+    pub struct pollfd {
+        fd: c_int,
+        events: c_short,
+        revents: c_short
+    }
+
+    extern {
+        pub fn poll(fds: *mut pollfd, nfds: c_uint, timeout: c_int) -> c_int;
+        pub fn getsockopt(fd: c_int, level: c_int, name: c_int, value: *mut c_void,
+                          len: *mut socklen_t) -> c_int;
+    }
+}
+
+struct Fd {
+    fd: libc::c_int
+}
+
+impl Drop for Fd {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.fd); }
+    }
+}
+
+/// A connected socket. Clones share the descriptor, which is closed with the last clone.
+#[deriving(Clone)]
+pub struct FdStream {
+    priv fd: Arc<Fd>
+}
+
+impl Reader for FdStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        loop {
+            let n = unsafe {
+                libc::read(self.fd.get().fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len() as libc::size_t)
+            };
+            return match n {
+                n if n < 0 && interrupted() => continue,
+                n if n < 0 => Err(IoError::last_error()),
+                0          => Err(standard_error(EndOfFile)),
+                n          => Ok(n as uint)
+            };
+        }
+    }
+}
+
+impl Writer for FdStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        let mut written = 0;
+        while written < buf.len() {
+            let rest = buf.slice_from(written);
+            let n = unsafe {
+                libc::write(self.fd.get().fd, rest.as_ptr() as *libc::c_void, rest.len() as libc::size_t)
+            };
+            if n < 0 {
+                if interrupted() {
+                    continue;
+                }
+                return Err(IoError::last_error());
+            }
+            written += n as uint;
+        }
+        Ok(())
+    }
+}
+
+// Whether the last call failed because a signal arrived before it did anything
+fn interrupted() -> bool {
+    os::errno() as libc::c_int == libc::EINTR
+}
+
+fn htons(x: u16) -> u16 {
+    x.to_be()
+}
+
+// Fills a sockaddr for `ip`:`port`, returning it with its length
+fn sockaddr(ip: IpAddr, port: u16) -> (libc::sockaddr_storage, libc::socklen_t) {
+    unsafe {
+        let mut storage: libc::sockaddr_storage = mem::init();
+        match ip {
+            Ipv4Addr(a, b, c, d) => {
+                let addr: &mut libc::sockaddr_in = mem::transmute(&mut storage);
+                addr.sin_family = libc::AF_INET as libc::sa_family_t;
+                addr.sin_port = htons(port);
+                addr.sin_addr = libc::in_addr {
+                    s_addr: ((a as u32 << 24) | (b as u32 << 16) | (c as u32 << 8) | d as u32).to_be()
+                };
+                (storage, mem::size_of::<libc::sockaddr_in>() as libc::socklen_t)
+            },
+            Ipv6Addr(a, b, c, d, e, f, g, h) => {
+                let addr: &mut libc::sockaddr_in6 = mem::transmute(&mut storage);
+                addr.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+                addr.sin6_port = htons(port);
+                addr.sin6_addr = libc::in6_addr {
+                    s6_addr: [htons(a), htons(b), htons(c), htons(d), htons(e), htons(f), htons(g), htons(h)]
+                };
+                (storage, mem::size_of::<libc::sockaddr_in6>() as libc::socklen_t)
+            }
+        }
+    }
+}
+
+/// Connects to `addr`, first binding the socket to `bind` if given. Port 0 is used for
+/// the local side, letting the system pick one.
+pub fn connect(addr: SocketAddr, bind: Option<IpAddr>) -> IoResult<FdStream> {
+    let family = match addr.ip { Ipv4Addr(..) => libc::AF_INET, Ipv6Addr(..) => libc::AF_INET6 };
+    unsafe {
+        let fd = libc::socket(family, libc::SOCK_STREAM, 0);
+        if fd < 0 {
+            return Err(IoError::last_error());
+        }
+        // Closes the socket if we return early
+        let stream = FdStream { fd: Arc::new(Fd { fd: fd }) };
+
+        match bind {
+            Some(ip) => {
+                let (local, len) = sockaddr(ip, 0);
+                if libc::bind(fd, &local as *libc::sockaddr_storage as *libc::sockaddr, len) < 0 {
+                    return Err(IoError::last_error());
+                }
+            },
+            None => ()
+        }
+
+        let (remote, len) = sockaddr(addr.ip, addr.port);
+        if libc::connect(fd, &remote as *libc::sockaddr_storage as *libc::sockaddr, len) < 0 {
+            // An interrupted connect carries on in the background, and can't be restarted
+            if !interrupted() {
+                return Err(IoError::last_error());
+            }
+            try!(wait_connected(fd));
+        }
+        Ok(stream)
+    }
+}
+
+// Waits for the connect in progress on `fd` to finish, returning its outcome
+unsafe fn wait_connected(fd: libc::c_int) -> IoResult<()> {
+    let mut pfd = ffi::pollfd { fd: fd, events: ffi::POLLOUT, revents: 0 };
+    loop {
+        match ffi::poll(&mut pfd, 1, -1) {
+            n if n < 0 && interrupted() => continue,
+            n if n < 0 => return Err(IoError::last_error()),
+            _          => break
+        }
+    }
+    let mut error: libc::c_int = 0;
+    let mut len = mem::size_of::<libc::c_int>() as libc::socklen_t;
+    if ffi::getsockopt(fd, libc::SOL_SOCKET, ffi::SO_ERROR, &mut error as *mut libc::c_int as *mut libc::c_void,
+                       &mut len) < 0 {
+        return Err(IoError::last_error());
+    }
+    if error != 0 {
+        return Err(IoError::from_errno(error as uint, true));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_connect_bound() {
+    use std::io::net::tcp::TcpListener;
+    use std::io::{Acceptor, Listener};
+
+    let mut listener = TcpListener::bind(from_str::<SocketAddr>("127.0.0.1:0").unwrap()).unwrap();
+    let addr = listener.socket_name().unwrap();
+    let mut acceptor = listener.listen().unwrap();
+    spawn(proc() {
+        let mut s = acceptor.accept().unwrap();
+        assert_eq!(s.peer_name().unwrap().ip, from_str("127.0.0.1").unwrap());
+        s.write(bytes!("hello")).unwrap();
+    });
+
+    let mut stream = connect(addr, from_str("127.0.0.1")).unwrap();
+    assert_eq!(stream.read_bytes(5).unwrap().as_slice(), bytes!("hello"));
+}
@@ -1,10 +1,15 @@
 use irc;
 use irc::client::{Client, ClientMessage};
 use std;
+use std::io::net::ip::IpAddr;
+use collections::HashMap;
 use encoding::{Encoding, IrcEncoding};
 use buffer;
 use envelope::Envelope;
 use database;
+use identity;
+use identity::Identity;
+use channel;
 
 pub struct EncodingPolicy {
     network: Encoding,
@@ -28,10 +33,199 @@ pub enum State {
     NetworkConnected
 }
 
+// Maximum length of the channel and key lists in a single JOIN, leaving room for the
+// command itself and the line terminator within the 512 byte limit
+static MAX_JOIN_LENGTH: uint = 400;
+// Maximum length of a MONITOR or ISON nickname list
+static MAX_NICK_LIST_LENGTH: uint = 400;
+// Port used when the server address doesn't specify one
+static DEFAULT_PORT: u16 = 6667;
+// Channels per JOIN when the server doesn't advertise TARGMAX
+static DEFAULT_JOIN_TARGETS: uint = 4;
+
+// IRCv3 capabilities we request if the server offers them
+static WANTED_CAPS: &'static [&'static str] = &["server-time", "batch", "draft/chathistory", "labeled-response",
+                                                "message-tags", "account-tag", "account-notify",
+                                                "away-notify", "extended-join", "chghost", "setname",
+                                                "multi-prefix", "userhost-in-names"];
+
+// Messages per CHATHISTORY request when the server doesn't limit it
+static DEFAULT_HISTORY_LIMIT: uint = 1000;
+
+// Timer kinds passed to set_timer
+static TIMER_PERFORM: u64 = 1;
+static TIMER_SERVICE_AUTH: u64 = 2;
+static TIMER_ISON: u64 = 3;
+// Timer ids carry the connection generation above the kind, so timers set for an
+// earlier connection are told apart
+static TIMER_KIND_BITS: uint = 4;
+
+// How long to hold back autojoin while waiting for services to confirm identification
+static SERVICE_AUTH_TIMEOUT_MS: u64 = 10000;
+
+// Starts of the notices services send once identified, formatting removed
+static SERVICE_AUTH_SUCCESS: &'static [&'static str] = &[
+    "You are now identified for ", // Atheme
+    "Password accepted - you are now recognized.", // Anope
+    "You are now logged in as ", // QuakeNet Q
+    "You are successfully identified as " // Anope 2
+];
+
+// How often buddies are polled with ISON on servers without MONITOR
+static ISON_INTERVAL_MS: u64 = 60000;
+
+/// Identification to a services bot for networks without SASL. `command` is sent to
+/// `service` as a PRIVMSG after `{nick}` and `{password}` have been substituted,
+/// e.g. "IDENTIFY {password}" for NickServ or "AUTH {nick} {password}" for Q.
+#[deriving(Clone)]
+pub struct ServiceAuth {
+    service: ~str,
+    command: ~str,
+    password: ~str
+}
+
+/// WEBIRC gateway credentials. The hostname and IP forwarded with them are the
+/// session's client address.
+#[deriving(Clone)]
+pub struct WebIrc {
+    password: ~str,
+    gateway: ~str
+}
+
 #[deriving(Clone)]
 pub struct Configuration {
-    server: ~str,
-    nickname: ~str
+    server: ~str, // host or host:port
+    nickname: ~str,
+    service_auth: Option<ServiceAuth>,
+    identity_id: Option<u64>,
+    proxy: Option<irc::proxy::Proxy>,
+    bind_address: Option<IpAddr>, // local address (vhost) to connect from
+    webirc: Option<WebIrc>
+}
+
+/// Splits a server address into host and port. IPv6 addresses with a port must be
+/// enclosed in brackets, as in "[::1]:6667".
+pub fn parse_server(server: &str) -> Option<(~str, u16)> {
+    let server = server.trim();
+    if server.starts_with("[") {
+        let end = match server.find(']') { Some(i) => i, None => return None };
+        let host = server.slice(1, end).to_owned();
+        let rest = server.slice_from(end + 1);
+        if rest.is_empty() {
+            return Some((host, DEFAULT_PORT));
+        }
+        if !rest.starts_with(":") {
+            return None;
+        }
+        return from_str(rest.slice_from(1)).map(|port| (host, port));
+    }
+    match server.rfind(':') {
+        // More than one colon means a bare IPv6 address
+        Some(i) if server.slice_to(i).find(':').is_none() =>
+            from_str(server.slice_from(i + 1)).map(|port| (server.slice_to(i).to_owned(), port)),
+        _ if server.is_empty() => None,
+        _ => Some((server.to_owned(), DEFAULT_PORT))
+    }
+}
+
+impl Configuration {
+    /// Fills in the passwords left empty from `old`. Remotes never see the stored
+    /// passwords, so they leave them empty to keep them.
+    pub fn keep_passwords(&mut self, old: &Configuration) {
+        match (&mut self.service_auth, &old.service_auth) {
+            (&Some(ref mut new), &Some(ref old)) => keep_password(&mut new.password, &old.password),
+            _ => ()
+        }
+        match (&mut self.webirc, &old.webirc) {
+            (&Some(ref mut new), &Some(ref old)) => keep_password(&mut new.password, &old.password),
+            _ => ()
+        }
+        match (&mut self.proxy, &old.proxy) {
+            (&Some(ref mut new), &Some(ref old)) => match (&mut new.credentials, &old.credentials) {
+                (&Some((_, ref mut new)), &Some((_, ref old))) => keep_password(new, old),
+                _ => ()
+            },
+            _ => ()
+        }
+    }
+}
+
+fn keep_password(new: &mut ~str, old: &~str) {
+    if new.is_empty() {
+        *new = old.clone();
+    }
+}
+
+/// Removes IRC bold, color, italic, underline and reverse codes.
+fn strip_formatting(text: &str) -> ~str {
+    let mut out = std::str::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    loop {
+        match chars.next() {
+            Some('\x03') => {
+                // Up to two digits of foreground, then optionally a comma and background
+                for _ in range(0, 2) {
+                    if chars.peek().map_or(false, |c| c.is_digit()) { chars.next(); }
+                }
+                if chars.peek() == Some(&',') {
+                    chars.next();
+                    for _ in range(0, 2) {
+                        if chars.peek().map_or(false, |c| c.is_digit()) { chars.next(); }
+                    }
+                }
+            },
+            Some('\x02') | Some('\x0f') | Some('\x16') | Some('\x1d') | Some('\x1f') => (),
+            Some(c) => out.push_char(c),
+            None    => return out
+        }
+    }
+}
+
+/// Raw IRC lines sent after registration, in order
+#[deriving(Clone)]
+pub struct PerformList {
+    commands: ~[~str],
+    delay_ms: u64 // delay between commands
+}
+
+impl std::default::Default for PerformList {
+    fn default() -> PerformList {
+        PerformList {
+            commands: ~[],
+            delay_ms: 0
+        }
+    }
+}
+
+/// A buffer as sent to a remote when it attaches
+pub struct BufferSnapshot {
+    id: u64,
+    role: buffer::Role,
+    stored_messages: u64,
+    last_read: Option<u64>,
+    unread: u64,
+    topic: Option<~str>, // channels only
+    members: ~[(channel::User, ~str)] // with membership prefixes, channels only
+}
+
+/// Everything a remote needs to show a network when it attaches
+pub struct Snapshot {
+    id: u64,
+    state: State,
+    configuration: Option<Configuration>,
+    nickname: Option<~str>, // current nickname
+    away: Option<~str>,
+    buffers: ~[BufferSnapshot]
+}
+
+// Batch types whose lines are held back and handled together once the batch ends
+static COLLECTED_BATCHES: &'static [&'static str] = &["chathistory", "netsplit", "netjoin"];
+
+// Lines of an open BATCH, handled together once it ends
+struct Batch {
+    kind: ~[u8],
+    params: ~[~[u8]],
+    lines: ~[(irc::parser::Tags, irc::parser::Message)]
 }
 
 pub struct Network {
@@ -43,6 +237,32 @@ pub struct Network {
     buffers: ~[buffer::Buffer],
     state: State,
     nickname: Option<~[u8]>, // current nickname
+    nickname_attempts: uint, // nicknames tried during registration
+    identity: Option<Identity>,
+    client_address: Option<(~str, ~str)>, // hostname and IP sent with WEBIRC, set by the session
+    away: Option<~str>, // away message as confirmed by the server
+    away_requested: Option<~str>, // last away message sent to the server
+    explicit_away: Option<~str>, // away message set by a remote
+    auto_away: bool, // no remotes attached and the identity wants auto-away
+    isupport: HashMap<~str, Option<~str>>, // last known RPL_ISUPPORT tokens
+    caps: ~[~str], // enabled IRCv3 capabilities
+    caps_offered: ~[~str], // capabilities listed so far in a multiline CAP LS
+    batches: HashMap<~[u8], Batch>, // open batches by reference
+    join_keys: HashMap<~str, ~str>, // keys of channels we are joining, by lowercase name
+    perform: PerformList,
+    perform_queue: ~[~str], // perform commands not yet sent, in reverse order
+    service_auth_pending: bool, // autojoin is waiting for services
+    generation: u64, // bumped whenever a connection ends
+    channels: HashMap<~str, channel::Channel>, // channels we are on, by lowercase name
+    users: HashMap<~str, channel::User>, // users sharing a channel with us, by lowercase nick
+    buddies: ~[~str], // nicknames whose presence is tracked besides query partners
+    presence: HashMap<~str, bool>, // last known presence by lowercase nick
+    ison_polling: bool, // the ISON timer is running
+    ison_queries: ~[~[u8]], // nick lists of ISON lines awaiting a reply, oldest first
+    next_label: u64,
+    pending_labels: HashMap<~[u8], Envelope<()>>, // labeled commands awaiting a reply, by label
+    labeled_batches: HashMap<~[u8], (Envelope<()>, ~[~str])>, // labeled-response batches by reference
+    labeled_nested: HashMap<~[u8], ~[u8]>, // batches inside labeled-response batches, to the outer reference
 
     config: Option<Configuration>
 }
@@ -59,17 +279,56 @@ impl Network {
             buffers: ~[],
             state: NetworkDisconnected,
             nickname: None,
+            nickname_attempts: 0,
+            identity: None,
+            client_address: None,
+            away: None,
+            away_requested: None,
+            explicit_away: None,
+            auto_away: false,
+            isupport: HashMap::new(),
+            caps: ~[],
+            caps_offered: ~[],
+            batches: HashMap::new(),
+            join_keys: HashMap::new(),
+            perform: std::default::Default::default(),
+            perform_queue: ~[],
+            service_auth_pending: false,
+            generation: 0,
+            channels: HashMap::new(),
+            users: HashMap::new(),
+            buddies: ~[],
+            presence: HashMap::new(),
+            ison_polling: false,
+            ison_queries: ~[],
+            next_label: 0,
+            pending_labels: HashMap::new(),
+            labeled_batches: HashMap::new(),
+            labeled_nested: HashMap::new(),
             config: None
         }
     }
 
-    pub fn handle_message(&mut self, reply: |msg::Message|) {
+    /// Handles the next message from the IRC client. Replies to labeled commands are
+    /// addressed to the remote that sent the command, everything else to all remotes.
+    pub fn handle_message(&mut self, reply: |Envelope<msg::Message>|) {
         let msg = match self.rx.recv_opt() {
             Some(m) => m,
             None    => return
         };
         println!("{:?}", msg);
 
+        match msg {
+            irc::client::Message(ref tags, ref msg, ref raw) => match self.labeled_reply(tags, msg, *raw) {
+                Some(labeled) => reply(labeled),
+                None          => ()
+            },
+            _ => ()
+        }
+        self.handle_client_message(msg, |m| reply(Envelope::empty(m)))
+    }
+
+    fn handle_client_message(&mut self, msg: ClientMessage, reply: |msg::Message|) {
         match msg {
             irc::client::ConnectionError(err) => {
                 // Client has died, make a new one
@@ -78,35 +337,265 @@ impl Network {
                 self.client = cli;
                 self.rx = rx;
                 self.state = NetworkDisconnected;
+                self.channels.clear();
+                self.users.clear();
+                self.presence.clear();
+                self.ison_polling = false;
+                self.ison_queries.clear();
+                self.generation += 1;
                 reply(msg::Disconnected(err.desc.to_owned()))
             },
-            irc::client::Message(msg) => {
+            // Set for an earlier connection
+            irc::client::Timer(id) if id >> TIMER_KIND_BITS != self.generation => (),
+            irc::client::Timer(id) => match id & ((1 << TIMER_KIND_BITS) - 1) {
+                TIMER_PERFORM => self.run_perform(),
+                TIMER_SERVICE_AUTH => {
+                    if self.service_auth_pending {
+                        self.service_auth_pending = false;
+                        self.reply_buffer(|x| reply(x), buffer::Status,
+                                          buffer::Information(~"No response from services, joining channels"));
+                        self.rejoin_channels();
+                    }
+                },
+                TIMER_ISON => {
+                    match self.state {
+                        NetworkConnected if self.ison_polling => {
+                            self.send_ison();
+                            self.set_timer(ISON_INTERVAL_MS, TIMER_ISON);
+                        },
+                        _ => self.ison_polling = false
+                    }
+                },
+                _ => ()
+            },
+            irc::client::Message(tags, msg, _) => {
+                // Batches nested in a collected one are opened and closed as usual, and
+                // collect their own lines
+                let nested_batch = match msg {
+                    irc::parser::BatchStart(..) | irc::parser::BatchEnd(..) => true,
+                    _ => false
+                };
+                let batch = tags.get(bytes!("batch")).map(|r| r.to_owned());
+                match batch {
+                    Some(ref r) if !nested_batch && self.batches.contains_key(r) => {
+                        self.batches.get_mut(r).lines.push((tags, msg));
+                        return;
+                    },
+                    _ => ()
+                }
+
                 match msg {
                     irc::parser::Ping(ref sender) => self.client.pong(*sender),
-                    irc::parser::Welcome(_) => {
+                    irc::parser::Cap(subcommand, caps, more) => self.handle_cap(subcommand, caps, more),
+                    irc::parser::BatchStart(reference, kind, params) => {
+                        if COLLECTED_BATCHES.iter().any(|k| k.as_bytes() == kind.as_slice()) {
+                            self.batches.insert(reference, Batch { kind: kind, params: params, lines: ~[] });
+                        }
+                    },
+                    irc::parser::BatchEnd(reference) => {
+                        match self.batches.pop(&reference) {
+                            Some(Batch { kind, params, lines }) => {
+                                match kind.as_slice() {
+                                    k if k == bytes!("chathistory") => match params.move_iter().next() {
+                                        Some(target) => self.backfill(|x| reply(x), target, lines),
+                                        None         => ()
+                                    },
+                                    k if k == bytes!("netsplit") => self.netsplit(|x| reply(x), params, lines),
+                                    k if k == bytes!("netjoin")  => self.netjoin(|x| reply(x), params, lines),
+                                    _ => ()
+                                }
+                            },
+                            None => ()
+                        }
+                    },
+                    irc::parser::Welcome(nickname) => {
                         self.state = NetworkConnected;
+                        self.nickname = Some(nickname);
+                        self.away = None;
+                        self.away_requested = None;
+                        self.update_away();
                         reply(msg::Connected);
-                        self.reply_buffer(reply, buffer::Status,
+                        self.reply_buffer(|x| reply(x), buffer::Status,
                                           buffer::Information(~"Welcome to IRC!"));
+                        self.service_auth_pending = self.identify_to_services();
+                        self.perform_queue = self.perform.commands.iter().rev().map(|c| c.clone()).collect();
+                        self.run_perform();
+                        if self.service_auth_pending {
+                            self.set_timer(SERVICE_AUTH_TIMEOUT_MS, TIMER_SERVICE_AUTH);
+                        } else {
+                            self.rejoin_channels();
+                        }
+
+                        // Channel history is requested once we have joined them
+                        let queries: ~[(~str, Option<u64>)] = self.buffers.mut_iter().filter_map(|buf| {
+                            let name = match buf.role {
+                                buffer::Query(ref name) => name.clone(),
+                                _ => return None
+                            };
+                            Some((name, buf.latest_time()))
+                        }).collect();
+                        for (name, since) in queries.move_iter() {
+                            let name = self.encoding.network.encode(&name);
+                            self.request_history(name, since);
+                        }
+                    },
+                    irc::parser::EndOfMotd => self.start_presence(),
+                    irc::parser::MonOnline(targets) => {
+                        for target in targets.iter() {
+                            self.set_presence(|x| reply(x), irc::parser::prefix_nick(*target), true);
+                        }
+                    },
+                    irc::parser::MonOffline(targets) => {
+                        for target in targets.iter() {
+                            self.set_presence(|x| reply(x), *target, false);
+                        }
+                    },
+                    irc::parser::Ison(online) => {
+                        // ISON only lists who is online, everyone else asked about in
+                        // the line it answers is not
+                        let asked = match self.ison_queries.shift() {
+                            Some(list) => list,
+                            None       => return
+                        };
+                        let online_l: ~[~str] = online.iter().map(|n| self.nick_key(*n)).collect();
+                        for nick in asked.split(|&b| b == ' ' as u8) {
+                            let is_online = online_l.contains(&self.nick_key(nick));
+                            self.set_presence(|x| reply(x), nick, is_online);
+                        }
+                    },
+                    irc::parser::NicknameInUse(nickname) => {
+                        match self.state {
+                            NetworkConnecting => self.try_next_nickname(nickname),
+                            _ => ()
+                        }
+                    },
+                    irc::parser::Away(nick, message) => {
+                        let nick_l = self.encoding.network.decode(nick.irc_lowercase());
+                        let nick = self.encoding.network.decode(nick);
+                        let message = self.encoding.incoming.decode(message);
+                        self.reply_buffer(reply, buffer::Query(nick_l),
+                                          buffer::Information(format!("{} is away: {}", nick, message)));
+                    },
+                    irc::parser::NowAway => {
+                        self.away = Some(self.away_requested.clone().unwrap_or(~""));
+                        reply(msg::AwayState(self.away.clone()));
+                    },
+                    irc::parser::Unaway => {
+                        self.away = None;
+                        reply(msg::AwayState(None));
                     },
-                    irc::parser::Join(who, channel) => {
+                    irc::parser::LoggedIn(_) => self.service_auth_confirmed(|x| reply(x)),
+                    irc::parser::Notice(who, _, msg) => {
+                        // For services that don't send RPL_LOGGEDIN
+                        if self.service_auth_pending && self.is_service_prefix(who) {
+                            let msg = strip_formatting(self.encoding.incoming.decode(msg));
+                            if SERVICE_AUTH_SUCCESS.iter().any(|s| msg.starts_with(*s)) {
+                                self.service_auth_confirmed(|x| reply(x));
+                            }
+                        }
+                    },
+                    irc::parser::Isupport(tokens) => {
+                        for token in tokens.iter() {
+                            let token = self.encoding.network.decode(*token);
+                            match token.find('=') {
+                                Some(i) => self.isupport.insert(token.slice_to(i).to_owned(),
+                                                                Some(token.slice_from(i + 1).to_owned())),
+                                None    => self.isupport.insert(token, None)
+                            };
+                        }
+                    },
+                    irc::parser::Join(who, channel, account, realname) => {
                         let channel_l = self.encoding.network.decode(channel.irc_lowercase());
-                        let who = self.encoding.network.decode(who);
-                        self.reply_buffer(reply,
-                                buffer::Channel(channel_l),
-                                buffer::Join(who));
+                        let own = self.is_own_prefix(who);
+                        let mut history_since = None;
+                        if own {
+                            let key = self.join_keys.pop(&channel_l);
+                            let buffer = self.get_buffer(|x| reply(x), buffer::Channel(channel_l.clone()));
+                            let key = key.or(buffer.key.clone());
+                            buffer.set_autojoin(true, key);
+                            history_since = buffer.latest_time();
+                        }
+                        self.user_joined(|x| reply(x), channel_l.clone(), who, account, realname);
+                        let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
+                        let msg = self.stamp(&tags, who, buffer::Join(nick));
+                        self.reply_buffer_message(reply, buffer::Channel(channel_l), msg);
+                        if own {
+                            self.request_history(channel, history_since);
+                        }
+                    },
+                    irc::parser::Part(who, channel, _) => {
+                        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                        let own = self.is_own_prefix(who);
+                        self.user_left(&channel_l, irc::parser::prefix_nick(who), own);
+                        if own {
+                            let buffer = self.get_buffer(|x| reply(x), buffer::Channel(channel_l));
+                            buffer.set_autojoin(false, None);
+                        }
+                    },
+                    irc::parser::Kick(_, channel, kicked, _) => {
+                        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                        let own = self.nickname.as_ref().map_or(false, |n| n.irc_equal(&kicked));
+                        self.user_left(&channel_l, kicked, own);
+                    },
+                    irc::parser::Quit(who, _) => { self.user_quit(who); },
+                    irc::parser::Nick(who, nickname) => self.user_renamed(who, nickname),
+                    irc::parser::Names(channel, entries) => self.add_names(channel, entries),
+                    irc::parser::EndOfNames(channel) => {
+                        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                        match self.channels.find_mut(&channel_l) {
+                            Some(chan) => chan.names_complete = true,
+                            None       => ()
+                        }
+                        // Users dropped from a fresh NAMES listing
+                        let keys: ~[~str] = self.users.keys().map(|k| k.clone()).collect();
+                        for key in keys.move_iter() {
+                            self.forget_user(key);
+                        }
+                    },
+                    irc::parser::Account(who, account) => {
+                        let account = account.map(|a| self.encoding.network.decode(a));
+                        self.update_user(reply, who, |user| user.account = account.clone());
+                    },
+                    irc::parser::AwayNotify(who, message) => {
+                        let message = message.map(|m| self.encoding.incoming.decode(m));
+                        self.update_user(reply, who, |user| user.away = message.clone());
+                    },
+                    irc::parser::Chghost(who, ident, host) => {
+                        let ident = self.encoding.network.decode(ident);
+                        let host = self.encoding.network.decode(host);
+                        self.update_user(reply, who, |user| {
+                            user.ident = Some(ident.clone());
+                            user.host = Some(host.clone());
+                        });
+                    },
+                    irc::parser::Setname(who, realname) => {
+                        let realname = self.encoding.incoming.decode(realname);
+                        self.update_user(reply, who, |user| user.realname = Some(realname.clone()));
+                    },
+                    irc::parser::Topic(_, channel, topic) => {
+                        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                        let topic = topic.map(|t| self.encoding.incoming.decode(t));
+                        self.set_topic(reply, channel_l, topic);
+                    },
+                    irc::parser::TopicIs(channel, topic) => {
+                        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                        let topic = self.encoding.incoming.decode(topic);
+                        self.set_topic(reply, channel_l, Some(topic));
                     },
                     irc::parser::Privmsg(who, target, msg) => {
                         if target.irc_equal(self.nickname.get_ref()) {
-                            let who_l = self.encoding.network.decode(who.irc_lowercase());
-                            let who = self.encoding.network.decode(who);
+                            let who_l = self.encoding.network.decode(
+                                irc::parser::prefix_nick(who).to_owned().irc_lowercase());
+                            let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
                             let msg = self.encoding.incoming.decode(msg);
-                            self.reply_buffer(reply, buffer::Query(who_l), buffer::Privmsg(who, msg));
+                            let msg = self.stamp(&tags, who, buffer::Privmsg(nick, msg));
+                            self.reply_buffer_message(reply, buffer::Query(who_l), msg);
                         } else {
                             let target_l = self.encoding.network.decode(target.irc_lowercase());
-                            let who = self.encoding.network.decode(who);
+                            let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
                             let msg = self.encoding.incoming.decode(msg);
-                            self.reply_buffer(reply, buffer::Channel(target_l), buffer::Privmsg(who, msg));
+                            let msg = self.stamp(&tags, who, buffer::Privmsg(nick, msg));
+                            self.reply_buffer_message(reply, buffer::Channel(target_l), msg);
                         }
                     }
                     _ => ()
@@ -116,23 +605,68 @@ impl Network {
     }
 
     pub fn handle_command(&mut self, cmd: Envelope<msg::Command>, reply: |Envelope<msg::Message>|) {
+        let bare = cmd.bare();
+
+        // Commands needing methods of the network, which can't be called once its fields
+        // are borrowed below
+        let contents = match cmd.contents {
+            msg::SetAway(message) => {
+                self.explicit_away = message;
+                self.update_away();
+                return reply(bare.copy_with(msg::Success));
+            },
+            msg::SetBuddyList(buddies) => {
+                self.db.update_network_buddies(self.id, buddies);
+                self.buddies = buddies;
+                match self.state {
+                    NetworkConnected => self.start_presence(),
+                    _                => ()
+                }
+                return reply(bare.copy_with(msg::Success));
+            },
+            contents => contents
+        };
+
+        let labeled = self.has_cap("labeled-response");
         let &Network { ref mut client, ref encoding, ref mut config, .. } = self;
         let &EncodingPolicy { network: ref en, outgoing: ref eo, .. } = encoding;
 
-        let bare = cmd.bare();
-
-        match cmd.contents {
+        match contents {
             msg::Connect => {
                 match config {
                     &Some(ref config) => {
-                        match from_str(config.server) {
-                            Some(server) => {
+                        match parse_server(config.server) {
+                            Some((host, port)) => {
+                                let (username, realname) = match self.identity {
+                                    Some(ref identity) => (identity.ident.clone(), identity.realname.clone()),
+                                    None               => (config.nickname.clone(), config.nickname.clone())
+                                };
                                 self.state = NetworkConnecting;
                                 self.nickname = Some(en.encode(&config.nickname));
-                                client.connect(server);
+                                self.nickname_attempts = 0;
+                                self.caps.clear();
+                                self.caps_offered.clear();
+                                self.batches.clear();
+                                self.channels.clear();
+                                self.users.clear();
+                                self.presence.clear();
+                                self.ison_polling = false;
+                                self.ison_queries.clear();
+                                self.pending_labels.clear();
+                                self.labeled_batches.clear();
+                                self.labeled_nested.clear();
+                                client.connect(host, port, config.proxy.as_ref(), config.bind_address);
+                                let webirc = match (&config.webirc, &self.client_address) {
+                                    (&Some(ref webirc), &Some((ref hostname, ref ip))) =>
+                                        Some((en.encode(&webirc.password), en.encode(&webirc.gateway),
+                                              en.encode(hostname), en.encode(ip))),
+                                    _ => None
+                                };
                                 client.register(en.encode(&config.nickname),
-                                                en.encode(&config.nickname),
-                                                eo.encode(&config.nickname));
+                                                en.encode(&username),
+                                                eo.encode(&realname),
+                                                webirc.as_ref().map(|&(ref p, ref g, ref h, ref i)|
+                                                    (p.as_slice(), g.as_slice(), h.as_slice(), i.as_slice())));
                                 reply(bare.copy_with(msg::Success));
                             },
                             None => reply(bare.copy_with(msg::Error(~"invalid server")))
@@ -143,14 +677,44 @@ impl Network {
             },
             msg::Disconnect => {
                 self.state = NetworkDisconnected;
-                client.quit(eo.encode(&~"So long, and thanks for all the fish."));
+                self.generation += 1;
+                let quit_message = match self.identity {
+                    Some(ref identity) => identity.quit_message(),
+                    None               => identity::default_quit_message()
+                };
+                client.quit(eo.encode(&quit_message));
                 client.disconnect();
                 reply(bare.copy_with(msg::Success));
             }
-            msg::JoinChannel(channel) => {
-                client.join(en.encode(&channel));
+            msg::JoinChannel(channel, key) => {
+                match key {
+                    Some(ref key) => {
+                        let channel_l = en.decode(en.encode(&channel).irc_lowercase());
+                        self.join_keys.insert(channel_l, key.clone());
+                    },
+                    None => ()
+                }
+                client.join(en.encode(&channel), key.map(|k| en.encode(&k)).as_ref().map(|k| k.as_slice()));
                 reply(bare.copy_with(msg::Success));
             },
+            msg::PartChannel(channel, message) => {
+                let message = message.or(self.identity.as_ref().and_then(|i| i.part_message()));
+                client.part(en.encode(&channel), message.map(|m| eo.encode(&m)).as_ref().map(|m| m.as_slice()));
+                reply(bare.copy_with(msg::Success));
+            },
+            msg::SendCommand(line) => {
+                let line = eo.encode(&line);
+                if labeled {
+                    // Success is implied by the CommandReply carrying the server's answer
+                    let label = self.next_label.to_str().into_bytes();
+                    self.next_label += 1;
+                    client.send_labeled(label, line);
+                    self.pending_labels.insert(label, bare);
+                } else {
+                    client.send_raw(line);
+                    reply(bare.copy_with(msg::Success));
+                }
+            },
             msg::SendPrivmsg(target, message) => {
                 client.privmsg(en.encode(&target), eo.encode(&message));
                 reply(bare.copy_with(msg::Success));
@@ -160,7 +724,11 @@ impl Network {
                     (buf.id, buf.role.clone(), buf.stored_messages)).collect();
                 reply(bare.copy_with(msg::BufferList(data)));
             },
-            msg::SetConfiguration(cfg) => {
+            msg::SetConfiguration(mut cfg) => {
+                match *config {
+                    Some(ref old) => cfg.keep_passwords(old),
+                    None          => ()
+                }
                 self.db.update_network_configuration(self.id, &cfg);
                 *config = Some(cfg);
                 reply(bare.copy_with(msg::Success));
@@ -168,13 +736,758 @@ impl Network {
             msg::GetConfiguration => {
                 reply(bare.copy_with(msg::Configuration(config.clone())))
             },
+            msg::SetPerform(perform) => {
+                self.db.update_network_perform(self.id, &perform);
+                self.perform = perform;
+                reply(bare.copy_with(msg::Success));
+            },
+            msg::GetPerform => {
+                reply(bare.copy_with(msg::Perform(self.perform.clone())))
+            },
+            msg::GetBuddyList => {
+                reply(bare.copy_with(msg::BuddyList(self.buddies.clone())))
+            },
+            msg::SetLastRead(bufid, message_id) => {
+                match self.buffers.mut_iter().find(|b| b.id == bufid) {
+                    Some(buf) => {
+                        buf.set_last_read(message_id);
+                        reply(bare.copy_with(msg::Success));
+                        // Other remotes move their marker too
+                        reply(Envelope::empty(msg::LastRead(bufid, message_id)));
+                    },
+                    None => reply(bare.copy_with(msg::Error(~"invalid buffer specified")))
+                }
+            },
             msg::GetBufferMessageRange(bufid, count, before_id) => {
                 match self.buffers.mut_iter().find(|b| b.id == bufid) {
                     Some(buf) => reply(bare.copy_with(
                         msg::BufferMessageRange(bufid, buf.fetch_message_range(count, before_id)))),
                     None => reply(bare.copy_with(msg::Error(~"invalid buffer specified")))
                 }
+            },
+            // Handled above
+            msg::SetAway(..) | msg::SetBuddyList(..) => ()
+        }
+    }
+
+    // Collects the reply to a labeled command. It is returned addressed to the remote that
+    // sent the command once complete, which for a labeled-response batch is at its end.
+    fn labeled_reply(&mut self, tags: &irc::parser::Tags, msg: &irc::parser::Message,
+                     raw: &[u8]) -> Option<Envelope<msg::Message>> {
+        let line = self.encoding.incoming.decode(raw);
+
+        // Lines of batches nested in a labeled batch, such as the chathistory batch
+        // answering a CHATHISTORY command, belong to the labeled batch
+        let outer = tags.get(bytes!("batch")).and_then(|reference| {
+            let reference = reference.to_owned();
+            if self.labeled_batches.contains_key(&reference) {
+                Some(reference)
+            } else {
+                self.labeled_nested.find(&reference).map(|r| r.clone())
             }
+        });
+        let outer = match (outer, msg) {
+            (Some(outer), _) => Some(outer),
+            // The end of a nested batch need not be tagged with the outer one
+            (None, &irc::parser::BatchEnd(ref reference)) => self.labeled_nested.find(reference).map(|r| r.clone()),
+            (None, _) => None
+        };
+        match outer {
+            Some(outer) => {
+                match *msg {
+                    irc::parser::BatchStart(ref reference, _, _) => {
+                        self.labeled_nested.insert(reference.clone(), outer.clone());
+                    },
+                    irc::parser::BatchEnd(ref reference) => {
+                        self.labeled_nested.pop(reference);
+                    },
+                    _ => ()
+                }
+                match self.labeled_batches.find_mut(&outer) {
+                    Some(&(_, ref mut lines)) => lines.push(line),
+                    None => ()
+                }
+                return None;
+            },
+            None => ()
+        }
+        match *msg {
+            irc::parser::BatchEnd(ref reference) =>
+                return self.labeled_batches.pop(reference).map(|(origin, lines)| {
+                    origin.copy_with(msg::CommandReply(lines))
+                }),
+            _ => ()
+        }
+
+        let origin = match tags.get(bytes!("label")).and_then(|l| self.pending_labels.pop(&l.to_owned())) {
+            Some(origin) => origin,
+            None         => return None
+        };
+        match *msg {
+            irc::parser::BatchStart(ref reference, ref kind, _) if kind.as_slice() == bytes!("labeled-response") => {
+                self.labeled_batches.insert(reference.clone(), (origin, ~[]));
+                None
+            },
+            // An empty reply
+            irc::parser::Unknown(ref raw) if raw.command.as_slice() == bytes!("ACK") =>
+                Some(origin.copy_with(msg::CommandReply(~[]))),
+            _ => Some(origin.copy_with(msg::CommandReply(~[line])))
+        }
+    }
+
+    fn has_cap(&self, cap: &str) -> bool {
+        self.caps.iter().any(|c| c.as_slice() == cap)
+    }
+
+    fn handle_cap(&mut self, subcommand: ~[u8], caps: ~[~[u8]], more: bool) {
+        let caps: ~[~str] = caps.iter().map(|c| self.encoding.network.decode(*c)).collect();
+        let registering = match self.state { NetworkConnecting => true, _ => false };
+
+        match subcommand.as_slice() {
+            b if b == bytes!("LS") || b == bytes!("NEW") => {
+                // CAP LS 302 values (sasl=PLAIN,...) aren't needed for anything yet
+                self.caps_offered.extend(caps.move_iter().map(|c| {
+                    match c.find('=') {
+                        Some(i) => c.slice_to(i).to_owned(),
+                        None    => c
+                    }
+                }));
+                if more {
+                    return;
+                }
+                let offered = std::mem::replace(&mut self.caps_offered, ~[]);
+                let wanted: ~[&str] = WANTED_CAPS.iter().map(|c| *c)
+                    .filter(|&c| offered.iter().any(|o| o.as_slice() == c) && !self.has_cap(c))
+                    .collect();
+                if !wanted.is_empty() {
+                    self.client.cap(bytes!("REQ"), Some(wanted.connect(" ").as_bytes()));
+                } else if registering {
+                    self.client.cap(bytes!("END"), None);
+                }
+            },
+            b if b == bytes!("ACK") => {
+                for cap in caps.move_iter() {
+                    if cap.starts_with("-") {
+                        let name = cap.slice_from(1).to_owned();
+                        self.caps.retain(|c| *c != name);
+                    } else if !self.has_cap(cap.as_slice()) {
+                        self.caps.push(cap);
+                    }
+                }
+                if registering {
+                    self.client.cap(bytes!("END"), None);
+                }
+            },
+            b if b == bytes!("NAK") => {
+                if registering {
+                    self.client.cap(bytes!("END"), None);
+                }
+            },
+            b if b == bytes!("DEL") => {
+                for cap in caps.iter() {
+                    self.caps.retain(|c| c != cap);
+                }
+            },
+            _ => ()
+        }
+    }
+
+    fn is_channel(&self, name: &[u8]) -> bool {
+        let chantypes = match self.isupport.find_equiv(&"CHANTYPES") {
+            Some(&Some(ref types)) => types.clone(),
+            _                      => ~"#&"
+        };
+        name.len() > 0 && chantypes.as_bytes().contains(&name[0])
+    }
+
+    // Asks the server for messages to `target` that we missed while disconnected
+    fn request_history(&mut self, target: ~[u8], since: Option<u64>) {
+        let since = match since {
+            Some(since) if self.has_cap("draft/chathistory") => since,
+            _ => return
+        };
+        let limit = match self.isupport.find_equiv(&"CHATHISTORY") {
+            Some(&Some(ref limit)) => from_str(*limit).unwrap_or(0),
+            _                      => 0
+        };
+        let limit = if limit == 0 { DEFAULT_HISTORY_LIMIT } else { limit };
+        self.client.chathistory_after(target, irc::parser::format_server_time(since).as_bytes(), limit);
+    }
+
+    // Stores the messages of a chathistory batch that aren't in the buffer yet
+    fn backfill(&mut self, reply: |msg::Message|, target: ~[u8],
+                lines: ~[(irc::parser::Tags, irc::parser::Message)]) {
+        let target_l = self.encoding.network.decode(target.irc_lowercase());
+        let role = if self.is_channel(target) { buffer::Channel(target_l) } else { buffer::Query(target_l) };
+
+        let mut messages = ~[];
+        for (tags, line) in lines.move_iter() {
+            match line {
+                irc::parser::Privmsg(who, _, msg) => {
+                    let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
+                    let msg = self.encoding.incoming.decode(msg);
+                    messages.push(self.stamp(&tags, who, buffer::Privmsg(nick, msg)));
+                },
+                _ => ()
+            }
+        }
+
+        let buffer = self.get_buffer(|x| reply(x), role);
+        let mut added = ~[];
+        for msg in messages.move_iter() {
+            if !buffer.contains(&msg) {
+                buffer.add(msg, |msg| added.push(msg));
+            }
+        }
+        if !added.is_empty() {
+            reply(msg::BufferBackfill(buffer.id, added));
+        }
+    }
+
+    // Creates a buffer message for a line received from the server with the sender
+    // `prefix`, using its server-time, msgid and account tags when available
+    fn stamp(&self, tags: &irc::parser::Tags, prefix: &[u8], cont: buffer::MessageContents) -> buffer::Message {
+        let en = &self.encoding.network;
+        let server_time = if self.has_cap("server-time") {
+            tags.get(bytes!("time")).and_then(irc::parser::parse_server_time)
+        } else {
+            None
+        };
+        let mut msg = match server_time {
+            Some(time) => buffer::Message::at(time, cont),
+            None       => buffer::Message::now(cont)
+        };
+        msg.msgid = tags.get(bytes!("msgid")).map(|id| en.decode(id));
+        // Servers send "*" for users that aren't logged in
+        msg.account = tags.get(bytes!("account")).filtered(|a| *a != bytes!("*")).map(|a| en.decode(a));
+        msg.prefix = Some(en.decode(prefix));
+        msg
+    }
+
+    fn nick_key(&self, nick: &[u8]) -> ~str {
+        self.encoding.network.decode(nick.to_owned().irc_lowercase())
+    }
+
+    // Creates or refreshes the user behind `prefix`, returning its key
+    fn track_user(&mut self, prefix: &[u8]) -> ~str {
+        let key = self.nick_key(irc::parser::prefix_nick(prefix));
+        let prefix = self.encoding.network.decode(prefix);
+        let user = self.users.find_or_insert_with(key.clone(), |_| channel::User::new(~""));
+        user.update_from_prefix(prefix);
+        key
+    }
+
+    // Applies `update` to the user behind `prefix` and forwards the result. Users not
+    // sharing a channel with us are ignored.
+    fn update_user(&mut self, reply: |msg::Message|, prefix: &[u8], update: |&mut channel::User|) {
+        let key = self.nick_key(irc::parser::prefix_nick(prefix));
+        let prefix = self.encoding.network.decode(prefix);
+        match self.users.find_mut(&key) {
+            Some(user) => {
+                user.update_from_prefix(prefix);
+                update(user);
+                reply(msg::UserUpdate(user.clone()));
+            },
+            None => ()
+        }
+    }
+
+    fn user_joined(&mut self, reply: |msg::Message|, channel_l: ~str, prefix: &[u8],
+                   account: Option<~[u8]>, realname: Option<~[u8]>) {
+        if self.is_own_prefix(prefix) {
+            self.channels.insert(channel_l.clone(), channel::Channel::new());
+        }
+        let key = self.track_user(prefix);
+        match self.channels.find_mut(&channel_l) {
+            Some(chan) => { chan.members.insert(key.clone(), ~""); },
+            None       => ()
+        }
+        if self.has_cap("extended-join") {
+            let account = account.map(|a| self.encoding.network.decode(a));
+            let realname = realname.map(|r| self.encoding.incoming.decode(r));
+            let user = self.users.get_mut(&key);
+            user.account = account;
+            if realname.is_some() {
+                user.realname = realname;
+            }
+            reply(msg::UserUpdate(user.clone()));
+        }
+    }
+
+    // Removes `nick` from a channel after a PART or KICK. When we are the one leaving,
+    // the whole channel is dropped.
+    fn user_left(&mut self, channel_l: &~str, nick: &[u8], own: bool) {
+        if own {
+            match self.channels.pop(channel_l) {
+                Some(chan) => {
+                    for (key, _) in chan.members.move_iter() {
+                        self.forget_user(key);
+                    }
+                },
+                None => ()
+            }
+        } else {
+            let key = self.nick_key(nick);
+            match self.channels.find_mut(channel_l) {
+                Some(chan) => { chan.members.remove(&key); },
+                None       => ()
+            }
+            self.forget_user(key);
+        }
+    }
+
+    // Removes the user behind `prefix` from all channels, returning the channels they were on
+    fn user_quit(&mut self, prefix: &[u8]) -> ~[~str] {
+        let key = self.nick_key(irc::parser::prefix_nick(prefix));
+        let mut channels = ~[];
+        for (name, chan) in self.channels.mut_iter() {
+            if chan.members.remove(&key) {
+                channels.push(name.clone());
+            }
+        }
+        self.users.remove(&key);
+        channels
+    }
+
+    // Names the two servers of a netsplit or netjoin batch
+    fn split_servers(&self, params: &[~[u8]]) -> ~str {
+        let servers: ~[~str] = params.iter().map(|p| self.encoding.network.decode(*p)).collect();
+        servers.connect(" <-> ")
+    }
+
+    // Applies the QUITs of a netsplit batch, logging one summary to each affected channel
+    // instead of a line per user
+    fn netsplit(&mut self, reply: |msg::Message|, params: ~[~[u8]],
+                lines: ~[(irc::parser::Tags, irc::parser::Message)]) {
+        let mut affected: HashMap<~str, ~[~str]> = HashMap::new();
+        for (_, line) in lines.move_iter() {
+            match line {
+                irc::parser::Quit(who, _) => {
+                    let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
+                    for channel_l in self.user_quit(who).move_iter() {
+                        affected.find_or_insert_with(channel_l, |_| ~[]).push(nick.clone());
+                    }
+                },
+                _ => ()
+            }
+        }
+        let servers = self.split_servers(params);
+        for (channel_l, nicks) in affected.move_iter() {
+            self.reply_buffer(|x| reply(x), buffer::Channel(channel_l),
+                              buffer::Information(format!("Netsplit {}: {} quit", servers, nicks.connect(", "))));
+        }
+    }
+
+    // Applies the JOINs of a netjoin batch, logging one summary to each affected channel
+    fn netjoin(&mut self, reply: |msg::Message|, params: ~[~[u8]],
+               lines: ~[(irc::parser::Tags, irc::parser::Message)]) {
+        let mut affected: HashMap<~str, ~[~str]> = HashMap::new();
+        for (_, line) in lines.move_iter() {
+            match line {
+                irc::parser::Join(who, channel, account, realname) => {
+                    let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+                    let nick = self.encoding.network.decode(irc::parser::prefix_nick(who));
+                    self.user_joined(|x| reply(x), channel_l.clone(), who, account, realname);
+                    affected.find_or_insert_with(channel_l, |_| ~[]).push(nick);
+                },
+                _ => ()
+            }
+        }
+        let servers = self.split_servers(params);
+        for (channel_l, nicks) in affected.move_iter() {
+            self.reply_buffer(|x| reply(x), buffer::Channel(channel_l),
+                              buffer::Information(format!("Netjoin {}: {} joined", servers, nicks.connect(", "))));
+        }
+    }
+
+    fn user_renamed(&mut self, prefix: &[u8], nickname: ~[u8]) {
+        if self.is_own_prefix(prefix) {
+            self.nickname = Some(nickname.clone());
+        }
+        let old_key = self.nick_key(irc::parser::prefix_nick(prefix));
+        let new_key = self.nick_key(nickname);
+        match self.users.pop(&old_key) {
+            Some(mut user) => {
+                user.nick = self.encoding.network.decode(nickname);
+                self.users.insert(new_key.clone(), user);
+            },
+            None => return
+        }
+        for (_, chan) in self.channels.mut_iter() {
+            match chan.members.pop(&old_key) {
+                Some(modes) => { chan.members.insert(new_key.clone(), modes); },
+                None        => ()
+            }
+        }
+    }
+
+    // Drops a user that no longer shares any channel with us
+    fn forget_user(&mut self, key: ~str) {
+        let own = match self.nickname {
+            Some(ref nickname) => self.nick_key(*nickname) == key,
+            None               => false
+        };
+        if !own && !self.channels.values().any(|chan| chan.members.contains_key(&key)) {
+            self.users.remove(&key);
+        }
+    }
+
+    // Membership prefix symbols from PREFIX=(ov)@+
+    fn membership_prefixes(&self) -> ~str {
+        match self.isupport.find_equiv(&"PREFIX") {
+            Some(&Some(ref prefix)) => match prefix.find(')') {
+                Some(i) => prefix.slice_from(i + 1).to_owned(),
+                None    => ~""
+            },
+            _ => ~"@+"
+        }
+    }
+
+    // Adds the entries of a NAMES reply to a channel we are on. With multi-prefix an
+    // entry can carry several prefixes, and with userhost-in-names a full nick!user@host.
+    fn add_names(&mut self, channel: ~[u8], entries: ~[~[u8]]) {
+        let channel_l = self.encoding.network.decode(channel.irc_lowercase());
+        if !self.channels.contains_key(&channel_l) {
+            return;
+        }
+        let prefixes = self.membership_prefixes();
+        let mut members = ~[];
+        for entry in entries.iter() {
+            let split = entry.iter().position(|b| !prefixes.as_bytes().contains(b)).unwrap_or(entry.len());
+            let modes = self.encoding.network.decode(entry.slice_to(split));
+            let key = self.track_user(entry.slice_from(split));
+            members.push((key, modes));
+        }
+        let chan = self.channels.get_mut(&channel_l);
+        // A NAMES reply after the initial one replaces the member list
+        if chan.names_complete {
+            chan.members.clear();
+            chan.names_complete = false;
+        }
+        for (key, modes) in members.move_iter() {
+            chan.members.insert(key, modes);
+        }
+    }
+
+    // Buddies and query partners, without duplicates
+    fn monitored_nicks(&self) -> ~[~str] {
+        let mut nicks: ~[~str] = ~[];
+        let mut keys: ~[~str] = ~[];
+        let queries = self.buffers.iter().filter_map(|buf| {
+            match buf.role {
+                buffer::Query(ref name) => Some(name.clone()),
+                _ => None
+            }
+        });
+        for nick in self.buddies.iter().map(|n| n.clone()).chain(queries) {
+            let key = self.nick_key(self.encoding.network.encode(&nick));
+            if !keys.contains(&key) {
+                keys.push(key);
+                nicks.push(nick);
+            }
+        }
+        nicks
+    }
+
+    // Packs nicknames into `separator`-separated lists that each fit in a single line
+    fn nick_lists(&self, nicks: &[~str], separator: u8) -> ~[~[u8]] {
+        let mut lists = ~[];
+        let mut list: ~[u8] = ~[];
+        for nick in nicks.iter() {
+            let nick = self.encoding.network.encode(nick);
+            if !list.is_empty() && list.len() + nick.len() + 1 > MAX_NICK_LIST_LENGTH {
+                lists.push(std::mem::replace(&mut list, ~[]));
+            }
+            if !list.is_empty() {
+                list.push(separator);
+            }
+            list.push_all(nick);
+        }
+        if !list.is_empty() {
+            lists.push(list);
+        }
+        lists
+    }
+
+    // (Re)starts presence tracking after registration or a buddy list change, with
+    // MONITOR if the server supports it and periodic ISON otherwise
+    fn start_presence(&mut self) {
+        let nicks = self.monitored_nicks();
+        let monitor_limit = match self.isupport.find_equiv(&"MONITOR") {
+            Some(&Some(ref limit)) => Some(from_str(*limit).unwrap_or(0u)),
+            Some(&None)            => Some(0),
+            None                   => None
+        };
+        match monitor_limit {
+            Some(limit) => {
+                let nicks = if limit > 0 && nicks.len() > limit { nicks.slice_to(limit) } else { nicks.as_slice() };
+                self.client.monitor(bytes!("C"), None);
+                for list in self.nick_lists(nicks, ',' as u8).iter() {
+                    self.client.monitor(bytes!("+"), Some(list.as_slice()));
+                }
+            },
+            None => {
+                self.send_ison();
+                if !self.ison_polling {
+                    self.ison_polling = true;
+                    self.set_timer(ISON_INTERVAL_MS, TIMER_ISON);
+                }
+            }
+        }
+    }
+
+    fn send_ison(&mut self) {
+        let nicks = self.monitored_nicks();
+        for list in self.nick_lists(nicks, ' ' as u8).move_iter() {
+            self.client.ison(list.as_slice());
+            self.ison_queries.push(list);
+        }
+    }
+
+    // Records the presence of `nick`, logging and forwarding it if it changed. Nicks
+    // not seen before are assumed to have been offline.
+    fn set_presence(&mut self, reply: |msg::Message|, nick: &[u8], online: bool) {
+        let nick_l = self.nick_key(nick);
+        let was_online = self.presence.find(&nick_l).map_or(false, |&o| o);
+        self.presence.insert(nick_l.clone(), online);
+        if was_online == online {
+            return;
+        }
+        let nick = self.encoding.network.decode(nick);
+        let text = if online { format!("{} is online", nick) } else { format!("{} went offline", nick) };
+        self.reply_buffer(|x| reply(x), buffer::Query(nick_l), buffer::Information(text));
+        reply(msg::Presence(nick, online));
+    }
+
+    /// Collects the network's state for a remote that attaches.
+    pub fn snapshot(&mut self) -> Snapshot {
+        let &Network { ref mut buffers, ref channels, ref users, .. } = self;
+        let mut buffer_snapshots = ~[];
+        for buf in buffers.mut_iter() {
+            let (topic, members) = match buf.role {
+                buffer::Channel(ref name) => match channels.find(name) {
+                    Some(chan) => {
+                        let members = chan.members.iter().map(|(key, prefixes)| {
+                            let user = users.find(key).map_or(channel::User::new(key.clone()), |u| u.clone());
+                            (user, prefixes.clone())
+                        }).collect();
+                        (chan.topic.clone(), members)
+                    },
+                    None => (None, ~[])
+                },
+                _ => (None, ~[])
+            };
+            buffer_snapshots.push(BufferSnapshot {
+                id: buf.id,
+                role: buf.role.clone(),
+                stored_messages: buf.stored_messages,
+                last_read: buf.last_read,
+                unread: buf.unread_count(),
+                topic: topic,
+                members: members
+            });
+        }
+        let nickname = match self.state {
+            NetworkDisconnected => None,
+            _ => self.nickname.as_ref().map(|n| self.encoding.network.decode(n.as_slice()))
+        };
+        Snapshot {
+            id: self.id,
+            state: self.state,
+            configuration: self.config.clone(),
+            nickname: nickname,
+            away: self.away.clone(),
+            buffers: buffer_snapshots
+        }
+    }
+
+    fn set_topic(&mut self, reply: |msg::Message|, channel_l: ~str, topic: Option<~str>) {
+        match self.channels.find_mut(&channel_l) {
+            Some(chan) => chan.topic = topic.clone(),
+            None       => return
+        }
+        reply(msg::Topic(channel_l, topic));
+    }
+
+    /// Called by the session when the last remote detaches or the first one attaches.
+    pub fn set_detached(&mut self, detached: bool) {
+        self.auto_away = detached && self.identity.as_ref().map_or(false, |i| i.auto_away);
+        self.update_away();
+    }
+
+    // Brings the server-side away state in line with the explicit and automatic away
+    // settings. An explicit away set by a remote takes precedence.
+    fn update_away(&mut self) {
+        match self.state {
+            NetworkConnected => (),
+            _                => return
+        }
+        let wanted = match self.explicit_away {
+            Some(ref message) => Some(message.clone()),
+            None if self.auto_away => self.identity.as_ref().map(|i| i.away_message()),
+            None => None
+        };
+        if wanted == self.away_requested {
+            return;
+        }
+        let encoded = {
+            let eo = &self.encoding.outgoing;
+            wanted.as_ref().map(|m| eo.encode(m))
+        };
+        self.client.away(encoded.as_ref().map(|m| m.as_slice()));
+        self.away_requested = wanted;
+    }
+
+    // Sets a timer of kind `kind` for the current connection
+    fn set_timer(&mut self, msecs: u64, kind: u64) {
+        self.client.set_timer(msecs, self.generation << TIMER_KIND_BITS | kind);
+    }
+
+    // Sends queued perform commands, waiting for the perform timer between them if a
+    // delay is configured
+    fn run_perform(&mut self) {
+        loop {
+            let command = match self.perform_queue.pop() {
+                Some(command) => command,
+                None          => return
+            };
+            self.client.send_raw(self.encoding.outgoing.encode(&command));
+            if self.perform.delay_ms > 0 && !self.perform_queue.is_empty() {
+                let delay = self.perform.delay_ms;
+                self.set_timer(delay, TIMER_PERFORM);
+                return;
+            }
+        }
+    }
+
+    // Sends the configured services identification, returning whether it was sent
+    fn identify_to_services(&mut self) -> bool {
+        // The nick in use, which isn't the configured one if that was taken
+        let nickname = match self.nickname {
+            Some(ref nickname) => self.encoding.network.decode(nickname.as_slice()),
+            None               => return false
+        };
+        let (service, command) = match self.config {
+            Some(Configuration { service_auth: Some(ref auth), .. }) =>
+                (auth.service.clone(),
+                 auth.command.replace("{nick}", nickname.as_slice())
+                             .replace("{password}", auth.password.as_slice())),
+            _ => return false
+        };
+        self.client.privmsg(self.encoding.network.encode(&service),
+                            self.encoding.outgoing.encode(&command));
+        true
+    }
+
+    fn service_auth_confirmed(&mut self, reply: |msg::Message|) {
+        if !self.service_auth_pending {
+            return;
+        }
+        self.service_auth_pending = false;
+        self.reply_buffer(|x| reply(x), buffer::Status, buffer::Information(~"Identified to services"));
+        self.rejoin_channels();
+    }
+
+    fn is_service_prefix(&self, prefix: &[u8]) -> bool {
+        let service = match self.config {
+            Some(Configuration { service_auth: Some(ref auth), .. }) => auth.service.clone(),
+            _ => return false
+        };
+        // Q@CServe.quakenet.org replies as plain Q
+        let service_nick = match service.find('@') {
+            Some(i) => service.slice_to(i).to_owned(),
+            None    => service
+        };
+        irc::parser::prefix_nick(prefix).to_owned().irc_equal(&self.encoding.network.encode(&service_nick))
+    }
+
+    // Picks the next nickname after `rejected` was refused during registration: first the
+    // identity's alternate nicks, then the rejected nickname with an underscore appended
+    fn try_next_nickname(&mut self, rejected: ~[u8]) {
+        let alternate = self.identity.as_ref().and_then(|identity| {
+            identity.alternate_nicks.get_opt(self.nickname_attempts).map(|nick| nick.clone())
+        });
+        self.nickname_attempts += 1;
+        let nickname = match alternate {
+            Some(nick) => self.encoding.network.encode(&nick),
+            None       => {
+                let mut nick = rejected;
+                nick.push('_' as u8);
+                nick
+            }
+        };
+        self.client.nick(nickname.as_slice());
+        self.nickname = Some(nickname);
+    }
+
+    fn is_own_prefix(&self, prefix: &[u8]) -> bool {
+        match self.nickname {
+            Some(ref nickname) => irc::parser::prefix_nick(prefix).to_owned().irc_equal(nickname),
+            None               => false
+        }
+    }
+
+    fn join_target_limit(&self) -> uint {
+        // TARGMAX=JOIN:4,PART:,... - an empty limit means unlimited
+        let targmax = match self.isupport.find_equiv(&"TARGMAX") {
+            Some(&Some(ref targmax)) => targmax.clone(),
+            _                        => return DEFAULT_JOIN_TARGETS
+        };
+        for target in targmax.split(',') {
+            let mut parts = target.splitn(':', 1);
+            match (parts.next(), parts.next()) {
+                (Some(cmd), Some(""))    if cmd == "JOIN" => return std::uint::MAX,
+                (Some(cmd), Some(limit)) if cmd == "JOIN" =>
+                    return from_str(limit).unwrap_or(DEFAULT_JOIN_TARGETS),
+                _ => ()
+            }
+        }
+        DEFAULT_JOIN_TARGETS
+    }
+
+    // Joins all channels marked for autojoin, packing as many as the server allows
+    // into each JOIN. Channels with keys must come first in a JOIN, so they are sent first.
+    fn rejoin_channels(&mut self) {
+        let limit = self.join_target_limit();
+        let en = &self.encoding.network;
+
+        let mut channels: ~[(~[u8], Option<~[u8]>)] = self.buffers.iter().filter_map(|buf| {
+            match buf.role {
+                buffer::Channel(ref name) if buf.autojoin =>
+                    Some((en.encode(name), buf.key.as_ref().map(|k| en.encode(k)))),
+                _ => None
+            }
+        }).collect();
+        channels.sort_by(|&(_, ref a), &(_, ref b)| b.is_some().cmp(&a.is_some()));
+
+        let mut chan_list: ~[u8] = ~[];
+        let mut key_list: ~[u8] = ~[];
+        let mut count = 0;
+        for &(ref channel, ref key) in channels.iter() {
+            let len = channel.len() + key.as_ref().map_or(0, |k| k.len()) + 2;
+            if count > 0 && (count == limit || chan_list.len() + key_list.len() + len > MAX_JOIN_LENGTH) {
+                self.client.join(chan_list, if key_list.is_empty() { None } else { Some(key_list.as_slice()) });
+                chan_list.clear();
+                key_list.clear();
+                count = 0;
+            }
+            if count > 0 {
+                chan_list.push(',' as u8);
+            }
+            chan_list.push_all(*channel);
+            match *key {
+                Some(ref key) => {
+                    if !key_list.is_empty() {
+                        key_list.push(',' as u8);
+                    }
+                    key_list.push_all(*key);
+                },
+                None => ()
+            }
+            count += 1;
+        }
+        if count > 0 {
+            self.client.join(chan_list, if key_list.is_empty() { None } else { Some(key_list.as_slice()) });
         }
     }
 
@@ -194,25 +1507,38 @@ impl Network {
     }
 
     fn reply_buffer(&mut self, reply: |msg::Message|, role: buffer::Role, cont: buffer::MessageContents) {
+        self.reply_buffer_message(reply, role, buffer::Message::now(cont))
+    }
+
+    fn reply_buffer_message(&mut self, reply: |msg::Message|, role: buffer::Role, msg: buffer::Message) {
         let buffer = self.get_buffer(|x| reply(x), role);
 
         let buf_id = buffer.id;
-        buffer.add(buffer::Message::now(cont),
+        buffer.add(msg,
             |msg| reply(msg::BufferMessage(buf_id, msg)));
     }
 }
 
 pub mod msg {
     use buffer;
+    use channel;
 
     pub enum Command {
         Connect,
         Disconnect,
-        JoinChannel(~str),
+        JoinChannel(~str, Option<~str>), // channel, key
+        PartChannel(~str, Option<~str>), // channel, message
         SendPrivmsg(~str, ~str),
+        SendCommand(~str), // raw IRC line
+        SetAway(Option<~str>), // message, None to come back
         GetBufferList,
         SetConfiguration(super::Configuration),
         GetConfiguration,
+        SetPerform(super::PerformList),
+        GetPerform,
+        SetBuddyList(~[~str]),
+        GetBuddyList,
+        SetLastRead(u64, u64), // buffer, message id
         GetBufferMessageRange(u64, uint, Option<u64>)
     }
 
@@ -225,6 +1551,164 @@ pub mod msg {
         Error(~str),
         Success,
         Configuration(Option<super::Configuration>),
-        BufferMessageRange(u64, ~[buffer::Message])
+        Perform(super::PerformList),
+        AwayState(Option<~str>), // away message, None if not away
+        BufferMessageRange(u64, ~[buffer::Message]),
+        BufferBackfill(u64, ~[buffer::Message]), // messages fetched from server history
+        UserUpdate(channel::User), // new information about a user sharing a channel with us
+        BuddyList(~[~str]),
+        Presence(~str, bool), // nickname, online
+        CommandReply(~[~str]), // server lines answering a SendCommand
+        Topic(~str, Option<~str>), // channel, topic or None if unset
+        LastRead(u64, u64) // buffer, message id
+    }
+}
+
+#[test]
+fn test_parse_server() {
+    assert_eq!(parse_server("irc.example.com"), Some((~"irc.example.com", 6667)));
+    assert_eq!(parse_server("irc.example.com:6697"), Some((~"irc.example.com", 6697)));
+    assert_eq!(parse_server("[::1]:7000"), Some((~"::1", 7000)));
+    assert_eq!(parse_server("2001:db8::1"), Some((~"2001:db8::1", 6667)));
+    assert!(parse_server("irc.example.com:port").is_none());
+    assert!(parse_server("").is_none());
+}
+
+#[test]
+fn test_keep_passwords() {
+    let config = |auth_password: &str, proxy_password: &str| Configuration {
+        server: ~"irc.example.com",
+        nickname: ~"q2",
+        service_auth: Some(ServiceAuth { service: ~"NickServ", command: ~"IDENTIFY {password}",
+                                         password: auth_password.to_owned() }),
+        identity_id: None,
+        proxy: Some(irc::proxy::Proxy { kind: irc::proxy::Socks5, host: ~"localhost", port: 1080,
+                                        credentials: Some((~"user", proxy_password.to_owned())) }),
+        bind_address: None,
+        webirc: None
+    };
+    let old = config("secret", "hunter2");
+
+    let mut update = config("", "");
+    update.keep_passwords(&old);
+    assert_eq!(update.service_auth.unwrap().password, ~"secret");
+    assert_eq!(update.proxy.unwrap().credentials, Some((~"user", ~"hunter2")));
+
+    let mut update = config("changed", "");
+    update.keep_passwords(&old);
+    assert_eq!(update.service_auth.unwrap().password, ~"changed");
+}
+
+#[test]
+fn test_strip_formatting() {
+    assert_eq!(strip_formatting("You are now identified for \x02q2\x02."), ~"You are now identified for q2.");
+    assert_eq!(strip_formatting("\x0304,12red\x03 and \x1fplain\x0f"), ~"red and plain");
+    assert_eq!(strip_formatting("\x035"), ~"");
+}
+
+#[cfg(test)]
+fn test_network() -> (Network, Sender<ClientMessage>) {
+    let mut db = database::Database::open(~":memory:").unwrap();
+    db.create_tables();
+    let mut nw = Network::new(0, database::Handle { db: ::sync::RWArc::new(db) });
+    let (tx, rx) = channel();
+    nw.rx = rx;
+    (nw, tx)
+}
+
+// Passes `line` to `nw` as if received from the server, returning the replies
+#[cfg(test)]
+fn receive_line(nw: &mut Network, tx: &Sender<ClientMessage>, line: &[u8]) -> ~[Envelope<msg::Message>] {
+    let (tags, msg) = irc::parser::parse_tagged_message(line).unwrap();
+    tx.send(irc::client::Message(tags, msg, line.to_owned()));
+    let mut replies = ~[];
+    nw.handle_message(|m| replies.push(m));
+    replies
+}
+
+#[cfg(test)]
+fn command_replies(replies: ~[Envelope<msg::Message>]) -> ~[(Option<u64>, ~[~str])] {
+    replies.move_iter().filter_map(|r| match r {
+        Envelope { remote_tag, contents: msg::CommandReply(lines), .. } => Some((remote_tag, lines)),
+        _ => None
+    }).collect()
+}
+
+#[test]
+fn test_labeled_reply() {
+    let (mut nw, tx) = test_network();
+    nw.caps = ~[~"labeled-response"];
+    let command = |line: &str, remote: u64| Envelope { client_tag: Some(1), remote_tag: Some(remote),
+                                                       contents: msg::SendCommand(line.to_owned()) };
+    nw.handle_command(command("VERSION", 7), |_| fail!("replied before the server did"));
+    nw.handle_command(command("WHOIS someone", 8), |_| fail!("replied before the server did"));
+
+    // Replies come back to the remote that sent the command, in whatever order
+    let line = "@label=1 :irc.example.com 401 q2 someone :No such nick";
+    assert_eq!(command_replies(receive_line(&mut nw, &tx, line.as_bytes())), ~[(Some(8), ~[line.to_owned()])]);
+
+    assert!(command_replies(receive_line(&mut nw, &tx, bytes!("@label=0 BATCH +b labeled-response")))
+            .is_empty());
+    let line = "@batch=b :irc.example.com 351 q2 ircd-1.0 irc.example.com :";
+    assert!(command_replies(receive_line(&mut nw, &tx, line.as_bytes())).is_empty());
+    assert_eq!(command_replies(receive_line(&mut nw, &tx, bytes!("BATCH -b"))), ~[(Some(7), ~[line.to_owned()])]);
+
+    // Unlabeled lines aren't command replies
+    assert!(command_replies(receive_line(&mut nw, &tx, bytes!(":irc.example.com 351 q2 ircd-1.0 x :")))
+            .is_empty());
+}
+
+// The Information lines among `replies`
+#[cfg(test)]
+fn information(replies: ~[Envelope<msg::Message>]) -> ~[~str] {
+    replies.move_iter().filter_map(|r| match r.contents {
+        msg::BufferMessage(_, buffer::Message { contents: buffer::Information(text), .. }) => Some(text),
+        _ => None
+    }).collect()
+}
+
+#[test]
+fn test_netsplit_netjoin() {
+    let (mut nw, tx) = test_network();
+    nw.nickname = Some(bytes!("q2").to_owned());
+    receive_line(&mut nw, &tx, bytes!(":q2!q@h JOIN #chan"));
+    receive_line(&mut nw, &tx, bytes!(":aji!a@a JOIN #chan"));
+    receive_line(&mut nw, &tx, bytes!(":jilles!j@j JOIN #chan"));
+
+    assert!(information(receive_line(&mut nw, &tx, bytes!("BATCH +s netsplit irc.hub other.host"))).is_empty());
+    assert!(information(receive_line(&mut nw, &tx, bytes!("@batch=s :aji!a@a QUIT :irc.hub other.host")))
+            .is_empty());
+    assert!(information(receive_line(&mut nw, &tx, bytes!("@batch=s :jilles!j@j QUIT :irc.hub other.host")))
+            .is_empty());
+    assert!(nw.channels.get(&~"#chan").members.len() == 3);
+    assert_eq!(information(receive_line(&mut nw, &tx, bytes!("BATCH -s"))),
+               ~[~"Netsplit irc.hub <-> other.host: aji, jilles quit"]);
+    assert!(nw.channels.get(&~"#chan").members.len() == 1);
+
+    receive_line(&mut nw, &tx, bytes!("BATCH +j netjoin irc.hub other.host"));
+    receive_line(&mut nw, &tx, bytes!("@batch=j :aji!a@a JOIN #chan"));
+    receive_line(&mut nw, &tx, bytes!("@batch=j :jilles!j@j JOIN #chan"));
+    assert_eq!(information(receive_line(&mut nw, &tx, bytes!("BATCH -j"))),
+               ~[~"Netjoin irc.hub <-> other.host: aji, jilles joined"]);
+    assert!(nw.channels.get(&~"#chan").members.len() == 3);
+}
+
+#[test]
+fn test_nested_batch() {
+    let (mut nw, tx) = test_network();
+    nw.caps = ~[~"labeled-response", ~"batch"];
+    nw.handle_command(Envelope { client_tag: Some(1), remote_tag: Some(7),
+                                 contents: msg::SendCommand(~"CHATHISTORY LATEST #chan * 10") },
+                      |_| fail!("replied before the server did"));
+
+    // The chathistory batch inside the labeled one must not swallow the labeled batch's end
+    let lines = [~"@label=0 BATCH +outer labeled-response",
+                 ~"@batch=outer BATCH +inner chathistory #chan",
+                 ~"@batch=inner :aji!a@a PRIVMSG #chan :hello",
+                 ~"BATCH -inner"];
+    for line in lines.iter() {
+        assert!(command_replies(receive_line(&mut nw, &tx, line.as_bytes())).is_empty());
     }
+    assert_eq!(command_replies(receive_line(&mut nw, &tx, bytes!("BATCH -outer"))),
+               ~[(Some(7), lines.slice_from(1).to_owned())]);
 }
@@ -4,7 +4,7 @@ use protobuf::*;
 use protobuf::rt;
 use protobuf::descriptor;
 
-static file_descriptor_proto_data: &'static [u8] = &[0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x02, 0x71, 0x32, 0x22, 0xb7, 0x04, 0x0a, 0x0d, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x2b, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x71, 0x32, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x2a, 0x0a, 0x0e, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x5f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x32, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x73, 0x65, 0x6e, 0x64, 0x5f, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x3f, 0x0a, 0x19, 0x73, 0x65, 0x74, 0x5f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x2f, 0x0a, 0x11, 0x67, 0x65, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x22, 0xd6, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x11, 0x0a, 0x0d, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x10, 0x01, 0x12, 0x12, 0x0a, 0x0e, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x64, 0x12, 0x0c, 0x0a, 0x07, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0xc8, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x10, 0xca, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x10, 0xcb, 0x01, 0x12, 0x12, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xcc, 0x01, 0x12, 0x0f, 0x0a, 0x0a, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0xcd, 0x01, 0x12, 0x1c, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xce, 0x01, 0x12, 0x1c, 0x0a, 0x17, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xcf, 0x01, 0x12, 0x14, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0xac, 0x02, 0x22, 0x24, 0x0a, 0x0e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x12, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x1d, 0x0a, 0x09, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1f, 0x0a, 0x0c, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2b, 0x0a, 0x0c, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x3c, 0x0a, 0x18, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x34, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x0d, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0xe2, 0x05, 0x0a, 0x0d, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x2b, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x71, 0x32, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x12, 0x26, 0x0a, 0x0c, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x27, 0x0a, 0x0c, 0x64, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x32, 0x2e, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x54, 0x12, 0x24, 0x0a, 0x0b, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x22, 0x0a, 0x0a, 0x6e, 0x65, 0x77, 0x5f, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x54, 0x12, 0x25, 0x0a, 0x0b, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x19, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x6a, 0x6f, 0x69, 0x6e, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x1d, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x38, 0x0a, 0x15, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x28, 0x0a, 0x0d, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x10, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x32, 0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x22, 0xcc, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x64, 0x12, 0x0e, 0x0a, 0x09, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0xc9, 0x01, 0x12, 0x11, 0x0a, 0x0c, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0xca, 0x01, 0x12, 0x0f, 0x0a, 0x0a, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xcb, 0x01, 0x12, 0x0e, 0x0a, 0x09, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x10, 0xcc, 0x01, 0x12, 0x19, 0x0a, 0x14, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xcd, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xb1, 0x02, 0x12, 0x09, 0x0a, 0x04, 0x4a, 0x6f, 0x69, 0x6e, 0x10, 0xb2, 0x02, 0x12, 0x0c, 0x0a, 0x07, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x10, 0xb3, 0x02, 0x12, 0x11, 0x0a, 0x0c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0xb4, 0x02, 0x22, 0x9e, 0x01, 0x0a, 0x0c, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0x54, 0x0a, 0x0c, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x17, 0x0a, 0x13, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x02, 0x22, 0x1f, 0x0a, 0x0d, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x70, 0x0a, 0x0a, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x28, 0x0a, 0x0b, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2a, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x51, 0x75, 0x65, 0x72, 0x79, 0x10, 0x03, 0x22, 0x50, 0x0a, 0x0b, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x36, 0x0a, 0x0a, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x1b, 0x0a, 0x0c, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x15, 0x0a, 0x06, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x14, 0x0a, 0x05, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x77, 0x68, 0x6f, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x24, 0x0a, 0x08, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x77, 0x68, 0x6f, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x39, 0x0a, 0x15, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x88, 0x01, 0x0a, 0x0d, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x0b, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x6a, 0x6f, 0x69, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x1d, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x4a, 0xc7, 0x2e, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xa3, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0x03, 0x02, 0x12, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x07, 0x0b, 0x0a, 0x1f, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x04, 0x16, 0x1a, 0x10, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x05, 0x14, 0x15, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x07, 0x04, 0x19, 0x1a, 0x09, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x07, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x07, 0x15, 0x18, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x09, 0x04, 0x12, 0x1a, 0x09, 0x20, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x09, 0x0e, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0a, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x0a, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x0a, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x0b, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0b, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x0b, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x0c, 0x14, 0x17, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x0d, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x0d, 0x11, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x0e, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x0e, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x0e, 0x1e, 0x21, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x0f, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x0f, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x03, 0x0f, 0x1e, 0x21, 0x0a, 0x17, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x11, 0x04, 0x1a, 0x1a, 0x08, 0x20, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x11, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x03, 0x11, 0x16, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x14, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x17, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x17, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x17, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x17, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x18, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x18, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x18, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x18, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x03, 0x1a, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x1a, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1a, 0x2b, 0x2c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x1b, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x1b, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1b, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1b, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x1c, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x1c, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1c, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x1c, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x1d, 0x02, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x1d, 0x24, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x1d, 0x40, 0x41, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x1e, 0x02, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x1e, 0x1c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x1e, 0x30, 0x32, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x21, 0x00, 0x23, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x21, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x22, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x22, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x25, 0x00, 0x27, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x25, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x26, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x29, 0x00, 0x2b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x29, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x2a, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2a, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x2d, 0x00, 0x30, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x2e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2f, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x32, 0x00, 0x35, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x32, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x33, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x33, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x33, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x34, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x34, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x34, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x34, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x37, 0x00, 0x3a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x37, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x38, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x38, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x38, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x38, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x39, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x39, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x39, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x3c, 0x00, 0x62, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x04, 0x00, 0x12, 0x04, 0x3d, 0x02, 0x4e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x04, 0x00, 0x01, 0x12, 0x03, 0x3d, 0x07, 0x0b, 0x0a, 0x1f, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x3f, 0x04, 0x0e, 0x1a, 0x10, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3f, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x3f, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x40, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x40, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x40, 0x0e, 0x0f, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x42, 0x04, 0x16, 0x1a, 0x09, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x42, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x42, 0x12, 0x15, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x44, 0x04, 0x14, 0x1a, 0x09, 0x20, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x44, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x44, 0x10, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x45, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x45, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x45, 0x13, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x46, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x46, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x46, 0x11, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x47, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x47, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x47, 0x10, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x48, 0x04, 0x1f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x48, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x48, 0x1b, 0x1e, 0x0a, 0x17, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x4a, 0x04, 0x16, 0x1a, 0x08, 0x20, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x4a, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x03, 0x4a, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x4b, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x4b, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x03, 0x4b, 0x0b, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x03, 0x4c, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x4c, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x03, 0x4c, 0x0e, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x03, 0x4d, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x4d, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x03, 0x4d, 0x13, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x50, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x50, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x50, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x52, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x52, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x52, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x52, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x52, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x53, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x53, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x53, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x53, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x54, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x54, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x54, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x54, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x04, 0x12, 0x03, 0x55, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x04, 0x12, 0x03, 0x55, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x55, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x55, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x05, 0x12, 0x03, 0x56, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x04, 0x12, 0x03, 0x56, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x05, 0x12, 0x03, 0x56, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x01, 0x12, 0x03, 0x56, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x03, 0x12, 0x03, 0x56, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x06, 0x12, 0x03, 0x58, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x04, 0x12, 0x03, 0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x06, 0x12, 0x03, 0x58, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x01, 0x12, 0x03, 0x58, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x03, 0x12, 0x03, 0x58, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x07, 0x12, 0x03, 0x59, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x06, 0x12, 0x03, 0x59, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x01, 0x12, 0x03, 0x59, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x03, 0x12, 0x03, 0x59, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x08, 0x12, 0x03, 0x5a, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x06, 0x12, 0x03, 0x5a, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x01, 0x12, 0x03, 0x5a, 0x17, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x03, 0x12, 0x03, 0x5a, 0x25, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x09, 0x12, 0x03, 0x5b, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x06, 0x12, 0x03, 0x5b, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x01, 0x12, 0x03, 0x5b, 0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x03, 0x12, 0x03, 0x5b, 0x23, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0a, 0x12, 0x03, 0x5c, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x5c, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x5c, 0x18, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x5c, 0x26, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0b, 0x12, 0x03, 0x5d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x5d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0b, 0x06, 0x12, 0x03, 0x5d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x5d, 0x1a, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0c, 0x12, 0x03, 0x5e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0c, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0c, 0x06, 0x12, 0x03, 0x5e, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x5e, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x5e, 0x18, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0d, 0x12, 0x03, 0x5f, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0d, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0d, 0x06, 0x12, 0x03, 0x5f, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x5f, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x5f, 0x1e, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0e, 0x12, 0x03, 0x60, 0x02, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0e, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0e, 0x06, 0x12, 0x03, 0x60, 0x0b, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x60, 0x21, 0x36, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x60, 0x39, 0x3b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0f, 0x12, 0x03, 0x61, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0f, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0f, 0x06, 0x12, 0x03, 0x61, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x61, 0x19, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x61, 0x29, 0x2b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x64, 0x00, 0x6c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x64, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x08, 0x04, 0x00, 0x12, 0x04, 0x65, 0x02, 0x69, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x04, 0x00, 0x01, 0x12, 0x03, 0x65, 0x07, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x66, 0x04, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x66, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x67, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x67, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x67, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x68, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x68, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x68, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x6a, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6a, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6a, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6b, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6b, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x6e, 0x00, 0x70, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x6e, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x72, 0x00, 0x7a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x72, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x04, 0x00, 0x12, 0x04, 0x73, 0x02, 0x77, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04, 0x00, 0x01, 0x12, 0x03, 0x73, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x74, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x74, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x74, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x75, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x75, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x75, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x76, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x76, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x76, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x78, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x78, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x03, 0x78, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x78, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x78, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x79, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x79, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x79, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x79, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x05, 0x7c, 0x00, 0x80, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x7d, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7d, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7d, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x7e, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x7e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x06, 0x12, 0x03, 0x7e, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7e, 0x16, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7e, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x7f, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x7f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x7f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7f, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7f, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x82, 0x01, 0x00, 0x85, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x82, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04, 0x83, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x04, 0x83, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x06, 0x12, 0x04, 0x84, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x16, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0x87, 0x01, 0x00, 0x89, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0x87, 0x01, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x04, 0x88, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x88, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x8b, 0x01, 0x00, 0x8d, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0x8f, 0x01, 0x00, 0x91, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0x90, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x90, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x90, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x93, 0x01, 0x00, 0x96, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x93, 0x01, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x94, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x94, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0x94, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0x94, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0x95, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x04, 0x95, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0x95, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x95, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0x98, 0x01, 0x00, 0x9b, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x98, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x99, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0x99, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0x99, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0x99, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0x99, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x04, 0x9a, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9a, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9a, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0x9d, 0x01, 0x00, 0xa3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12, 0x04, 0x9e, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x04, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9f, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x02, 0x12, 0x04, 0xa0, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa0, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa0, 0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x03, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x04, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x06, 0x12, 0x04, 0xa1, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa1, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x04, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x04, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x06, 0x12, 0x04, 0xa2, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x14, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa2, 0x01, 0x1e, 0x1f];
+static file_descriptor_proto_data: &'static [u8] = &[0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x02, 0x71, 0x32, 0x22, 0xd1, 0x09, 0x0a, 0x0d, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x2b, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x71, 0x32, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x2a, 0x0a, 0x0e, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x5f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x32, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x73, 0x65, 0x6e, 0x64, 0x5f, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x3f, 0x0a, 0x19, 0x73, 0x65, 0x74, 0x5f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x2f, 0x0a, 0x11, 0x67, 0x65, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x70, 0x61, 0x72, 0x74, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x61, 0x72, 0x74, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x2a, 0x0a, 0x10, 0x73, 0x65, 0x74, 0x5f, 0x70, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x1f, 0x0a, 0x08, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x54, 0x12, 0x1e, 0x0a, 0x08, 0x73, 0x65, 0x74, 0x5f, 0x61, 0x77, 0x61, 0x79, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x74, 0x41, 0x77, 0x61, 0x79, 0x54, 0x12, 0x26, 0x0a, 0x0e, 0x73, 0x65, 0x74, 0x5f, 0x62, 0x75, 0x64, 0x64, 0x79, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x73, 0x65, 0x6e, 0x64, 0x5f, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x6e, 0x64, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x54, 0x12, 0x2a, 0x0a, 0x0e, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x32, 0x2e, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x54, 0x12, 0x19, 0x0a, 0x05, 0x6c, 0x6f, 0x67, 0x69, 0x6e, 0x18, 0x12, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x4c, 0x6f, 0x67, 0x69, 0x6e, 0x54, 0x12, 0x19, 0x0a, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x18, 0x13, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x54, 0x12, 0x27, 0x0a, 0x0d, 0x73, 0x65, 0x74, 0x5f, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x72, 0x65, 0x61, 0x64, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x74, 0x4c, 0x61, 0x73, 0x74, 0x52, 0x65, 0x61, 0x64, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x54, 0x22, 0xe7, 0x03, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x11, 0x0a, 0x0d, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x6f, 0x67, 0x69, 0x6e, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x10, 0x03, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x69, 0x6e, 0x67, 0x10, 0x04, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x6f, 0x6e, 0x67, 0x10, 0x05, 0x12, 0x12, 0x0a, 0x0e, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x64, 0x12, 0x13, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x65, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x10, 0x66, 0x12, 0x12, 0x0a, 0x0e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x10, 0x67, 0x12, 0x14, 0x0a, 0x10, 0x53, 0x65, 0x74, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x10, 0x68, 0x12, 0x0c, 0x0a, 0x07, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0xc8, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x10, 0xca, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x10, 0xcb, 0x01, 0x12, 0x12, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xcc, 0x01, 0x12, 0x0f, 0x0a, 0x0a, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0xcd, 0x01, 0x12, 0x1c, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xce, 0x01, 0x12, 0x1c, 0x0a, 0x17, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xcf, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x50, 0x61, 0x72, 0x74, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x10, 0xd0, 0x01, 0x12, 0x13, 0x0a, 0x0e, 0x47, 0x65, 0x74, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xd1, 0x01, 0x12, 0x13, 0x0a, 0x0e, 0x53, 0x65, 0x74, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xd2, 0x01, 0x12, 0x0c, 0x0a, 0x07, 0x53, 0x65, 0x74, 0x41, 0x77, 0x61, 0x79, 0x10, 0xd3, 0x01, 0x12, 0x11, 0x0a, 0x0c, 0x47, 0x65, 0x74, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xd4, 0x01, 0x12, 0x11, 0x0a, 0x0c, 0x53, 0x65, 0x74, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xd5, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x53, 0x65, 0x6e, 0x64, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x10, 0xd6, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x53, 0x65, 0x74, 0x4c, 0x61, 0x73, 0x74, 0x52, 0x65, 0x61, 0x64, 0x10, 0xd7, 0x01, 0x12, 0x14, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0xac, 0x02, 0x22, 0x23, 0x0a, 0x05, 0x50, 0x69, 0x6e, 0x67, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x72, 0x74, 0x74, 0x5f, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0x22, 0x0a, 0x0c, 0x53, 0x65, 0x74, 0x4c, 0x61, 0x73, 0x74, 0x52, 0x65, 0x61, 0x64, 0x54, 0x12, 0x12, 0x0a, 0x0a, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x4c, 0x0a, 0x06, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x54, 0x12, 0x18, 0x0a, 0x10, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x16, 0x0a, 0x0e, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x66, 0x65, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x22, 0x28, 0x0a, 0x06, 0x4c, 0x6f, 0x67, 0x69, 0x6e, 0x54, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x24, 0x0a, 0x0e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x12, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x1d, 0x0a, 0x09, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2c, 0x0a, 0x0c, 0x4a, 0x6f, 0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x22, 0x1b, 0x0a, 0x08, 0x53, 0x65, 0x74, 0x41, 0x77, 0x61, 0x79, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x22, 0x30, 0x0a, 0x0c, 0x50, 0x61, 0x72, 0x74, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0x2b, 0x0a, 0x0c, 0x53, 0x65, 0x6e, 0x64, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x42, 0x0a, 0x07, 0x57, 0x65, 0x62, 0x49, 0x72, 0x63, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x67, 0x61, 0x74, 0x65, 0x77, 0x61, 0x79, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x5f, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x22, 0x2e, 0x0a, 0x0e, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x68, 0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0xa8, 0x01, 0x0a, 0x06, 0x50, 0x72, 0x6f, 0x78, 0x79, 0x54, 0x12, 0x23, 0x0a, 0x0a, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x6f, 0x78, 0x79, 0x54, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x68, 0x6f, 0x73, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x10, 0x0a, 0x08, 0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x5f, 0x73, 0x65, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x22, 0x23, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x53, 0x6f, 0x63, 0x6b, 0x73, 0x35, 0x10, 0x01, 0x12, 0x0f, 0x0a, 0x0b, 0x48, 0x74, 0x74, 0x70, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0x02, 0x22, 0xc7, 0x01, 0x0a, 0x18, 0x53, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x26, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x61, 0x75, 0x74, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x75, 0x74, 0x68, 0x54, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x19, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x6f, 0x78, 0x79, 0x54, 0x12, 0x14, 0x0a, 0x0c, 0x62, 0x69, 0x6e, 0x64, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1b, 0x0a, 0x06, 0x77, 0x65, 0x62, 0x69, 0x72, 0x63, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x71, 0x32, 0x2e, 0x57, 0x65, 0x62, 0x49, 0x72, 0x63, 0x54, 0x22, 0x58, 0x0a, 0x0c, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x75, 0x74, 0x68, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x5f, 0x73, 0x65, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x22, 0x34, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x0d, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0x8a, 0x0b, 0x0a, 0x0d, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x2b, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x71, 0x32, 0x2e, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x12, 0x26, 0x0a, 0x0c, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x27, 0x0a, 0x0c, 0x64, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x32, 0x2e, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x54, 0x12, 0x24, 0x0a, 0x0b, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x22, 0x0a, 0x0a, 0x6e, 0x65, 0x77, 0x5f, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x54, 0x12, 0x25, 0x0a, 0x0b, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x19, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x6a, 0x6f, 0x69, 0x6e, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x1d, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x38, 0x0a, 0x15, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x28, 0x0a, 0x0d, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x10, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x32, 0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x26, 0x0a, 0x0c, 0x70, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x24, 0x0a, 0x0d, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x12, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x54, 0x12, 0x22, 0x0a, 0x0a, 0x61, 0x77, 0x61, 0x79, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x13, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x41, 0x77, 0x61, 0x79, 0x53, 0x74, 0x61, 0x74, 0x65, 0x54, 0x12, 0x15, 0x0a, 0x0d, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x6d, 0x73, 0x67, 0x69, 0x64, 0x18, 0x14, 0x20, 0x01, 0x28, 0x09, 0x12, 0x17, 0x0a, 0x0f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x15, 0x20, 0x01, 0x28, 0x09, 0x12, 0x16, 0x0a, 0x0e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x18, 0x16, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1e, 0x0a, 0x0b, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x18, 0x17, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x55, 0x73, 0x65, 0x72, 0x54, 0x12, 0x22, 0x0a, 0x0a, 0x62, 0x75, 0x64, 0x64, 0x79, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x18, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x1f, 0x0a, 0x08, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x63, 0x65, 0x18, 0x19, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x63, 0x65, 0x54, 0x12, 0x28, 0x0a, 0x0d, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x5f, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x18, 0x1a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x71, 0x32, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x54, 0x12, 0x1d, 0x0a, 0x07, 0x77, 0x65, 0x6c, 0x63, 0x6f, 0x6d, 0x65, 0x18, 0x1b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x57, 0x65, 0x6c, 0x63, 0x6f, 0x6d, 0x65, 0x54, 0x12, 0x1f, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x1c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x12, 0x19, 0x0a, 0x05, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x18, 0x1d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x1e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x54, 0x22, 0xb7, 0x03, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x65, 0x6c, 0x63, 0x6f, 0x6d, 0x65, 0x10, 0x03, 0x12, 0x0d, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x10, 0x05, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x65, 0x73, 0x79, 0x6e, 0x63, 0x10, 0x06, 0x12, 0x0f, 0x0a, 0x0b, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x64, 0x12, 0x10, 0x0a, 0x0c, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x65, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x10, 0x66, 0x12, 0x0e, 0x0a, 0x09, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0xc9, 0x01, 0x12, 0x11, 0x0a, 0x0c, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0xca, 0x01, 0x12, 0x0f, 0x0a, 0x0a, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xcb, 0x01, 0x12, 0x0e, 0x0a, 0x09, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x10, 0xcc, 0x01, 0x12, 0x19, 0x0a, 0x14, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xcd, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xce, 0x01, 0x12, 0x0e, 0x0a, 0x09, 0x41, 0x77, 0x61, 0x79, 0x53, 0x74, 0x61, 0x74, 0x65, 0x10, 0xcf, 0x01, 0x12, 0x0f, 0x0a, 0x0a, 0x55, 0x73, 0x65, 0x72, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0xd0, 0x01, 0x12, 0x0e, 0x0a, 0x09, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0xd1, 0x01, 0x12, 0x0d, 0x0a, 0x08, 0x50, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x63, 0x65, 0x10, 0xd2, 0x01, 0x12, 0x11, 0x0a, 0x0c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x10, 0xd3, 0x01, 0x12, 0x0a, 0x0a, 0x05, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x10, 0xd4, 0x01, 0x12, 0x0d, 0x0a, 0x08, 0x4c, 0x61, 0x73, 0x74, 0x52, 0x65, 0x61, 0x64, 0x10, 0xd5, 0x01, 0x12, 0x10, 0x0a, 0x0b, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0xb1, 0x02, 0x12, 0x09, 0x0a, 0x04, 0x4a, 0x6f, 0x69, 0x6e, 0x10, 0xb2, 0x02, 0x12, 0x0c, 0x0a, 0x07, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x10, 0xb3, 0x02, 0x12, 0x11, 0x0a, 0x0c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0xb4, 0x02, 0x12, 0x0d, 0x0a, 0x08, 0x42, 0x61, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x6c, 0x10, 0xb5, 0x02, 0x22, 0x33, 0x0a, 0x09, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x12, 0x26, 0x0a, 0x08, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x22, 0xc4, 0x01, 0x0a, 0x10, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x30, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x61, 0x77, 0x61, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x24, 0x0a, 0x07, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x22, 0xab, 0x01, 0x0a, 0x0f, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x72, 0x65, 0x61, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x75, 0x6e, 0x72, 0x65, 0x61, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x23, 0x0a, 0x07, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x71, 0x32, 0x2e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x22, 0x3b, 0x0a, 0x0e, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x55, 0x73, 0x65, 0x72, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0x28, 0x0a, 0x06, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0x4c, 0x0a, 0x08, 0x57, 0x65, 0x6c, 0x63, 0x6f, 0x6d, 0x65, 0x54, 0x12, 0x18, 0x0a, 0x10, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x14, 0x0a, 0x0c, 0x63, 0x6f, 0x72, 0x65, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x66, 0x65, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x22, 0x9e, 0x01, 0x0a, 0x0c, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x71, 0x32, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0x54, 0x0a, 0x0c, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x17, 0x0a, 0x13, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x02, 0x22, 0x1f, 0x0a, 0x0d, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x70, 0x0a, 0x0a, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x28, 0x0a, 0x0b, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2a, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x43, 0x68, 0x61, 0x6e, 0x6e, 0x65, 0x6c, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x51, 0x75, 0x65, 0x72, 0x79, 0x10, 0x03, 0x22, 0x50, 0x0a, 0x0b, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x36, 0x0a, 0x0a, 0x4e, 0x65, 0x77, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x71, 0x32, 0x2e, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x1b, 0x0a, 0x0c, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x15, 0x0a, 0x06, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x14, 0x0a, 0x05, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x77, 0x68, 0x6f, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x24, 0x0a, 0x08, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x0b, 0x0a, 0x03, 0x77, 0x68, 0x6f, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0xc4, 0x01, 0x0a, 0x15, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x26, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x61, 0x75, 0x74, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x75, 0x74, 0x68, 0x54, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x19, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x6f, 0x78, 0x79, 0x54, 0x12, 0x14, 0x0a, 0x0c, 0x62, 0x69, 0x6e, 0x64, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1b, 0x0a, 0x06, 0x77, 0x65, 0x62, 0x69, 0x72, 0x63, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x71, 0x32, 0x2e, 0x57, 0x65, 0x62, 0x49, 0x72, 0x63, 0x54, 0x22, 0xa6, 0x01, 0x0a, 0x09, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x61, 0x6c, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x17, 0x0a, 0x0f, 0x61, 0x6c, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x74, 0x65, 0x5f, 0x6e, 0x69, 0x63, 0x6b, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x71, 0x75, 0x69, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x70, 0x61, 0x72, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x77, 0x61, 0x79, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x61, 0x75, 0x74, 0x6f, 0x5f, 0x61, 0x77, 0x61, 0x79, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x22, 0x32, 0x0a, 0x0c, 0x50, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x10, 0x0a, 0x08, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x64, 0x65, 0x6c, 0x61, 0x79, 0x5f, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x22, 0x1d, 0x0a, 0x0a, 0x41, 0x77, 0x61, 0x79, 0x53, 0x74, 0x61, 0x74, 0x65, 0x54, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x22, 0x1c, 0x0a, 0x0c, 0x53, 0x65, 0x6e, 0x64, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x54, 0x12, 0x0c, 0x0a, 0x04, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1e, 0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x54, 0x12, 0x0d, 0x0a, 0x05, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x22, 0x1b, 0x0a, 0x0a, 0x42, 0x75, 0x64, 0x64, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x69, 0x63, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x22, 0x29, 0x0a, 0x09, 0x50, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x63, 0x65, 0x54, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x69, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x08, 0x22, 0x63, 0x0a, 0x05, 0x55, 0x73, 0x65, 0x72, 0x54, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x69, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x68, 0x6f, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x61, 0x6c, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x61, 0x77, 0x61, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x22, 0xb8, 0x01, 0x0a, 0x0d, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x54, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x0b, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x71, 0x32, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x12, 0x17, 0x0a, 0x04, 0x6a, 0x6f, 0x69, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x71, 0x32, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x54, 0x12, 0x1d, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x71, 0x32, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x6d, 0x73, 0x67, 0x54, 0x12, 0x0d, 0x0a, 0x05, 0x6d, 0x73, 0x67, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x4a, 0xff, 0x84, 0x01, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xfd, 0x02, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x3a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0x03, 0x02, 0x22, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x07, 0x0b, 0x0a, 0x1f, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x04, 0x16, 0x1a, 0x10, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x05, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x06, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x06, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x06, 0x0c, 0x0d, 0x0a, 0x29, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x07, 0x04, 0x0e, 0x22, 0x1a, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x07, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x07, 0x0c, 0x0d, 0x0a, 0x2d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x08, 0x04, 0x0d, 0x22, 0x1e, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x08, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x08, 0x0b, 0x0c, 0x0a, 0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x09, 0x04, 0x0d, 0x22, 0x15, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x09, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x09, 0x0b, 0x0c, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x0b, 0x04, 0x19, 0x1a, 0x09, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0b, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x0b, 0x15, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x0c, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x0c, 0x16, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x0d, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x0d, 0x15, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x0e, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x0e, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x03, 0x0e, 0x15, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x0f, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x0f, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x03, 0x0f, 0x17, 0x1a, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x03, 0x11, 0x04, 0x12, 0x1a, 0x09, 0x20, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x11, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x03, 0x11, 0x0e, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x03, 0x12, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x12, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x03, 0x12, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x03, 0x13, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x13, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0c, 0x02, 0x12, 0x03, 0x13, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0d, 0x12, 0x03, 0x14, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x14, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0d, 0x02, 0x12, 0x03, 0x14, 0x14, 0x17, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0e, 0x12, 0x03, 0x15, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x15, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0e, 0x02, 0x12, 0x03, 0x15, 0x11, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0f, 0x12, 0x03, 0x16, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x16, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x0f, 0x02, 0x12, 0x03, 0x16, 0x1e, 0x21, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x10, 0x12, 0x03, 0x17, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x10, 0x01, 0x12, 0x03, 0x17, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x10, 0x02, 0x12, 0x03, 0x17, 0x1e, 0x21, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x11, 0x12, 0x03, 0x18, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x11, 0x01, 0x12, 0x03, 0x18, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x11, 0x02, 0x12, 0x03, 0x18, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x12, 0x03, 0x19, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x01, 0x12, 0x03, 0x19, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x02, 0x12, 0x03, 0x19, 0x15, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x13, 0x12, 0x03, 0x1a, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x13, 0x01, 0x12, 0x03, 0x1a, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x13, 0x02, 0x12, 0x03, 0x1a, 0x15, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x14, 0x12, 0x03, 0x1b, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x14, 0x01, 0x12, 0x03, 0x1b, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x14, 0x02, 0x12, 0x03, 0x1b, 0x0e, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x15, 0x12, 0x03, 0x1c, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x15, 0x01, 0x12, 0x03, 0x1c, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x15, 0x02, 0x12, 0x03, 0x1c, 0x13, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x16, 0x12, 0x03, 0x1d, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x16, 0x01, 0x12, 0x03, 0x1d, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x16, 0x02, 0x12, 0x03, 0x1d, 0x13, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x17, 0x12, 0x03, 0x1e, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x17, 0x01, 0x12, 0x03, 0x1e, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x17, 0x02, 0x12, 0x03, 0x1e, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x18, 0x12, 0x03, 0x1f, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x18, 0x01, 0x12, 0x03, 0x1f, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x18, 0x02, 0x12, 0x03, 0x1f, 0x12, 0x15, 0x0a, 0x17, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x19, 0x12, 0x03, 0x21, 0x04, 0x1a, 0x1a, 0x08, 0x20, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x19, 0x01, 0x12, 0x03, 0x21, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x19, 0x02, 0x12, 0x03, 0x21, 0x16, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x24, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x26, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x26, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x26, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x27, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x27, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x28, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x28, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x28, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x03, 0x2a, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2a, 0x1a, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x2a, 0x2b, 0x2c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x2b, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2b, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x2b, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x2c, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x2c, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x2c, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x2c, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x2d, 0x02, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x03, 0x2d, 0x0b, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2d, 0x24, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2d, 0x40, 0x41, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x2e, 0x02, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12, 0x03, 0x2e, 0x0b, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2e, 0x1c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2e, 0x30, 0x32, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x2f, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x06, 0x12, 0x03, 0x2f, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x2f, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x03, 0x12, 0x03, 0x2f, 0x27, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x03, 0x30, 0x02, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x30, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x30, 0x18, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x30, 0x2b, 0x2d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x03, 0x31, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x06, 0x12, 0x03, 0x31, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x31, 0x15, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x31, 0x20, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x03, 0x32, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x06, 0x12, 0x03, 0x32, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x32, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x32, 0x1f, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0d, 0x12, 0x03, 0x33, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0d, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0d, 0x06, 0x12, 0x03, 0x33, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x33, 0x16, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x33, 0x27, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0e, 0x12, 0x03, 0x34, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0e, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0e, 0x06, 0x12, 0x03, 0x34, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x34, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x34, 0x27, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0f, 0x12, 0x03, 0x35, 0x02, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0f, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0f, 0x06, 0x12, 0x03, 0x35, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x35, 0x1a, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x35, 0x2b, 0x2d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x10, 0x12, 0x03, 0x36, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x10, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x10, 0x06, 0x12, 0x03, 0x36, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x10, 0x01, 0x12, 0x03, 0x36, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x10, 0x03, 0x12, 0x03, 0x36, 0x1a, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x11, 0x12, 0x03, 0x37, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x11, 0x04, 0x12, 0x03, 0x37, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x11, 0x06, 0x12, 0x03, 0x37, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x11, 0x01, 0x12, 0x03, 0x37, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x11, 0x03, 0x12, 0x03, 0x37, 0x1a, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x12, 0x12, 0x03, 0x38, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x12, 0x04, 0x12, 0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x12, 0x06, 0x12, 0x03, 0x38, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x12, 0x01, 0x12, 0x03, 0x38, 0x18, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x12, 0x03, 0x12, 0x03, 0x38, 0x28, 0x2a, 0x0a, 0x1c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x13, 0x12, 0x03, 0x39, 0x02, 0x1b, 0x22, 0x0f, 0x20, 0x50, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x50, 0x6f, 0x6e, 0x67, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x13, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x13, 0x06, 0x12, 0x03, 0x39, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x13, 0x01, 0x12, 0x03, 0x39, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x13, 0x03, 0x12, 0x03, 0x39, 0x18, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x3c, 0x00, 0x3f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x0d, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x3d, 0x02, 0x19, 0x22, 0x16, 0x20, 0x65, 0x63, 0x68, 0x6f, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3d, 0x17, 0x18, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x1d, 0x22, 0x2d, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x2d, 0x74, 0x72, 0x69, 0x70, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x6d, 0x65, 0x61, 0x73, 0x75, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3e, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x41, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x41, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x42, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x42, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x42, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x45, 0x00, 0x49, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x45, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x46, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x25, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x47, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x47, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x47, 0x23, 0x24, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x48, 0x02, 0x1f, 0x22, 0x16, 0x20, 0x77, 0x61, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x48, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x48, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x48, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x48, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x4b, 0x00, 0x4e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x4b, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x4c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4d, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x50, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x50, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x51, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x51, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x51, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x51, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x54, 0x00, 0x56, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x54, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x55, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x55, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x55, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x55, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x58, 0x00, 0x5b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x58, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x59, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x59, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x59, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x59, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x5a, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5a, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x5d, 0x00, 0x5f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x10, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x1e, 0x22, 0x14, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x6f, 0x6d, 0x65, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x61, 0x00, 0x64, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x61, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x62, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x62, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x62, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x63, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x63, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x63, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x63, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x63, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x66, 0x00, 0x69, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x66, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x67, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x67, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x67, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x67, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x67, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x68, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x68, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x68, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x68, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x6e, 0x00, 0x72, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6e, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x70, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x70, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x70, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x70, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x70, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x71, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x71, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x71, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x71, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x71, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x74, 0x00, 0x77, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x74, 0x08, 0x16, 0x0a, 0x1d, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03, 0x75, 0x02, 0x1f, 0x22, 0x10, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x6c, 0x65, 0x61, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x75, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x75, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x75, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x75, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x76, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x76, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x76, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x05, 0x79, 0x00, 0x84, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x79, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x04, 0x00, 0x12, 0x04, 0x7a, 0x02, 0x7d, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x04, 0x00, 0x01, 0x12, 0x03, 0x7a, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x7b, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x7b, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x7c, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7c, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x7c, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x7e, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x06, 0x12, 0x03, 0x7e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7e, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7e, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x7f, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04, 0x12, 0x03, 0x7f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x7f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7f, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7f, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0x80, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x04, 0x12, 0x04, 0x80, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x04, 0x80, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x04, 0x80, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04, 0x80, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x04, 0x81, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x04, 0x12, 0x04, 0x81, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x05, 0x12, 0x04, 0x81, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x04, 0x81, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x04, 0x81, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04, 0x12, 0x04, 0x82, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x04, 0x12, 0x04, 0x82, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01, 0x12, 0x04, 0x82, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12, 0x04, 0x82, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x05, 0x12, 0x04, 0x83, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x04, 0x12, 0x04, 0x83, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x05, 0x12, 0x04, 0x83, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x01, 0x12, 0x04, 0x83, 0x01, 0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x03, 0x12, 0x04, 0x83, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x86, 0x01, 0x00, 0x8e, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x86, 0x01, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0x87, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x87, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0x87, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x87, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x87, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0x88, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04, 0x12, 0x04, 0x88, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0x88, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0x88, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0x89, 0x01, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x04, 0x12, 0x04, 0x89, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x06, 0x12, 0x04, 0x89, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x04, 0x89, 0x01, 0x18, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x04, 0x89, 0x01, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x04, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x04, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x04, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x06, 0x12, 0x04, 0x8d, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0x90, 0x01, 0x00, 0x95, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x90, 0x01, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x91, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x04, 0x92, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0x92, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0x93, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x04, 0x93, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05, 0x12, 0x04, 0x93, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04, 0x93, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0x93, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x04, 0x94, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x04, 0x12, 0x04, 0x94, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x05, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x04, 0x94, 0x01, 0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x04, 0x94, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x97, 0x01, 0x00, 0x9a, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x97, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x98, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x98, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0x98, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0x98, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0x98, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0x99, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x04, 0x99, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0x99, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x99, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0x9c, 0x01, 0x00, 0xdf, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x08, 0x15, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x11, 0x04, 0x00, 0x12, 0x06, 0x9d, 0x01, 0x02, 0xbd, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x04, 0x00, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x07, 0x0b, 0x0a, 0x20, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x0e, 0x1a, 0x10, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x9f, 0x01, 0x0c, 0x0d, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xa0, 0x01, 0x0e, 0x0f, 0x0a, 0x20, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xa1, 0x01, 0x04, 0x10, 0x22, 0x10, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa1, 0x01, 0x0e, 0x0f, 0x0a, 0x3e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x12, 0x22, 0x2e, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x50, 0x6f, 0x6e, 0x67, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x20, 0x66, 0x65, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xa2, 0x01, 0x10, 0x11, 0x0a, 0x20, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xa3, 0x01, 0x04, 0x17, 0x22, 0x10, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x50, 0x69, 0x6e, 0x67, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xa3, 0x01, 0x15, 0x16, 0x0a, 0x46, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0xa4, 0x01, 0x04, 0x0f, 0x22, 0x36, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x2c, 0x20, 0x61, 0x20, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x69, 0x66, 0x20, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x65, 0x64, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xa4, 0x01, 0x0d, 0x0e, 0x0a, 0x19, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0xa6, 0x01, 0x04, 0x16, 0x1a, 0x09, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0xa6, 0x01, 0x12, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0xa7, 0x01, 0x13, 0x16, 0x0a, 0x28, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x08, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x13, 0x22, 0x18, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xa8, 0x01, 0x0f, 0x12, 0x0a, 0x19, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x09, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x14, 0x1a, 0x09, 0x20, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x10, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x04, 0xab, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xab, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x04, 0xab, 0x01, 0x13, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x04, 0xac, 0x01, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xac, 0x01, 0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x04, 0xac, 0x01, 0x11, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x04, 0xad, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0c, 0x01, 0x12, 0x04, 0xad, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0c, 0x02, 0x12, 0x04, 0xad, 0x01, 0x10, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0d, 0x12, 0x04, 0xae, 0x01, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0d, 0x01, 0x12, 0x04, 0xae, 0x01, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0d, 0x02, 0x12, 0x04, 0xae, 0x01, 0x1b, 0x1e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0e, 0x12, 0x04, 0xaf, 0x01, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0e, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0e, 0x02, 0x12, 0x04, 0xaf, 0x01, 0x12, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0f, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0f, 0x01, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x0f, 0x02, 0x12, 0x04, 0xb0, 0x01, 0x10, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x10, 0x12, 0x04, 0xb1, 0x01, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x10, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x10, 0x02, 0x12, 0x04, 0xb1, 0x01, 0x11, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x11, 0x12, 0x04, 0xb2, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x11, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x11, 0x02, 0x12, 0x04, 0xb2, 0x01, 0x10, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x12, 0x12, 0x04, 0xb3, 0x01, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x12, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x12, 0x02, 0x12, 0x04, 0xb3, 0x01, 0x0f, 0x12, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x13, 0x12, 0x04, 0xb4, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x13, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x13, 0x02, 0x12, 0x04, 0xb4, 0x01, 0x13, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x14, 0x12, 0x04, 0xb5, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x14, 0x01, 0x12, 0x04, 0xb5, 0x01, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x14, 0x02, 0x12, 0x04, 0xb5, 0x01, 0x0c, 0x0f, 0x0a, 0x2a, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x15, 0x12, 0x04, 0xb6, 0x01, 0x04, 0x13, 0x22, 0x1a, 0x20, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x15, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x15, 0x02, 0x12, 0x04, 0xb6, 0x01, 0x0f, 0x12, 0x0a, 0x18, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x16, 0x12, 0x04, 0xb8, 0x01, 0x04, 0x16, 0x1a, 0x08, 0x20, 0x42, 0x75, 0x66, 0x66, 0x65, 0x72, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x16, 0x01, 0x12, 0x04, 0xb8, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x16, 0x02, 0x12, 0x04, 0xb8, 0x01, 0x12, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x17, 0x12, 0x04, 0xb9, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x17, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x17, 0x02, 0x12, 0x04, 0xb9, 0x01, 0x0b, 0x0e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x18, 0x12, 0x04, 0xba, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x18, 0x01, 0x12, 0x04, 0xba, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x18, 0x02, 0x12, 0x04, 0xba, 0x01, 0x0e, 0x11, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x19, 0x12, 0x04, 0xbb, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x19, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x19, 0x02, 0x12, 0x04, 0xbb, 0x01, 0x13, 0x16, 0x0a, 0x4a, 0x0a, 0x06, 0x04, 0x11, 0x04, 0x00, 0x02, 0x1a, 0x12, 0x04, 0xbc, 0x01, 0x04, 0x13, 0x22, 0x3a, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x20, 0x66, 0x65, 0x74, 0x63, 0x68, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x1a, 0x01, 0x12, 0x04, 0xbc, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x11, 0x04, 0x00, 0x02, 0x1a, 0x02, 0x12, 0x04, 0xbc, 0x01, 0x0f, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x06, 0x12, 0x04, 0xbf, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x10, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc1, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc2, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x05, 0x12, 0x04, 0xc4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x05, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x05, 0x04, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x05, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x06, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x04, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x06, 0x12, 0x04, 0xc7, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x18, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x07, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x04, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x06, 0x12, 0x04, 0xc8, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x19, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x08, 0x12, 0x04, 0xc9, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x08, 0x04, 0x12, 0x04, 0xc9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x08, 0x06, 0x12, 0x04, 0xc9, 0x01, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x08, 0x01, 0x12, 0x04, 0xc9, 0x01, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x08, 0x03, 0x12, 0x04, 0xc9, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x09, 0x12, 0x04, 0xca, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x09, 0x04, 0x12, 0x04, 0xca, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x09, 0x06, 0x12, 0x04, 0xca, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x09, 0x01, 0x12, 0x04, 0xca, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x09, 0x03, 0x12, 0x04, 0xca, 0x01, 0x23, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0a, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0a, 0x04, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0a, 0x06, 0x12, 0x04, 0xcb, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0a, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x26, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0b, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0b, 0x04, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0b, 0x06, 0x12, 0x04, 0xcc, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0b, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x1a, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0c, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0c, 0x04, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0c, 0x06, 0x12, 0x04, 0xcd, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0c, 0x01, 0x12, 0x04, 0xcd, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0c, 0x03, 0x12, 0x04, 0xcd, 0x01, 0x18, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0d, 0x12, 0x04, 0xce, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0d, 0x04, 0x12, 0x04, 0xce, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0d, 0x06, 0x12, 0x04, 0xce, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0d, 0x01, 0x12, 0x04, 0xce, 0x01, 0x14, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0d, 0x03, 0x12, 0x04, 0xce, 0x01, 0x1e, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0e, 0x12, 0x04, 0xcf, 0x01, 0x02, 0x3c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0e, 0x04, 0x12, 0x04, 0xcf, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0e, 0x06, 0x12, 0x04, 0xcf, 0x01, 0x0b, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0e, 0x01, 0x12, 0x04, 0xcf, 0x01, 0x21, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0e, 0x03, 0x12, 0x04, 0xcf, 0x01, 0x39, 0x3b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x0f, 0x12, 0x04, 0xd0, 0x01, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0f, 0x04, 0x12, 0x04, 0xd0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0f, 0x06, 0x12, 0x04, 0xd0, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0f, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x19, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x0f, 0x03, 0x12, 0x04, 0xd0, 0x01, 0x29, 0x2b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x10, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x10, 0x04, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x10, 0x06, 0x12, 0x04, 0xd1, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x10, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x18, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x10, 0x03, 0x12, 0x04, 0xd1, 0x01, 0x27, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x11, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x11, 0x04, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x11, 0x06, 0x12, 0x04, 0xd2, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x11, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x15, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x11, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x25, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x12, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x12, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x12, 0x06, 0x12, 0x04, 0xd3, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x12, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x12, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x23, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x13, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x13, 0x04, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x13, 0x05, 0x12, 0x04, 0xd4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x13, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x12, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x13, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x22, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x14, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x14, 0x04, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x14, 0x05, 0x12, 0x04, 0xd5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x14, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x12, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x14, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x24, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x15, 0x12, 0x04, 0xd6, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x15, 0x04, 0x12, 0x04, 0xd6, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x15, 0x05, 0x12, 0x04, 0xd6, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x15, 0x01, 0x12, 0x04, 0xd6, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x15, 0x03, 0x12, 0x04, 0xd6, 0x01, 0x23, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x16, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x16, 0x04, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x16, 0x06, 0x12, 0x04, 0xd7, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x16, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x11, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x16, 0x03, 0x12, 0x04, 0xd7, 0x01, 0x1f, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x17, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x17, 0x04, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x17, 0x06, 0x12, 0x04, 0xd8, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x17, 0x01, 0x12, 0x04, 0xd8, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x17, 0x03, 0x12, 0x04, 0xd8, 0x01, 0x23, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x18, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x18, 0x04, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x18, 0x06, 0x12, 0x04, 0xd9, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x18, 0x01, 0x12, 0x04, 0xd9, 0x01, 0x15, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x18, 0x03, 0x12, 0x04, 0xd9, 0x01, 0x20, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x19, 0x12, 0x04, 0xda, 0x01, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x19, 0x04, 0x12, 0x04, 0xda, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x19, 0x06, 0x12, 0x04, 0xda, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x19, 0x01, 0x12, 0x04, 0xda, 0x01, 0x19, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x19, 0x03, 0x12, 0x04, 0xda, 0x01, 0x29, 0x2b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x1a, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1a, 0x04, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1a, 0x06, 0x12, 0x04, 0xdb, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1a, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x14, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1a, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x1e, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x1b, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1b, 0x04, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1b, 0x06, 0x12, 0x04, 0xdc, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1b, 0x01, 0x12, 0x04, 0xdc, 0x01, 0x15, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1b, 0x03, 0x12, 0x04, 0xdc, 0x01, 0x20, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x1c, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1c, 0x04, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1c, 0x06, 0x12, 0x04, 0xdd, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1c, 0x01, 0x12, 0x04, 0xdd, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1c, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x1a, 0x1c, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x1d, 0x12, 0x04, 0xde, 0x01, 0x02, 0x1b, 0x22, 0x1e, 0x20, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1d, 0x04, 0x12, 0x04, 0xde, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1d, 0x06, 0x12, 0x04, 0xde, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1d, 0x01, 0x12, 0x04, 0xde, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x1d, 0x03, 0x12, 0x04, 0xde, 0x01, 0x18, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xe1, 0x01, 0x00, 0xe3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xe1, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xe2, 0x01, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe2, 0x01, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe2, 0x01, 0x1c, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe2, 0x01, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xe5, 0x01, 0x00, 0xec, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe6, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x06, 0x12, 0x04, 0xe7, 0x01, 0x0b, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x25, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x2d, 0x2e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x33, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x04, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x06, 0x12, 0x04, 0xe8, 0x01, 0x0b, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x21, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x31, 0x32, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x1f, 0x22, 0x29, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6e, 0x69, 0x63, 0x6b, 0x6e, 0x61, 0x6d, 0x65, 0x2c, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x64, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x04, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe9, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x1d, 0x1e, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0xea, 0x01, 0x02, 0x1b, 0x22, 0x13, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x77, 0x61, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x04, 0x12, 0x04, 0xea, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x05, 0x12, 0x04, 0xea, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x01, 0x12, 0x04, 0xea, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03, 0x12, 0x04, 0xea, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x06, 0x12, 0x04, 0xeb, 0x01, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x1b, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xee, 0x01, 0x00, 0xf6, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xee, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0xef, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0xef, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xef, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xef, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xef, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x06, 0x12, 0x04, 0xf0, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x16, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0xf1, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x04, 0x12, 0x04, 0xf1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf1, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x12, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf1, 0x01, 0x24, 0x25, 0x0a, 0x1a, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x20, 0x22, 0x0c, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x04, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0xf2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf2, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xf3, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0xf3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf3, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x05, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x04, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x05, 0x12, 0x04, 0xf4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x01, 0x12, 0x04, 0xf4, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x03, 0x12, 0x04, 0xf4, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x06, 0x12, 0x04, 0xf5, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x04, 0x12, 0x04, 0xf5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x06, 0x12, 0x04, 0xf5, 0x01, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x01, 0x12, 0x04, 0xf5, 0x01, 0x1a, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06, 0xf8, 0x01, 0x00, 0xfb, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf9, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf9, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf9, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x04, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xfa, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfa, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfa, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xfd, 0x01, 0x00, 0x80, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x04, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfe, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x1c, 0x1d, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0xff, 0x01, 0x02, 0x1c, 0x22, 0x12, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x63, 0x6c, 0x65, 0x61, 0x72, 0x65, 0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x04, 0x12, 0x04, 0xff, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x05, 0x12, 0x04, 0xff, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12, 0x04, 0xff, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04, 0xff, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0x82, 0x02, 0x00, 0x86, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0x82, 0x02, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0x83, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04, 0x83, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x02, 0x12, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0x84, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x02, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x02, 0x21, 0x22, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02, 0x12, 0x04, 0x85, 0x02, 0x02, 0x1f, 0x22, 0x19, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x62, 0x6f, 0x74, 0x68, 0x20, 0x73, 0x69, 0x64, 0x65, 0x73, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x04, 0x12, 0x04, 0x85, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x05, 0x12, 0x04, 0x85, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0x85, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0x85, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0x88, 0x02, 0x00, 0x90, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0x88, 0x02, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x18, 0x04, 0x00, 0x12, 0x06, 0x89, 0x02, 0x02, 0x8d, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x04, 0x00, 0x01, 0x12, 0x04, 0x89, 0x02, 0x07, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8a, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8a, 0x02, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x8a, 0x02, 0x1a, 0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x8b, 0x02, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8c, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x02, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8e, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8f, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x06, 0x12, 0x04, 0x8f, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x18, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0x92, 0x02, 0x00, 0x94, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0x92, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0x93, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0x93, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0x93, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0x93, 0x02, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0x93, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0x96, 0x02, 0x00, 0x9e, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0x96, 0x02, 0x08, 0x12, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1a, 0x04, 0x00, 0x12, 0x06, 0x97, 0x02, 0x02, 0x9b, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x04, 0x00, 0x01, 0x12, 0x04, 0x97, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x98, 0x02, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x98, 0x02, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x98, 0x02, 0x0d, 0x0e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x99, 0x02, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99, 0x02, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x99, 0x02, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x9a, 0x02, 0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9a, 0x02, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x9a, 0x02, 0x0c, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9c, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x10, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9c, 0x02, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x04, 0x12, 0x04, 0x9d, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9d, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9d, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0xa0, 0x02, 0x00, 0xa4, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa1, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa1, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0xa2, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa2, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa2, 0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa2, 0x02, 0x16, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa2, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x02, 0x12, 0x04, 0xa3, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa3, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa3, 0x02, 0x12, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa3, 0x02, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0xa6, 0x02, 0x00, 0xa9, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0xa7, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa7, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa7, 0x02, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa7, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa8, 0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x16, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa8, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0xab, 0x02, 0x00, 0xad, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xab, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xac, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x04, 0x12, 0x04, 0xac, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x05, 0x12, 0x04, 0xac, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xac, 0x02, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xac, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12, 0x06, 0xaf, 0x02, 0x00, 0xb1, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xaf, 0x02, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xb0, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb0, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb0, 0x02, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb0, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06, 0xb3, 0x02, 0x00, 0xb5, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb4, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb4, 0x02, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb4, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xb7, 0x02, 0x00, 0xba, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xb8, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb8, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb8, 0x02, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb8, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x01, 0x12, 0x04, 0xb9, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x04, 0x12, 0x04, 0xb9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb9, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb9, 0x02, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb9, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x21, 0x12, 0x06, 0xbc, 0x02, 0x00, 0xc4, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x21, 0x01, 0x12, 0x04, 0xbc, 0x02, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x00, 0x12, 0x04, 0xbd, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbd, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbd, 0x02, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbd, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x04, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x05, 0x12, 0x04, 0xbe, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbe, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x02, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x02, 0x04, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x02, 0x06, 0x12, 0x04, 0xbf, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbf, 0x02, 0x18, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x02, 0x03, 0x12, 0x04, 0xbf, 0x02, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc0, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x04, 0x12, 0x04, 0xc1, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x04, 0x04, 0x12, 0x04, 0xc1, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x04, 0x06, 0x12, 0x04, 0xc1, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc1, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc1, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x05, 0x12, 0x04, 0xc2, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x05, 0x04, 0x12, 0x04, 0xc2, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc2, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x05, 0x03, 0x12, 0x04, 0xc2, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x06, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x06, 0x04, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x06, 0x06, 0x12, 0x04, 0xc3, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x06, 0x01, 0x12, 0x04, 0xc3, 0x02, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x06, 0x03, 0x12, 0x04, 0xc3, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x06, 0xc6, 0x02, 0x00, 0xcf, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04, 0xc6, 0x02, 0x08, 0x11, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12, 0x04, 0xc7, 0x02, 0x02, 0x19, 0x22, 0x1b, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc7, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc7, 0x02, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc7, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc8, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x02, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc9, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc9, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc9, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x03, 0x12, 0x04, 0xca, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x03, 0x04, 0x12, 0x04, 0xca, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x03, 0x05, 0x12, 0x04, 0xca, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x03, 0x01, 0x12, 0x04, 0xca, 0x02, 0x12, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x03, 0x03, 0x12, 0x04, 0xca, 0x02, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x04, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x04, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x05, 0x12, 0x04, 0xcb, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x03, 0x12, 0x04, 0xcb, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x05, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x05, 0x04, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x05, 0x05, 0x12, 0x04, 0xcc, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x05, 0x01, 0x12, 0x04, 0xcc, 0x02, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x05, 0x03, 0x12, 0x04, 0xcc, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x06, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x05, 0x12, 0x04, 0xcd, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x01, 0x12, 0x04, 0xcd, 0x02, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x03, 0x12, 0x04, 0xcd, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x07, 0x12, 0x04, 0xce, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x04, 0x12, 0x04, 0xce, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x05, 0x12, 0x04, 0xce, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x01, 0x12, 0x04, 0xce, 0x02, 0x10, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x03, 0x12, 0x04, 0xce, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x06, 0xd1, 0x02, 0x00, 0xd4, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0xd1, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd2, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd2, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x01, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd3, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd3, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd3, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x24, 0x12, 0x06, 0xd6, 0x02, 0x00, 0xd8, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x24, 0x01, 0x12, 0x04, 0xd6, 0x02, 0x08, 0x12, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x00, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x1e, 0x22, 0x13, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x77, 0x61, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd7, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd7, 0x02, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd7, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0xda, 0x02, 0x00, 0xdc, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x25, 0x01, 0x12, 0x04, 0xda, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x00, 0x12, 0x04, 0xdb, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x04, 0x12, 0x04, 0xdb, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x05, 0x12, 0x04, 0xdb, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x01, 0x12, 0x04, 0xdb, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x03, 0x12, 0x04, 0xdb, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x26, 0x12, 0x06, 0xde, 0x02, 0x00, 0xe0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x26, 0x01, 0x12, 0x04, 0xde, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x00, 0x12, 0x04, 0xdf, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x04, 0x12, 0x04, 0xdf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x05, 0x12, 0x04, 0xdf, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x01, 0x12, 0x04, 0xdf, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x03, 0x12, 0x04, 0xdf, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x27, 0x12, 0x06, 0xe2, 0x02, 0x00, 0xe4, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x27, 0x01, 0x12, 0x04, 0xe2, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x00, 0x12, 0x04, 0xe3, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe3, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe3, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe3, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x28, 0x12, 0x06, 0xe6, 0x02, 0x00, 0xe9, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x28, 0x01, 0x12, 0x04, 0xe6, 0x02, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x00, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe7, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe7, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe7, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x01, 0x12, 0x04, 0xe8, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe8, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe8, 0x02, 0x10, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe8, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x29, 0x12, 0x06, 0xeb, 0x02, 0x00, 0xf2, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x29, 0x01, 0x12, 0x04, 0xeb, 0x02, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x00, 0x12, 0x04, 0xec, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x04, 0x12, 0x04, 0xec, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x05, 0x12, 0x04, 0xec, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x01, 0x12, 0x04, 0xec, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x03, 0x12, 0x04, 0xec, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x01, 0x12, 0x04, 0xed, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x04, 0x12, 0x04, 0xed, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x05, 0x12, 0x04, 0xed, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x01, 0x12, 0x04, 0xed, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x03, 0x12, 0x04, 0xed, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x02, 0x12, 0x04, 0xee, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x04, 0x12, 0x04, 0xee, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x05, 0x12, 0x04, 0xee, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x01, 0x12, 0x04, 0xee, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x03, 0x12, 0x04, 0xee, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x03, 0x12, 0x04, 0xef, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x04, 0x12, 0x04, 0xef, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x05, 0x12, 0x04, 0xef, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x01, 0x12, 0x04, 0xef, 0x02, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x03, 0x12, 0x04, 0xef, 0x02, 0x1d, 0x1e, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x04, 0x12, 0x04, 0xf0, 0x02, 0x02, 0x1e, 0x22, 0x23, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6c, 0x6f, 0x67, 0x67, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x20, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x04, 0x12, 0x04, 0xf0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf0, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf0, 0x02, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf0, 0x02, 0x1c, 0x1d, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x05, 0x12, 0x04, 0xf1, 0x02, 0x02, 0x1b, 0x22, 0x12, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x04, 0x12, 0x04, 0xf1, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x05, 0x12, 0x04, 0xf1, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x01, 0x12, 0x04, 0xf1, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x03, 0x12, 0x04, 0xf1, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2a, 0x12, 0x06, 0xf4, 0x02, 0x00, 0xfd, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2a, 0x01, 0x12, 0x04, 0xf4, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x00, 0x12, 0x04, 0xf5, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf5, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf5, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf5, 0x02, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf5, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf6, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf6, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x02, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x02, 0x04, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x02, 0x06, 0x12, 0x04, 0xf7, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf7, 0x02, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf7, 0x02, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x03, 0x12, 0x04, 0xf8, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x03, 0x04, 0x12, 0x04, 0xf8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x03, 0x06, 0x12, 0x04, 0xf8, 0x02, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf8, 0x02, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf8, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x04, 0x12, 0x04, 0xf9, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x04, 0x04, 0x12, 0x04, 0xf9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x04, 0x06, 0x12, 0x04, 0xf9, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf9, 0x02, 0x14, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf9, 0x02, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x05, 0x12, 0x04, 0xfa, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x05, 0x04, 0x12, 0x04, 0xfa, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x05, 0x05, 0x12, 0x04, 0xfa, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x05, 0x01, 0x12, 0x04, 0xfa, 0x02, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x05, 0x03, 0x12, 0x04, 0xfa, 0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x06, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x06, 0x04, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x06, 0x05, 0x12, 0x04, 0xfb, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x06, 0x01, 0x12, 0x04, 0xfb, 0x02, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x06, 0x03, 0x12, 0x04, 0xfb, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x07, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x07, 0x04, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x07, 0x05, 0x12, 0x04, 0xfc, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x07, 0x01, 0x12, 0x04, 0xfc, 0x02, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x07, 0x03, 0x12, 0x04, 0xfc, 0x02, 0x1b, 0x1c];
 
 pub fn file_descriptor_proto() -> descriptor::FileDescriptorProto {
     parse_from_bytes(file_descriptor_proto_data)
@@ -21,6 +21,17 @@ pub struct RemoteCommand {
     send_privmsg: Option<SendPrivmsgT>,
     set_network_configuration: Option<SetNetworkConfigurationT>,
     get_message_range: Option<GetMessageRangeT>,
+    part_channel: Option<PartChannelT>,
+    set_perform_list: Option<PerformListT>,
+    identity: Option<IdentityT>,
+    set_away: Option<SetAwayT>,
+    set_buddy_list: Option<BuddyListT>,
+    send_command: Option<SendCommandT>,
+    client_address: Option<ClientAddressT>,
+    login: Option<LoginT>,
+    hello: Option<HelloT>,
+    set_last_read: Option<SetLastReadT>,
+    ping: Option<PingT>,
 }
 
 impl<'a> RemoteCommand {
@@ -35,6 +46,17 @@ impl<'a> RemoteCommand {
             send_privmsg: None,
             set_network_configuration: None,
             get_message_range: None,
+            part_channel: None,
+            set_perform_list: None,
+            identity: None,
+            set_away: None,
+            set_buddy_list: None,
+            send_command: None,
+            client_address: None,
+            login: None,
+            hello: None,
+            set_last_read: None,
+            ping: None,
         }
     }
 
@@ -49,6 +71,17 @@ impl<'a> RemoteCommand {
             send_privmsg: None,
             set_network_configuration: None,
             get_message_range: None,
+            part_channel: None,
+            set_perform_list: None,
+            identity: None,
+            set_away: None,
+            set_buddy_list: None,
+            send_command: None,
+            client_address: None,
+            login: None,
+            hello: None,
+            set_last_read: None,
+            ping: None,
         };
         &'static instance
     }
@@ -123,6 +156,105 @@ impl<'a> RemoteCommand {
             },
             None => {},
         };
+        match self.part_channel {
+            Some(ref v) => {
+                os.write_tag(11, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.set_perform_list {
+            Some(ref v) => {
+                os.write_tag(12, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.identity {
+            Some(ref v) => {
+                os.write_tag(13, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.set_away {
+            Some(ref v) => {
+                os.write_tag(14, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.set_buddy_list {
+            Some(ref v) => {
+                os.write_tag(15, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.send_command {
+            Some(ref v) => {
+                os.write_tag(16, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.client_address {
+            Some(ref v) => {
+                os.write_tag(17, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.login {
+            Some(ref v) => {
+                os.write_tag(18, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.hello {
+            Some(ref v) => {
+                os.write_tag(19, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.set_last_read {
+            Some(ref v) => {
+                os.write_tag(20, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.ping {
+            Some(ref v) => {
+                os.write_tag(21, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
     }
 
     pub fn clear_packet_type(&mut self) {
@@ -373,481 +505,357 @@ impl<'a> RemoteCommand {
             None => GetMessageRangeT::default_instance(),
         }
     }
-}
 
-impl Message for RemoteCommand {
-    fn new() -> RemoteCommand {
-        RemoteCommand::new()
+    pub fn clear_part_channel(&mut self) {
+        self.part_channel = None;
     }
 
-    fn clear(&mut self) {
-        self.clear_packet_type();
-        self.clear_network_id();
-        self.clear_buffer_id();
-        self.clear_tag();
-        self.clear_attach_session();
-        self.clear_join_channel();
-        self.clear_send_privmsg();
-        self.clear_set_network_configuration();
-        self.clear_get_message_range();
+    pub fn has_part_channel(&self) -> bool {
+        self.part_channel.is_some()
     }
 
-    fn is_initialized(&self) -> bool {
-        if self.packet_type.is_none() {
-            return false;
+    // Param is passed by value, moved
+    pub fn set_part_channel(&mut self, v: PartChannelT) {
+        self.part_channel = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_part_channel(&'a mut self) -> &'a mut PartChannelT {
+        if self.part_channel.is_none() {
+            self.part_channel = Some(PartChannelT::new());
         };
-        true
+        self.part_channel.get_mut_ref()
     }
 
-    fn merge_from(&mut self, is: &mut CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
-            match field_number {
-                1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = RemoteCommand_Type::new(is.read_int32());
-                    self.packet_type = Some(tmp);
-                },
-                2 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.network_id = Some(tmp);
-                },
-                3 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.buffer_id = Some(tmp);
-                },
-                4 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.tag = Some(tmp);
-                },
-                5 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = AttachSessionT::new();
-                    is.merge_message(&mut tmp);
-                    self.attach_session = Some(tmp);
-                },
-                7 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = JoinChannelT::new();
-                    is.merge_message(&mut tmp);
-                    self.join_channel = Some(tmp);
-                },
-                8 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = SendPrivmsgT::new();
-                    is.merge_message(&mut tmp);
-                    self.send_privmsg = Some(tmp);
-                },
-                9 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = SetNetworkConfigurationT::new();
-                    is.merge_message(&mut tmp);
-                    self.set_network_configuration = Some(tmp);
-                },
-                10 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = GetMessageRangeT::new();
-                    is.merge_message(&mut tmp);
-                    self.get_message_range = Some(tmp);
-                },
-                _ => {
-                    // TODO: store in unknown fields
-                    is.skip_field(wire_type);
-                },
-            };
+    pub fn get_part_channel(&'a self) -> &'a PartChannelT {
+        match self.part_channel {
+            Some(ref v) => v,
+            None => PartChannelT::default_instance(),
         }
     }
 
-    // Compute sizes of nested messages
-    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
-        let pos = sizes.len();
-        sizes.push(0);
-        let mut my_size = 0;
-        for value in self.packet_type.iter() {
-            my_size += rt::enum_size(1, *value);
-        };
-        for value in self.network_id.iter() {
-            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.buffer_id.iter() {
-            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.tag.iter() {
-            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.attach_session.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.join_channel.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.send_privmsg.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.set_network_configuration.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.get_message_range.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        sizes[pos] = my_size;
-        // value is returned for convenience
-        my_size
+    pub fn clear_set_perform_list(&mut self) {
+        self.set_perform_list = None;
     }
 
-    fn write_to(&self, os: &mut CodedOutputStream) {
-        self.check_initialized();
-        let mut sizes: ~[u32] = ~[];
-        self.compute_sizes(&mut sizes);
-        let mut sizes_pos = 1; // first element is self
-        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
-        assert_eq!(sizes_pos, sizes.len());
+    pub fn has_set_perform_list(&self) -> bool {
+        self.set_perform_list.is_some()
     }
-}
 
-#[deriving(Clone,Eq)]
-pub enum RemoteCommand_Type {
-    AttachSession = 1,
-    GetNetworkList = 100,
-    Connect = 200,
-    JoinChannel = 202,
-    SendPrivmsg = 203,
-    GetBufferList = 204,
-    Disconnect = 205,
-    GetNetworkConfiguration = 206,
-    SetNetworkConfiguration = 207,
-    GetMessageRange = 300,
-}
+    // Param is passed by value, moved
+    pub fn set_set_perform_list(&mut self, v: PerformListT) {
+        self.set_perform_list = Some(v);
+    }
 
-impl RemoteCommand_Type {
-    pub fn new(value: i32) -> RemoteCommand_Type {
-        match value {
-            1 => AttachSession,
-            100 => GetNetworkList,
-            200 => Connect,
-            202 => JoinChannel,
-            203 => SendPrivmsg,
-            204 => GetBufferList,
-            205 => Disconnect,
-            206 => GetNetworkConfiguration,
-            207 => SetNetworkConfiguration,
-            300 => GetMessageRange,
-            _ => fail!()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_set_perform_list(&'a mut self) -> &'a mut PerformListT {
+        if self.set_perform_list.is_none() {
+            self.set_perform_list = Some(PerformListT::new());
+        };
+        self.set_perform_list.get_mut_ref()
+    }
+
+    pub fn get_set_perform_list(&'a self) -> &'a PerformListT {
+        match self.set_perform_list {
+            Some(ref v) => v,
+            None => PerformListT::default_instance(),
         }
     }
-}
 
-impl ProtobufEnum for RemoteCommand_Type {
-    fn value(&self) -> i32 {
-        *self as i32
+    pub fn clear_identity(&mut self) {
+        self.identity = None;
     }
-}
 
-#[deriving(Clone,Eq)]
-pub struct AttachSessionT {
-    session_id: Option<u64>,
-}
+    pub fn has_identity(&self) -> bool {
+        self.identity.is_some()
+    }
 
-impl<'a> AttachSessionT {
-    pub fn new() -> AttachSessionT {
-        AttachSessionT {
-            session_id: None,
-        }
+    // Param is passed by value, moved
+    pub fn set_identity(&mut self, v: IdentityT) {
+        self.identity = Some(v);
     }
 
-    pub fn default_instance() -> &'static AttachSessionT {
-        static instance: AttachSessionT = AttachSessionT {
-            session_id: None,
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identity(&'a mut self) -> &'a mut IdentityT {
+        if self.identity.is_none() {
+            self.identity = Some(IdentityT::new());
         };
-        &'static instance
+        self.identity.get_mut_ref()
     }
 
-    #[allow(unused_variable)]
-    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.session_id {
-            Some(ref v) => {
-                os.write_uint64(1, *v);
-            },
-            None => {},
-        };
+    pub fn get_identity(&'a self) -> &'a IdentityT {
+        match self.identity {
+            Some(ref v) => v,
+            None => IdentityT::default_instance(),
+        }
     }
 
-    pub fn clear_session_id(&mut self) {
-        self.session_id = None;
+    pub fn clear_set_away(&mut self) {
+        self.set_away = None;
     }
 
-    pub fn has_session_id(&self) -> bool {
-        self.session_id.is_some()
+    pub fn has_set_away(&self) -> bool {
+        self.set_away.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_session_id(&mut self, v: u64) {
-        self.session_id = Some(v);
+    pub fn set_set_away(&mut self, v: SetAwayT) {
+        self.set_away = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_session_id(&'a mut self) -> &'a mut u64 {
-        if self.session_id.is_none() {
-            self.session_id = Some(0);
+    pub fn mut_set_away(&'a mut self) -> &'a mut SetAwayT {
+        if self.set_away.is_none() {
+            self.set_away = Some(SetAwayT::new());
         };
-        self.session_id.get_mut_ref()
+        self.set_away.get_mut_ref()
     }
 
-    pub fn get_session_id(&self) -> u64 {
-        self.session_id.unwrap_or(0)
+    pub fn get_set_away(&'a self) -> &'a SetAwayT {
+        match self.set_away {
+            Some(ref v) => v,
+            None => SetAwayT::default_instance(),
+        }
     }
-}
 
-impl Message for AttachSessionT {
-    fn new() -> AttachSessionT {
-        AttachSessionT::new()
+    pub fn clear_set_buddy_list(&mut self) {
+        self.set_buddy_list = None;
     }
 
-    fn clear(&mut self) {
-        self.clear_session_id();
+    pub fn has_set_buddy_list(&self) -> bool {
+        self.set_buddy_list.is_some()
     }
 
-    fn is_initialized(&self) -> bool {
-        if self.session_id.is_none() {
-            return false;
+    // Param is passed by value, moved
+    pub fn set_set_buddy_list(&mut self, v: BuddyListT) {
+        self.set_buddy_list = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_set_buddy_list(&'a mut self) -> &'a mut BuddyListT {
+        if self.set_buddy_list.is_none() {
+            self.set_buddy_list = Some(BuddyListT::new());
         };
-        true
+        self.set_buddy_list.get_mut_ref()
     }
 
-    fn merge_from(&mut self, is: &mut CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
-            match field_number {
-                1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.session_id = Some(tmp);
-                },
-                _ => {
-                    // TODO: store in unknown fields
-                    is.skip_field(wire_type);
-                },
-            };
+    pub fn get_set_buddy_list(&'a self) -> &'a BuddyListT {
+        match self.set_buddy_list {
+            Some(ref v) => v,
+            None => BuddyListT::default_instance(),
         }
     }
 
-    // Compute sizes of nested messages
-    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
-        let pos = sizes.len();
-        sizes.push(0);
-        let mut my_size = 0;
-        for value in self.session_id.iter() {
-            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
-        };
-        sizes[pos] = my_size;
-        // value is returned for convenience
-        my_size
+    pub fn clear_send_command(&mut self) {
+        self.send_command = None;
     }
 
-    fn write_to(&self, os: &mut CodedOutputStream) {
-        self.check_initialized();
-        let mut sizes: ~[u32] = ~[];
-        self.compute_sizes(&mut sizes);
-        let mut sizes_pos = 1; // first element is self
-        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
-        assert_eq!(sizes_pos, sizes.len());
+    pub fn has_send_command(&self) -> bool {
+        self.send_command.is_some()
     }
-}
-
-#[deriving(Clone,Eq)]
-pub struct RegisterT {
-    nickname: Option<~str>,
-}
 
-impl<'a> RegisterT {
-    pub fn new() -> RegisterT {
-        RegisterT {
-            nickname: None,
-        }
+    // Param is passed by value, moved
+    pub fn set_send_command(&mut self, v: SendCommandT) {
+        self.send_command = Some(v);
     }
 
-    pub fn default_instance() -> &'static RegisterT {
-        static instance: RegisterT = RegisterT {
-            nickname: None,
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_send_command(&'a mut self) -> &'a mut SendCommandT {
+        if self.send_command.is_none() {
+            self.send_command = Some(SendCommandT::new());
         };
-        &'static instance
+        self.send_command.get_mut_ref()
     }
 
-    #[allow(unused_variable)]
-    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.nickname {
-            Some(ref v) => {
-                os.write_string(2, *v);
-            },
-            None => {},
-        };
+    pub fn get_send_command(&'a self) -> &'a SendCommandT {
+        match self.send_command {
+            Some(ref v) => v,
+            None => SendCommandT::default_instance(),
+        }
     }
 
-    pub fn clear_nickname(&mut self) {
-        self.nickname = None;
+    pub fn clear_client_address(&mut self) {
+        self.client_address = None;
     }
 
-    pub fn has_nickname(&self) -> bool {
-        self.nickname.is_some()
+    pub fn has_client_address(&self) -> bool {
+        self.client_address.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_nickname(&mut self, v: ~str) {
-        self.nickname = Some(v);
+    pub fn set_client_address(&mut self, v: ClientAddressT) {
+        self.client_address = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
-        if self.nickname.is_none() {
-            self.nickname = Some(~"");
+    pub fn mut_client_address(&'a mut self) -> &'a mut ClientAddressT {
+        if self.client_address.is_none() {
+            self.client_address = Some(ClientAddressT::new());
         };
-        self.nickname.get_mut_ref()
+        self.client_address.get_mut_ref()
     }
 
-    pub fn get_nickname(&'a self) -> &'a str {
-        match self.nickname {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
+    pub fn get_client_address(&'a self) -> &'a ClientAddressT {
+        match self.client_address {
+            Some(ref v) => v,
+            None => ClientAddressT::default_instance(),
         }
     }
-}
 
-impl Message for RegisterT {
-    fn new() -> RegisterT {
-        RegisterT::new()
+    pub fn clear_login(&mut self) {
+        self.login = None;
     }
 
-    fn clear(&mut self) {
-        self.clear_nickname();
+    pub fn has_login(&self) -> bool {
+        self.login.is_some()
     }
 
-    fn is_initialized(&self) -> bool {
-        if self.nickname.is_none() {
-            return false;
+    // Param is passed by value, moved
+    pub fn set_login(&mut self, v: LoginT) {
+        self.login = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_login(&'a mut self) -> &'a mut LoginT {
+        if self.login.is_none() {
+            self.login = Some(LoginT::new());
         };
-        true
+        self.login.get_mut_ref()
     }
 
-    fn merge_from(&mut self, is: &mut CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
-            match field_number {
-                2 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.nickname = Some(tmp);
-                },
-                _ => {
-                    // TODO: store in unknown fields
-                    is.skip_field(wire_type);
-                },
-            };
+    pub fn get_login(&'a self) -> &'a LoginT {
+        match self.login {
+            Some(ref v) => v,
+            None => LoginT::default_instance(),
         }
     }
 
-    // Compute sizes of nested messages
-    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
-        let pos = sizes.len();
-        sizes.push(0);
-        let mut my_size = 0;
-        for value in self.nickname.iter() {
-            my_size += rt::string_size(2, *value);
-        };
-        sizes[pos] = my_size;
-        // value is returned for convenience
-        my_size
+    pub fn clear_hello(&mut self) {
+        self.hello = None;
     }
 
-    fn write_to(&self, os: &mut CodedOutputStream) {
-        self.check_initialized();
-        let mut sizes: ~[u32] = ~[];
-        self.compute_sizes(&mut sizes);
-        let mut sizes_pos = 1; // first element is self
-        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
-        assert_eq!(sizes_pos, sizes.len());
+    pub fn has_hello(&self) -> bool {
+        self.hello.is_some()
     }
-}
 
-#[deriving(Clone,Eq)]
-pub struct JoinChannelT {
-    channel: Option<~str>,
-}
+    // Param is passed by value, moved
+    pub fn set_hello(&mut self, v: HelloT) {
+        self.hello = Some(v);
+    }
 
-impl<'a> JoinChannelT {
-    pub fn new() -> JoinChannelT {
-        JoinChannelT {
-            channel: None,
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hello(&'a mut self) -> &'a mut HelloT {
+        if self.hello.is_none() {
+            self.hello = Some(HelloT::new());
+        };
+        self.hello.get_mut_ref()
+    }
+
+    pub fn get_hello(&'a self) -> &'a HelloT {
+        match self.hello {
+            Some(ref v) => v,
+            None => HelloT::default_instance(),
         }
     }
 
-    pub fn default_instance() -> &'static JoinChannelT {
-        static instance: JoinChannelT = JoinChannelT {
-            channel: None,
-        };
-        &'static instance
+    pub fn clear_set_last_read(&mut self) {
+        self.set_last_read = None;
     }
 
-    #[allow(unused_variable)]
-    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.channel {
-            Some(ref v) => {
-                os.write_string(2, *v);
-            },
-            None => {},
+    pub fn has_set_last_read(&self) -> bool {
+        self.set_last_read.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_last_read(&mut self, v: SetLastReadT) {
+        self.set_last_read = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_set_last_read(&'a mut self) -> &'a mut SetLastReadT {
+        if self.set_last_read.is_none() {
+            self.set_last_read = Some(SetLastReadT::new());
         };
+        self.set_last_read.get_mut_ref()
     }
 
-    pub fn clear_channel(&mut self) {
-        self.channel = None;
+    pub fn get_set_last_read(&'a self) -> &'a SetLastReadT {
+        match self.set_last_read {
+            Some(ref v) => v,
+            None => SetLastReadT::default_instance(),
+        }
     }
 
-    pub fn has_channel(&self) -> bool {
-        self.channel.is_some()
+    pub fn clear_ping(&mut self) {
+        self.ping = None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        self.ping.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_channel(&mut self, v: ~str) {
-        self.channel = Some(v);
+    pub fn set_ping(&mut self, v: PingT) {
+        self.ping = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_channel(&'a mut self) -> &'a mut ~str {
-        if self.channel.is_none() {
-            self.channel = Some(~"");
+    pub fn mut_ping(&'a mut self) -> &'a mut PingT {
+        if self.ping.is_none() {
+            self.ping = Some(PingT::new());
         };
-        self.channel.get_mut_ref()
+        self.ping.get_mut_ref()
     }
 
-    pub fn get_channel(&'a self) -> &'a str {
-        match self.channel {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
+    pub fn get_ping(&'a self) -> &'a PingT {
+        match self.ping {
+            Some(ref v) => v,
+            None => PingT::default_instance(),
         }
     }
 }
 
-impl Message for JoinChannelT {
-    fn new() -> JoinChannelT {
-        JoinChannelT::new()
+impl Message for RemoteCommand {
+    fn new() -> RemoteCommand {
+        RemoteCommand::new()
     }
 
     fn clear(&mut self) {
-        self.clear_channel();
+        self.clear_packet_type();
+        self.clear_network_id();
+        self.clear_buffer_id();
+        self.clear_tag();
+        self.clear_attach_session();
+        self.clear_join_channel();
+        self.clear_send_privmsg();
+        self.clear_set_network_configuration();
+        self.clear_get_message_range();
+        self.clear_part_channel();
+        self.clear_set_perform_list();
+        self.clear_identity();
+        self.clear_set_away();
+        self.clear_set_buddy_list();
+        self.clear_send_command();
+        self.clear_client_address();
+        self.clear_login();
+        self.clear_hello();
+        self.clear_set_last_read();
+        self.clear_ping();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.channel.is_none() {
+        if self.packet_type.is_none() {
             return false;
         };
         true
@@ -857,10 +865,121 @@ impl Message for JoinChannelT {
         while !is.eof() {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = RemoteCommand_Type::new(is.read_int32());
+                    self.packet_type = Some(tmp);
+                },
                 2 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.channel = Some(tmp);
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.network_id = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.buffer_id = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.tag = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = AttachSessionT::new();
+                    is.merge_message(&mut tmp);
+                    self.attach_session = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = JoinChannelT::new();
+                    is.merge_message(&mut tmp);
+                    self.join_channel = Some(tmp);
+                },
+                8 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SendPrivmsgT::new();
+                    is.merge_message(&mut tmp);
+                    self.send_privmsg = Some(tmp);
+                },
+                9 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SetNetworkConfigurationT::new();
+                    is.merge_message(&mut tmp);
+                    self.set_network_configuration = Some(tmp);
+                },
+                10 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = GetMessageRangeT::new();
+                    is.merge_message(&mut tmp);
+                    self.get_message_range = Some(tmp);
+                },
+                11 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PartChannelT::new();
+                    is.merge_message(&mut tmp);
+                    self.part_channel = Some(tmp);
+                },
+                12 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PerformListT::new();
+                    is.merge_message(&mut tmp);
+                    self.set_perform_list = Some(tmp);
+                },
+                13 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = IdentityT::new();
+                    is.merge_message(&mut tmp);
+                    self.identity = Some(tmp);
+                },
+                14 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SetAwayT::new();
+                    is.merge_message(&mut tmp);
+                    self.set_away = Some(tmp);
+                },
+                15 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BuddyListT::new();
+                    is.merge_message(&mut tmp);
+                    self.set_buddy_list = Some(tmp);
+                },
+                16 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SendCommandT::new();
+                    is.merge_message(&mut tmp);
+                    self.send_command = Some(tmp);
+                },
+                17 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ClientAddressT::new();
+                    is.merge_message(&mut tmp);
+                    self.client_address = Some(tmp);
+                },
+                18 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = LoginT::new();
+                    is.merge_message(&mut tmp);
+                    self.login = Some(tmp);
+                },
+                19 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = HelloT::new();
+                    is.merge_message(&mut tmp);
+                    self.hello = Some(tmp);
+                },
+                20 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SetLastReadT::new();
+                    is.merge_message(&mut tmp);
+                    self.set_last_read = Some(tmp);
+                },
+                21 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PingT::new();
+                    is.merge_message(&mut tmp);
+                    self.ping = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -875,8 +994,81 @@ impl Message for JoinChannelT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.channel.iter() {
-            my_size += rt::string_size(2, *value);
+        for value in self.packet_type.iter() {
+            my_size += rt::enum_size(1, *value);
+        };
+        for value in self.network_id.iter() {
+            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.buffer_id.iter() {
+            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.tag.iter() {
+            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.attach_session.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.join_channel.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.send_privmsg.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.set_network_configuration.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.get_message_range.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.part_channel.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.set_perform_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.identity.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.set_away.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.set_buddy_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.send_command.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.client_address.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.login.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.hello.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.set_last_read.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.ping.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -894,117 +1086,178 @@ impl Message for JoinChannelT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct SendPrivmsgT {
-    target: Option<~str>,
-    msg: Option<~str>,
+pub enum RemoteCommand_Type {
+    AttachSession = 1,
+    Login = 2,
+    Hello = 3,
+    Ping = 4,
+    Pong = 5,
+    GetNetworkList = 100,
+    GetIdentityList = 101,
+    CreateIdentity = 102,
+    UpdateIdentity = 103,
+    SetClientAddress = 104,
+    Connect = 200,
+    JoinChannel = 202,
+    SendPrivmsg = 203,
+    GetBufferList = 204,
+    Disconnect = 205,
+    GetNetworkConfiguration = 206,
+    SetNetworkConfiguration = 207,
+    PartChannel = 208,
+    GetPerformList = 209,
+    SetPerformList = 210,
+    SetAway = 211,
+    GetBuddyList = 212,
+    SetBuddyList = 213,
+    SendCommand = 214,
+    SetLastRead = 215,
+    GetMessageRange = 300,
 }
 
-impl<'a> SendPrivmsgT {
-    pub fn new() -> SendPrivmsgT {
-        SendPrivmsgT {
-            target: None,
-            msg: None,
+impl RemoteCommand_Type {
+    pub fn new(value: i32) -> RemoteCommand_Type {
+        match value {
+            1 => AttachSession,
+            2 => Login,
+            3 => Hello,
+            4 => Ping,
+            5 => Pong,
+            100 => GetNetworkList,
+            101 => GetIdentityList,
+            102 => CreateIdentity,
+            103 => UpdateIdentity,
+            104 => SetClientAddress,
+            200 => Connect,
+            202 => JoinChannel,
+            203 => SendPrivmsg,
+            204 => GetBufferList,
+            205 => Disconnect,
+            206 => GetNetworkConfiguration,
+            207 => SetNetworkConfiguration,
+            208 => PartChannel,
+            209 => GetPerformList,
+            210 => SetPerformList,
+            211 => SetAway,
+            212 => GetBuddyList,
+            213 => SetBuddyList,
+            214 => SendCommand,
+            215 => SetLastRead,
+            300 => GetMessageRange,
+            _ => fail!()
         }
     }
+}
 
-    pub fn default_instance() -> &'static SendPrivmsgT {
-        static instance: SendPrivmsgT = SendPrivmsgT {
-            target: None,
-            msg: None,
+impl ProtobufEnum for RemoteCommand_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct PingT {
+    id: Option<u64>,
+    rtt_ms: Option<u64>,
+}
+
+impl<'a> PingT {
+    pub fn new() -> PingT {
+        PingT {
+            id: None,
+            rtt_ms: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static PingT {
+        static instance: PingT = PingT {
+            id: None,
+            rtt_ms: None,
         };
         &'static instance
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.target {
+        match self.id {
             Some(ref v) => {
-                os.write_string(2, *v);
+                os.write_uint64(1, *v);
             },
             None => {},
         };
-        match self.msg {
+        match self.rtt_ms {
             Some(ref v) => {
-                os.write_string(3, *v);
+                os.write_uint64(2, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_target(&mut self) {
-        self.target = None;
+    pub fn clear_id(&mut self) {
+        self.id = None;
     }
 
-    pub fn has_target(&self) -> bool {
-        self.target.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_target(&mut self, v: ~str) {
-        self.target = Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_target(&'a mut self) -> &'a mut ~str {
-        if self.target.is_none() {
-            self.target = Some(~"");
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
         };
-        self.target.get_mut_ref()
+        self.id.get_mut_ref()
     }
 
-    pub fn get_target(&'a self) -> &'a str {
-        match self.target {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    pub fn clear_msg(&mut self) {
-        self.msg = None;
+    pub fn clear_rtt_ms(&mut self) {
+        self.rtt_ms = None;
     }
 
-    pub fn has_msg(&self) -> bool {
-        self.msg.is_some()
+    pub fn has_rtt_ms(&self) -> bool {
+        self.rtt_ms.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_msg(&mut self, v: ~str) {
-        self.msg = Some(v);
+    pub fn set_rtt_ms(&mut self, v: u64) {
+        self.rtt_ms = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
-        if self.msg.is_none() {
-            self.msg = Some(~"");
+    pub fn mut_rtt_ms(&'a mut self) -> &'a mut u64 {
+        if self.rtt_ms.is_none() {
+            self.rtt_ms = Some(0);
         };
-        self.msg.get_mut_ref()
+        self.rtt_ms.get_mut_ref()
     }
 
-    pub fn get_msg(&'a self) -> &'a str {
-        match self.msg {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_rtt_ms(&self) -> u64 {
+        self.rtt_ms.unwrap_or(0)
     }
 }
 
-impl Message for SendPrivmsgT {
-    fn new() -> SendPrivmsgT {
-        SendPrivmsgT::new()
+impl Message for PingT {
+    fn new() -> PingT {
+        PingT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_target();
-        self.clear_msg();
+        self.clear_id();
+        self.clear_rtt_ms();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.target.is_none() {
-            return false;
-        };
-        if self.msg.is_none() {
+        if self.id.is_none() {
             return false;
         };
         true
@@ -1014,15 +1267,15 @@ impl Message for SendPrivmsgT {
         while !is.eof() {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
-                2 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.target = Some(tmp);
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
                 },
-                3 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.msg = Some(tmp);
+                2 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.rtt_ms = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -1037,11 +1290,11 @@ impl Message for SendPrivmsgT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.target.iter() {
-            my_size += rt::string_size(2, *value);
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
         };
-        for value in self.msg.iter() {
-            my_size += rt::string_size(3, *value);
+        for value in self.rtt_ms.iter() {
+            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -1059,117 +1312,72 @@ impl Message for SendPrivmsgT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct SetNetworkConfigurationT {
-    server: Option<~str>,
-    nickname: Option<~str>,
+pub struct SetLastReadT {
+    message_id: Option<u64>,
 }
 
-impl<'a> SetNetworkConfigurationT {
-    pub fn new() -> SetNetworkConfigurationT {
-        SetNetworkConfigurationT {
-            server: None,
-            nickname: None,
+impl<'a> SetLastReadT {
+    pub fn new() -> SetLastReadT {
+        SetLastReadT {
+            message_id: None,
         }
     }
 
-    pub fn default_instance() -> &'static SetNetworkConfigurationT {
-        static instance: SetNetworkConfigurationT = SetNetworkConfigurationT {
-            server: None,
-            nickname: None,
+    pub fn default_instance() -> &'static SetLastReadT {
+        static instance: SetLastReadT = SetLastReadT {
+            message_id: None,
         };
         &'static instance
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.server {
-            Some(ref v) => {
-                os.write_string(1, *v);
-            },
-            None => {},
-        };
-        match self.nickname {
+        match self.message_id {
             Some(ref v) => {
-                os.write_string(2, *v);
+                os.write_uint64(1, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_server(&mut self) {
-        self.server = None;
-    }
-
-    pub fn has_server(&self) -> bool {
-        self.server.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_server(&mut self, v: ~str) {
-        self.server = Some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_server(&'a mut self) -> &'a mut ~str {
-        if self.server.is_none() {
-            self.server = Some(~"");
-        };
-        self.server.get_mut_ref()
-    }
-
-    pub fn get_server(&'a self) -> &'a str {
-        match self.server {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
-    }
-
-    pub fn clear_nickname(&mut self) {
-        self.nickname = None;
+    pub fn clear_message_id(&mut self) {
+        self.message_id = None;
     }
 
-    pub fn has_nickname(&self) -> bool {
-        self.nickname.is_some()
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_nickname(&mut self, v: ~str) {
-        self.nickname = Some(v);
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
-        if self.nickname.is_none() {
-            self.nickname = Some(~"");
+    pub fn mut_message_id(&'a mut self) -> &'a mut u64 {
+        if self.message_id.is_none() {
+            self.message_id = Some(0);
         };
-        self.nickname.get_mut_ref()
+        self.message_id.get_mut_ref()
     }
 
-    pub fn get_nickname(&'a self) -> &'a str {
-        match self.nickname {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
     }
 }
 
-impl Message for SetNetworkConfigurationT {
-    fn new() -> SetNetworkConfigurationT {
-        SetNetworkConfigurationT::new()
+impl Message for SetLastReadT {
+    fn new() -> SetLastReadT {
+        SetLastReadT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_server();
-        self.clear_nickname();
+        self.clear_message_id();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.server.is_none() {
-            return false;
-        };
-        if self.nickname.is_none() {
+        if self.message_id.is_none() {
             return false;
         };
         true
@@ -1180,14 +1388,9 @@ impl Message for SetNetworkConfigurationT {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
                 1 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.server = Some(tmp);
-                },
-                2 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.nickname = Some(tmp);
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.message_id = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -1202,11 +1405,8 @@ impl Message for SetNetworkConfigurationT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.server.iter() {
-            my_size += rt::string_size(1, *value);
-        };
-        for value in self.nickname.iter() {
-            my_size += rt::string_size(2, *value);
+        for value in self.message_id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -1224,108 +1424,143 @@ impl Message for SetNetworkConfigurationT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct GetMessageRangeT {
-    count: Option<u32>,
-    before_id: Option<u64>,
+pub struct HelloT {
+    protocol_version: Option<u32>,
+    client_version: Option<~str>,
+    features: ~[~str],
 }
 
-impl<'a> GetMessageRangeT {
-    pub fn new() -> GetMessageRangeT {
-        GetMessageRangeT {
-            count: None,
-            before_id: None,
+impl<'a> HelloT {
+    pub fn new() -> HelloT {
+        HelloT {
+            protocol_version: None,
+            client_version: None,
+            features: ~[],
         }
     }
 
-    pub fn default_instance() -> &'static GetMessageRangeT {
-        static instance: GetMessageRangeT = GetMessageRangeT {
-            count: None,
-            before_id: None,
-        };
-        &'static instance
+    pub fn default_instance() -> &'static HelloT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: HelloT = HelloT {
+//             protocol_version: None,
+//             client_version: None,
+//             features: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.count {
+        match self.protocol_version {
             Some(ref v) => {
                 os.write_uint32(1, *v);
             },
             None => {},
         };
-        match self.before_id {
+        match self.client_version {
             Some(ref v) => {
-                os.write_uint64(2, *v);
+                os.write_string(2, *v);
             },
             None => {},
         };
+        for v in self.features.iter() {
+            os.write_string(3, *v);
+        };
     }
 
-    pub fn clear_count(&mut self) {
-        self.count = None;
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = None;
     }
 
-    pub fn has_count(&self) -> bool {
-        self.count.is_some()
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_count(&mut self, v: u32) {
-        self.count = Some(v);
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_count(&'a mut self) -> &'a mut u32 {
-        if self.count.is_none() {
-            self.count = Some(0);
+    pub fn mut_protocol_version(&'a mut self) -> &'a mut u32 {
+        if self.protocol_version.is_none() {
+            self.protocol_version = Some(0);
         };
-        self.count.get_mut_ref()
+        self.protocol_version.get_mut_ref()
     }
 
-    pub fn get_count(&self) -> u32 {
-        self.count.unwrap_or(0)
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
     }
 
-    pub fn clear_before_id(&mut self) {
-        self.before_id = None;
+    pub fn clear_client_version(&mut self) {
+        self.client_version = None;
     }
 
-    pub fn has_before_id(&self) -> bool {
-        self.before_id.is_some()
+    pub fn has_client_version(&self) -> bool {
+        self.client_version.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_before_id(&mut self, v: u64) {
-        self.before_id = Some(v);
+    pub fn set_client_version(&mut self, v: ~str) {
+        self.client_version = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_before_id(&'a mut self) -> &'a mut u64 {
-        if self.before_id.is_none() {
-            self.before_id = Some(0);
+    pub fn mut_client_version(&'a mut self) -> &'a mut ~str {
+        if self.client_version.is_none() {
+            self.client_version = Some(~"");
         };
-        self.before_id.get_mut_ref()
+        self.client_version.get_mut_ref()
     }
 
-    pub fn get_before_id(&self) -> u64 {
-        self.before_id.unwrap_or(0)
+    pub fn get_client_version(&'a self) -> &'a str {
+        match self.client_version {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_features(&mut self) {
+        self.features.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_features(&mut self, v: ~[~str]) {
+        self.features = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_features(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.features
+    }
+
+    pub fn get_features(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.features)
+    }
+
+    pub fn add_features(&mut self, v: ~str) {
+        self.features.push(v);
     }
 }
 
-impl Message for GetMessageRangeT {
-    fn new() -> GetMessageRangeT {
-        GetMessageRangeT::new()
+impl Message for HelloT {
+    fn new() -> HelloT {
+        HelloT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_count();
-        self.clear_before_id();
+        self.clear_protocol_version();
+        self.clear_client_version();
+        self.clear_features();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.count.is_none() {
+        if self.protocol_version.is_none() {
             return false;
         };
         true
@@ -1338,12 +1573,17 @@ impl Message for GetMessageRangeT {
                 1 => {
                     assert_eq!(wire_format::WireTypeVarint, wire_type);
                     let tmp = is.read_uint32();
-                    self.count = Some(tmp);
+                    self.protocol_version = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.before_id = Some(tmp);
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.client_version = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.features.push(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -1358,11 +1598,14 @@ impl Message for GetMessageRangeT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.count.iter() {
+        for value in self.protocol_version.iter() {
             my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
         };
-        for value in self.before_id.iter() {
-            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
+        for value in self.client_version.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.features.iter() {
+            my_size += rt::string_size(3, *value);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -1380,644 +1623,6701 @@ impl Message for GetMessageRangeT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct RemoteMessage {
-    packet_type: Option<RemoteMessage_Type>,
-    network_id: Option<u64>,
-    buffer_id: Option<u64>,
-    message_id: Option<u64>,
-    message_time: Option<u64>,
-    tag: Option<u64>,
-    network_list: ~[NetworkListT],
-    disconnected: Option<DisconnectedT>,
-    buffer_list: ~[BufferListT],
-    new_buffer: Option<NewBufferT>,
-    information: Option<InformationT>,
-    error: Option<ErrorT>,
-    join: Option<JoinT>,
-    privmsg: Option<PrivmsgT>,
-    network_configuration: Option<NetworkConfigurationT>,
-    message_range: ~[MessageRangeT],
+pub struct LoginT {
+    name: Option<~str>,
+    password: Option<~str>,
 }
 
-impl<'a> RemoteMessage {
-    pub fn new() -> RemoteMessage {
-        RemoteMessage {
-            packet_type: None,
-            network_id: None,
-            buffer_id: None,
-            message_id: None,
-            message_time: None,
-            tag: None,
-            network_list: ~[],
-            disconnected: None,
-            buffer_list: ~[],
-            new_buffer: None,
-            information: None,
-            error: None,
-            join: None,
-            privmsg: None,
-            network_configuration: None,
-            message_range: ~[],
+impl<'a> LoginT {
+    pub fn new() -> LoginT {
+        LoginT {
+            name: None,
+            password: None,
         }
     }
 
-    pub fn default_instance() -> &'static RemoteMessage {
-//         // doesn't work, because rust doen't implement static constants of types like ~str
-//         // https://github.com/mozilla/rust/issues/8406
-//         static instance: RemoteMessage = RemoteMessage {
-//             packet_type: None,
-//             network_id: None,
-//             buffer_id: None,
-//             message_id: None,
-//             message_time: None,
-//             tag: None,
-//             network_list: ~[],
-//             disconnected: None,
-//             buffer_list: ~[],
-//             new_buffer: None,
-//             information: None,
-//             error: None,
-//             join: None,
-//             privmsg: None,
-//             network_configuration: None,
-//             message_range: ~[],
-//         };
-//         &'static instance
-        fail!("TODO");
+    pub fn default_instance() -> &'static LoginT {
+        static instance: LoginT = LoginT {
+            name: None,
+            password: None,
+        };
+        &'static instance
     }
 
+    #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.packet_type {
+        match self.name {
             Some(ref v) => {
-                os.write_enum(1, *v as i32);
+                os.write_string(1, *v);
             },
             None => {},
         };
-        match self.network_id {
+        match self.password {
             Some(ref v) => {
-                os.write_uint64(2, *v);
+                os.write_string(2, *v);
             },
             None => {},
         };
-        match self.buffer_id {
-            Some(ref v) => {
-                os.write_uint64(3, *v);
-            },
-            None => {},
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ~str) {
+        self.name = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&'a mut self) -> &'a mut ~str {
+        if self.name.is_none() {
+            self.name = Some(~"");
         };
-        match self.message_id {
-            Some(ref v) => {
-                os.write_uint64(4, *v);
-            },
-            None => {},
+        self.name.get_mut_ref()
+    }
+
+    pub fn get_name(&'a self) -> &'a str {
+        match self.name {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password(&mut self) {
+        self.password = None;
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ~str) {
+        self.password = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&'a mut self) -> &'a mut ~str {
+        if self.password.is_none() {
+            self.password = Some(~"");
         };
-        match self.message_time {
-            Some(ref v) => {
-                os.write_uint64(5, *v);
-            },
-            None => {},
+        self.password.get_mut_ref()
+    }
+
+    pub fn get_password(&'a self) -> &'a str {
+        match self.password {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for LoginT {
+    fn new() -> LoginT {
+        LoginT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_password();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
         };
-        match self.tag {
-            Some(ref v) => {
-                os.write_uint64(6, *v);
-            },
-            None => {},
+        if self.password.is_none() {
+            return false;
         };
-        for v in self.network_list.iter() {
-            os.write_tag(7, wire_format::WireTypeLengthDelimited);
-            os.write_raw_varint32(sizes[*sizes_pos]);
-            *sizes_pos += 1;
-            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.name = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.password = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += rt::string_size(1, *value);
         };
-        match self.disconnected {
-            Some(ref v) => {
-                os.write_tag(8, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
-            },
-            None => {},
+        for value in self.password.iter() {
+            my_size += rt::string_size(2, *value);
         };
-        for v in self.buffer_list.iter() {
-            os.write_tag(9, wire_format::WireTypeLengthDelimited);
-            os.write_raw_varint32(sizes[*sizes_pos]);
-            *sizes_pos += 1;
-            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct AttachSessionT {
+    session_id: Option<u64>,
+}
+
+impl<'a> AttachSessionT {
+    pub fn new() -> AttachSessionT {
+        AttachSessionT {
+            session_id: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static AttachSessionT {
+        static instance: AttachSessionT = AttachSessionT {
+            session_id: None,
         };
-        match self.new_buffer {
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.session_id {
             Some(ref v) => {
-                os.write_tag(10, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                os.write_uint64(1, *v);
             },
             None => {},
         };
-        match self.information {
-            Some(ref v) => {
-                os.write_tag(11, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
-            },
-            None => {},
+    }
+
+    pub fn clear_session_id(&mut self) {
+        self.session_id = None;
+    }
+
+    pub fn has_session_id(&self) -> bool {
+        self.session_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: u64) {
+        self.session_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&'a mut self) -> &'a mut u64 {
+        if self.session_id.is_none() {
+            self.session_id = Some(0);
+        };
+        self.session_id.get_mut_ref()
+    }
+
+    pub fn get_session_id(&self) -> u64 {
+        self.session_id.unwrap_or(0)
+    }
+}
+
+impl Message for AttachSessionT {
+    fn new() -> AttachSessionT {
+        AttachSessionT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_session_id();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.session_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.session_id = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.session_id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct RegisterT {
+    nickname: Option<~str>,
+}
+
+impl<'a> RegisterT {
+    pub fn new() -> RegisterT {
+        RegisterT {
+            nickname: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static RegisterT {
+        static instance: RegisterT = RegisterT {
+            nickname: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.nickname {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_nickname(&mut self) {
+        self.nickname = None;
+    }
+
+    pub fn has_nickname(&self) -> bool {
+        self.nickname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nickname(&mut self, v: ~str) {
+        self.nickname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
+        if self.nickname.is_none() {
+            self.nickname = Some(~"");
+        };
+        self.nickname.get_mut_ref()
+    }
+
+    pub fn get_nickname(&'a self) -> &'a str {
+        match self.nickname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for RegisterT {
+    fn new() -> RegisterT {
+        RegisterT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_nickname();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.nickname.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.nickname = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.nickname.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct JoinChannelT {
+    channel: Option<~str>,
+    key: Option<~str>,
+}
+
+impl<'a> JoinChannelT {
+    pub fn new() -> JoinChannelT {
+        JoinChannelT {
+            channel: None,
+            key: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static JoinChannelT {
+        static instance: JoinChannelT = JoinChannelT {
+            channel: None,
+            key: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.channel {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.key {
+            Some(ref v) => {
+                os.write_string(3, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_channel(&mut self) {
+        self.channel = None;
+    }
+
+    pub fn has_channel(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ~str) {
+        self.channel = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&'a mut self) -> &'a mut ~str {
+        if self.channel.is_none() {
+            self.channel = Some(~"");
+        };
+        self.channel.get_mut_ref()
+    }
+
+    pub fn get_channel(&'a self) -> &'a str {
+        match self.channel {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_key(&mut self) {
+        self.key = None;
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ~str) {
+        self.key = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&'a mut self) -> &'a mut ~str {
+        if self.key.is_none() {
+            self.key = Some(~"");
+        };
+        self.key.get_mut_ref()
+    }
+
+    pub fn get_key(&'a self) -> &'a str {
+        match self.key {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for JoinChannelT {
+    fn new() -> JoinChannelT {
+        JoinChannelT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_channel();
+        self.clear_key();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.channel.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.channel = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.key = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.channel.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.key.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct SetAwayT {
+    message: Option<~str>,
+}
+
+impl<'a> SetAwayT {
+    pub fn new() -> SetAwayT {
+        SetAwayT {
+            message: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static SetAwayT {
+        static instance: SetAwayT = SetAwayT {
+            message: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.message {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_message(&mut self) {
+        self.message = None;
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ~str) {
+        self.message = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&'a mut self) -> &'a mut ~str {
+        if self.message.is_none() {
+            self.message = Some(~"");
+        };
+        self.message.get_mut_ref()
+    }
+
+    pub fn get_message(&'a self) -> &'a str {
+        match self.message {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for SetAwayT {
+    fn new() -> SetAwayT {
+        SetAwayT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_message();
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.message = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.message.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct PartChannelT {
+    channel: Option<~str>,
+    message: Option<~str>,
+}
+
+impl<'a> PartChannelT {
+    pub fn new() -> PartChannelT {
+        PartChannelT {
+            channel: None,
+            message: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static PartChannelT {
+        static instance: PartChannelT = PartChannelT {
+            channel: None,
+            message: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.channel {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.message {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_channel(&mut self) {
+        self.channel = None;
+    }
+
+    pub fn has_channel(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ~str) {
+        self.channel = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&'a mut self) -> &'a mut ~str {
+        if self.channel.is_none() {
+            self.channel = Some(~"");
+        };
+        self.channel.get_mut_ref()
+    }
+
+    pub fn get_channel(&'a self) -> &'a str {
+        match self.channel {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_message(&mut self) {
+        self.message = None;
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ~str) {
+        self.message = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&'a mut self) -> &'a mut ~str {
+        if self.message.is_none() {
+            self.message = Some(~"");
+        };
+        self.message.get_mut_ref()
+    }
+
+    pub fn get_message(&'a self) -> &'a str {
+        match self.message {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for PartChannelT {
+    fn new() -> PartChannelT {
+        PartChannelT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_channel();
+        self.clear_message();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.channel.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.channel = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.message = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.channel.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.message.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct SendPrivmsgT {
+    target: Option<~str>,
+    msg: Option<~str>,
+}
+
+impl<'a> SendPrivmsgT {
+    pub fn new() -> SendPrivmsgT {
+        SendPrivmsgT {
+            target: None,
+            msg: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static SendPrivmsgT {
+        static instance: SendPrivmsgT = SendPrivmsgT {
+            target: None,
+            msg: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.target {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.msg {
+            Some(ref v) => {
+                os.write_string(3, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_target(&mut self) {
+        self.target = None;
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ~str) {
+        self.target = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&'a mut self) -> &'a mut ~str {
+        if self.target.is_none() {
+            self.target = Some(~"");
+        };
+        self.target.get_mut_ref()
+    }
+
+    pub fn get_target(&'a self) -> &'a str {
+        match self.target {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_msg(&mut self) {
+        self.msg = None;
+    }
+
+    pub fn has_msg(&self) -> bool {
+        self.msg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg(&mut self, v: ~str) {
+        self.msg = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
+        if self.msg.is_none() {
+            self.msg = Some(~"");
+        };
+        self.msg.get_mut_ref()
+    }
+
+    pub fn get_msg(&'a self) -> &'a str {
+        match self.msg {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for SendPrivmsgT {
+    fn new() -> SendPrivmsgT {
+        SendPrivmsgT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_target();
+        self.clear_msg();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.target.is_none() {
+            return false;
+        };
+        if self.msg.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.target = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.msg = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.target.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.msg.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct WebIrcT {
+    password: Option<~str>,
+    gateway: Option<~str>,
+    password_set: Option<bool>,
+}
+
+impl<'a> WebIrcT {
+    pub fn new() -> WebIrcT {
+        WebIrcT {
+            password: None,
+            gateway: None,
+            password_set: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static WebIrcT {
+        static instance: WebIrcT = WebIrcT {
+            password: None,
+            gateway: None,
+            password_set: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.password {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.gateway {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.password_set {
+            Some(ref v) => {
+                os.write_bool(3, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_password(&mut self) {
+        self.password = None;
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ~str) {
+        self.password = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&'a mut self) -> &'a mut ~str {
+        if self.password.is_none() {
+            self.password = Some(~"");
+        };
+        self.password.get_mut_ref()
+    }
+
+    pub fn get_password(&'a self) -> &'a str {
+        match self.password {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_gateway(&mut self) {
+        self.gateway = None;
+    }
+
+    pub fn has_gateway(&self) -> bool {
+        self.gateway.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gateway(&mut self, v: ~str) {
+        self.gateway = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gateway(&'a mut self) -> &'a mut ~str {
+        if self.gateway.is_none() {
+            self.gateway = Some(~"");
+        };
+        self.gateway.get_mut_ref()
+    }
+
+    pub fn get_gateway(&'a self) -> &'a str {
+        match self.gateway {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password_set(&mut self) {
+        self.password_set = None;
+    }
+
+    pub fn has_password_set(&self) -> bool {
+        self.password_set.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_set(&mut self, v: bool) {
+        self.password_set = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password_set(&'a mut self) -> &'a mut bool {
+        if self.password_set.is_none() {
+            self.password_set = Some(false);
+        };
+        self.password_set.get_mut_ref()
+    }
+
+    pub fn get_password_set(&self) -> bool {
+        self.password_set.unwrap_or(false)
+    }
+}
+
+impl Message for WebIrcT {
+    fn new() -> WebIrcT {
+        WebIrcT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_password();
+        self.clear_gateway();
+        self.clear_password_set();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.gateway.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.password = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.gateway = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_bool();
+                    self.password_set = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.password.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.gateway.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.password_set.iter() {
+            my_size += 2;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct ClientAddressT {
+    hostname: Option<~str>,
+    ip: Option<~str>,
+}
+
+impl<'a> ClientAddressT {
+    pub fn new() -> ClientAddressT {
+        ClientAddressT {
+            hostname: None,
+            ip: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static ClientAddressT {
+        static instance: ClientAddressT = ClientAddressT {
+            hostname: None,
+            ip: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.hostname {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.ip {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_hostname(&mut self) {
+        self.hostname = None;
+    }
+
+    pub fn has_hostname(&self) -> bool {
+        self.hostname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ~str) {
+        self.hostname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&'a mut self) -> &'a mut ~str {
+        if self.hostname.is_none() {
+            self.hostname = Some(~"");
+        };
+        self.hostname.get_mut_ref()
+    }
+
+    pub fn get_hostname(&'a self) -> &'a str {
+        match self.hostname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_ip(&mut self) {
+        self.ip = None;
+    }
+
+    pub fn has_ip(&self) -> bool {
+        self.ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ip(&mut self, v: ~str) {
+        self.ip = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ip(&'a mut self) -> &'a mut ~str {
+        if self.ip.is_none() {
+            self.ip = Some(~"");
+        };
+        self.ip.get_mut_ref()
+    }
+
+    pub fn get_ip(&'a self) -> &'a str {
+        match self.ip {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for ClientAddressT {
+    fn new() -> ClientAddressT {
+        ClientAddressT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_hostname();
+        self.clear_ip();
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.hostname = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.ip = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.hostname.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.ip.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct ProxyT {
+    proxy_type: Option<ProxyT_Type>,
+    host: Option<~str>,
+    port: Option<u32>,
+    username: Option<~str>,
+    password: Option<~str>,
+    password_set: Option<bool>,
+}
+
+impl<'a> ProxyT {
+    pub fn new() -> ProxyT {
+        ProxyT {
+            proxy_type: None,
+            host: None,
+            port: None,
+            username: None,
+            password: None,
+            password_set: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static ProxyT {
+        static instance: ProxyT = ProxyT {
+            proxy_type: None,
+            host: None,
+            port: None,
+            username: None,
+            password: None,
+            password_set: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.proxy_type {
+            Some(ref v) => {
+                os.write_enum(1, *v as i32);
+            },
+            None => {},
+        };
+        match self.host {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.port {
+            Some(ref v) => {
+                os.write_uint32(3, *v);
+            },
+            None => {},
+        };
+        match self.username {
+            Some(ref v) => {
+                os.write_string(4, *v);
+            },
+            None => {},
+        };
+        match self.password {
+            Some(ref v) => {
+                os.write_string(5, *v);
+            },
+            None => {},
+        };
+        match self.password_set {
+            Some(ref v) => {
+                os.write_bool(6, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_proxy_type(&mut self) {
+        self.proxy_type = None;
+    }
+
+    pub fn has_proxy_type(&self) -> bool {
+        self.proxy_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proxy_type(&mut self, v: ProxyT_Type) {
+        self.proxy_type = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proxy_type(&'a mut self) -> &'a mut ProxyT_Type {
+        if self.proxy_type.is_none() {
+            self.proxy_type = Some(ProxyT_Type::new(0));
+        };
+        self.proxy_type.get_mut_ref()
+    }
+
+    pub fn get_proxy_type(&self) -> ProxyT_Type {
+        self.proxy_type.unwrap_or(ProxyT_Type::new(0))
+    }
+
+    pub fn clear_host(&mut self) {
+        self.host = None;
+    }
+
+    pub fn has_host(&self) -> bool {
+        self.host.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host(&mut self, v: ~str) {
+        self.host = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host(&'a mut self) -> &'a mut ~str {
+        if self.host.is_none() {
+            self.host = Some(~"");
+        };
+        self.host.get_mut_ref()
+    }
+
+    pub fn get_host(&'a self) -> &'a str {
+        match self.host {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_port(&mut self) {
+        self.port = None;
+    }
+
+    pub fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: u32) {
+        self.port = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_port(&'a mut self) -> &'a mut u32 {
+        if self.port.is_none() {
+            self.port = Some(0);
+        };
+        self.port.get_mut_ref()
+    }
+
+    pub fn get_port(&self) -> u32 {
+        self.port.unwrap_or(0)
+    }
+
+    pub fn clear_username(&mut self) {
+        self.username = None;
+    }
+
+    pub fn has_username(&self) -> bool {
+        self.username.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_username(&mut self, v: ~str) {
+        self.username = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_username(&'a mut self) -> &'a mut ~str {
+        if self.username.is_none() {
+            self.username = Some(~"");
+        };
+        self.username.get_mut_ref()
+    }
+
+    pub fn get_username(&'a self) -> &'a str {
+        match self.username {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password(&mut self) {
+        self.password = None;
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ~str) {
+        self.password = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&'a mut self) -> &'a mut ~str {
+        if self.password.is_none() {
+            self.password = Some(~"");
+        };
+        self.password.get_mut_ref()
+    }
+
+    pub fn get_password(&'a self) -> &'a str {
+        match self.password {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password_set(&mut self) {
+        self.password_set = None;
+    }
+
+    pub fn has_password_set(&self) -> bool {
+        self.password_set.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_set(&mut self, v: bool) {
+        self.password_set = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password_set(&'a mut self) -> &'a mut bool {
+        if self.password_set.is_none() {
+            self.password_set = Some(false);
+        };
+        self.password_set.get_mut_ref()
+    }
+
+    pub fn get_password_set(&self) -> bool {
+        self.password_set.unwrap_or(false)
+    }
+}
+
+impl Message for ProxyT {
+    fn new() -> ProxyT {
+        ProxyT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_proxy_type();
+        self.clear_host();
+        self.clear_port();
+        self.clear_username();
+        self.clear_password();
+        self.clear_password_set();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.proxy_type.is_none() {
+            return false;
+        };
+        if self.host.is_none() {
+            return false;
+        };
+        if self.port.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = ProxyT_Type::new(is.read_int32());
+                    self.proxy_type = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.host = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint32();
+                    self.port = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.username = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.password = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_bool();
+                    self.password_set = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.proxy_type.iter() {
+            my_size += rt::enum_size(1, *value);
+        };
+        for value in self.host.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.port.iter() {
+            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.username.iter() {
+            my_size += rt::string_size(4, *value);
+        };
+        for value in self.password.iter() {
+            my_size += rt::string_size(5, *value);
+        };
+        for value in self.password_set.iter() {
+            my_size += 2;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub enum ProxyT_Type {
+    Socks5 = 1,
+    HttpConnect = 2,
+}
+
+impl ProxyT_Type {
+    pub fn new(value: i32) -> ProxyT_Type {
+        match value {
+            1 => Socks5,
+            2 => HttpConnect,
+            _ => fail!()
+        }
+    }
+}
+
+impl ProtobufEnum for ProxyT_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct SetNetworkConfigurationT {
+    server: Option<~str>,
+    nickname: Option<~str>,
+    service_auth: Option<ServiceAuthT>,
+    identity_id: Option<u64>,
+    proxy: Option<ProxyT>,
+    bind_address: Option<~str>,
+    webirc: Option<WebIrcT>,
+}
+
+impl<'a> SetNetworkConfigurationT {
+    pub fn new() -> SetNetworkConfigurationT {
+        SetNetworkConfigurationT {
+            server: None,
+            nickname: None,
+            service_auth: None,
+            identity_id: None,
+            proxy: None,
+            bind_address: None,
+            webirc: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static SetNetworkConfigurationT {
+        static instance: SetNetworkConfigurationT = SetNetworkConfigurationT {
+            server: None,
+            nickname: None,
+            service_auth: None,
+            identity_id: None,
+            proxy: None,
+            bind_address: None,
+            webirc: None,
+        };
+        &'static instance
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.server {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.nickname {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.service_auth {
+            Some(ref v) => {
+                os.write_tag(3, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.identity_id {
+            Some(ref v) => {
+                os.write_uint64(4, *v);
+            },
+            None => {},
+        };
+        match self.proxy {
+            Some(ref v) => {
+                os.write_tag(5, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.bind_address {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
+        match self.webirc {
+            Some(ref v) => {
+                os.write_tag(7, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_server(&mut self) {
+        self.server = None;
+    }
+
+    pub fn has_server(&self) -> bool {
+        self.server.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server(&mut self, v: ~str) {
+        self.server = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_server(&'a mut self) -> &'a mut ~str {
+        if self.server.is_none() {
+            self.server = Some(~"");
+        };
+        self.server.get_mut_ref()
+    }
+
+    pub fn get_server(&'a self) -> &'a str {
+        match self.server {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_nickname(&mut self) {
+        self.nickname = None;
+    }
+
+    pub fn has_nickname(&self) -> bool {
+        self.nickname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nickname(&mut self, v: ~str) {
+        self.nickname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
+        if self.nickname.is_none() {
+            self.nickname = Some(~"");
+        };
+        self.nickname.get_mut_ref()
+    }
+
+    pub fn get_nickname(&'a self) -> &'a str {
+        match self.nickname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_service_auth(&mut self) {
+        self.service_auth = None;
+    }
+
+    pub fn has_service_auth(&self) -> bool {
+        self.service_auth.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_auth(&mut self, v: ServiceAuthT) {
+        self.service_auth = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_auth(&'a mut self) -> &'a mut ServiceAuthT {
+        if self.service_auth.is_none() {
+            self.service_auth = Some(ServiceAuthT::new());
+        };
+        self.service_auth.get_mut_ref()
+    }
+
+    pub fn get_service_auth(&'a self) -> &'a ServiceAuthT {
+        match self.service_auth {
+            Some(ref v) => v,
+            None => ServiceAuthT::default_instance(),
+        }
+    }
+
+    pub fn clear_identity_id(&mut self) {
+        self.identity_id = None;
+    }
+
+    pub fn has_identity_id(&self) -> bool {
+        self.identity_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identity_id(&mut self, v: u64) {
+        self.identity_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identity_id(&'a mut self) -> &'a mut u64 {
+        if self.identity_id.is_none() {
+            self.identity_id = Some(0);
+        };
+        self.identity_id.get_mut_ref()
+    }
+
+    pub fn get_identity_id(&self) -> u64 {
+        self.identity_id.unwrap_or(0)
+    }
+
+    pub fn clear_proxy(&mut self) {
+        self.proxy = None;
+    }
+
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proxy(&mut self, v: ProxyT) {
+        self.proxy = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proxy(&'a mut self) -> &'a mut ProxyT {
+        if self.proxy.is_none() {
+            self.proxy = Some(ProxyT::new());
+        };
+        self.proxy.get_mut_ref()
+    }
+
+    pub fn get_proxy(&'a self) -> &'a ProxyT {
+        match self.proxy {
+            Some(ref v) => v,
+            None => ProxyT::default_instance(),
+        }
+    }
+
+    pub fn clear_bind_address(&mut self) {
+        self.bind_address = None;
+    }
+
+    pub fn has_bind_address(&self) -> bool {
+        self.bind_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bind_address(&mut self, v: ~str) {
+        self.bind_address = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bind_address(&'a mut self) -> &'a mut ~str {
+        if self.bind_address.is_none() {
+            self.bind_address = Some(~"");
+        };
+        self.bind_address.get_mut_ref()
+    }
+
+    pub fn get_bind_address(&'a self) -> &'a str {
+        match self.bind_address {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_webirc(&mut self) {
+        self.webirc = None;
+    }
+
+    pub fn has_webirc(&self) -> bool {
+        self.webirc.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webirc(&mut self, v: WebIrcT) {
+        self.webirc = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_webirc(&'a mut self) -> &'a mut WebIrcT {
+        if self.webirc.is_none() {
+            self.webirc = Some(WebIrcT::new());
+        };
+        self.webirc.get_mut_ref()
+    }
+
+    pub fn get_webirc(&'a self) -> &'a WebIrcT {
+        match self.webirc {
+            Some(ref v) => v,
+            None => WebIrcT::default_instance(),
+        }
+    }
+}
+
+impl Message for SetNetworkConfigurationT {
+    fn new() -> SetNetworkConfigurationT {
+        SetNetworkConfigurationT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_server();
+        self.clear_nickname();
+        self.clear_service_auth();
+        self.clear_identity_id();
+        self.clear_proxy();
+        self.clear_bind_address();
+        self.clear_webirc();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.server.is_none() {
+            return false;
+        };
+        if self.nickname.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.server = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.nickname = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ServiceAuthT::new();
+                    is.merge_message(&mut tmp);
+                    self.service_auth = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.identity_id = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ProxyT::new();
+                    is.merge_message(&mut tmp);
+                    self.proxy = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.bind_address = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = WebIrcT::new();
+                    is.merge_message(&mut tmp);
+                    self.webirc = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.server.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.nickname.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.service_auth.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.identity_id.iter() {
+            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.proxy.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.bind_address.iter() {
+            my_size += rt::string_size(6, *value);
+        };
+        for value in self.webirc.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct ServiceAuthT {
+    service: Option<~str>,
+    command: Option<~str>,
+    password: Option<~str>,
+    password_set: Option<bool>,
+}
+
+impl<'a> ServiceAuthT {
+    pub fn new() -> ServiceAuthT {
+        ServiceAuthT {
+            service: None,
+            command: None,
+            password: None,
+            password_set: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static ServiceAuthT {
+        static instance: ServiceAuthT = ServiceAuthT {
+            service: None,
+            command: None,
+            password: None,
+            password_set: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.service {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.command {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.password {
+            Some(ref v) => {
+                os.write_string(3, *v);
+            },
+            None => {},
+        };
+        match self.password_set {
+            Some(ref v) => {
+                os.write_bool(4, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_service(&mut self) {
+        self.service = None;
+    }
+
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ~str) {
+        self.service = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&'a mut self) -> &'a mut ~str {
+        if self.service.is_none() {
+            self.service = Some(~"");
+        };
+        self.service.get_mut_ref()
+    }
+
+    pub fn get_service(&'a self) -> &'a str {
+        match self.service {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_command(&mut self) {
+        self.command = None;
+    }
+
+    pub fn has_command(&self) -> bool {
+        self.command.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command(&mut self, v: ~str) {
+        self.command = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_command(&'a mut self) -> &'a mut ~str {
+        if self.command.is_none() {
+            self.command = Some(~"");
+        };
+        self.command.get_mut_ref()
+    }
+
+    pub fn get_command(&'a self) -> &'a str {
+        match self.command {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password(&mut self) {
+        self.password = None;
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ~str) {
+        self.password = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&'a mut self) -> &'a mut ~str {
+        if self.password.is_none() {
+            self.password = Some(~"");
+        };
+        self.password.get_mut_ref()
+    }
+
+    pub fn get_password(&'a self) -> &'a str {
+        match self.password {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_password_set(&mut self) {
+        self.password_set = None;
+    }
+
+    pub fn has_password_set(&self) -> bool {
+        self.password_set.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_set(&mut self, v: bool) {
+        self.password_set = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password_set(&'a mut self) -> &'a mut bool {
+        if self.password_set.is_none() {
+            self.password_set = Some(false);
+        };
+        self.password_set.get_mut_ref()
+    }
+
+    pub fn get_password_set(&self) -> bool {
+        self.password_set.unwrap_or(false)
+    }
+}
+
+impl Message for ServiceAuthT {
+    fn new() -> ServiceAuthT {
+        ServiceAuthT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_service();
+        self.clear_command();
+        self.clear_password();
+        self.clear_password_set();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.service.is_none() {
+            return false;
+        };
+        if self.command.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.service = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.command = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.password = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_bool();
+                    self.password_set = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.service.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.command.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.password.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        for value in self.password_set.iter() {
+            my_size += 2;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct GetMessageRangeT {
+    count: Option<u32>,
+    before_id: Option<u64>,
+}
+
+impl<'a> GetMessageRangeT {
+    pub fn new() -> GetMessageRangeT {
+        GetMessageRangeT {
+            count: None,
+            before_id: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static GetMessageRangeT {
+        static instance: GetMessageRangeT = GetMessageRangeT {
+            count: None,
+            before_id: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.count {
+            Some(ref v) => {
+                os.write_uint32(1, *v);
+            },
+            None => {},
+        };
+        match self.before_id {
+            Some(ref v) => {
+                os.write_uint64(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_count(&mut self) {
+        self.count = None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_count(&'a mut self) -> &'a mut u32 {
+        if self.count.is_none() {
+            self.count = Some(0);
+        };
+        self.count.get_mut_ref()
+    }
+
+    pub fn get_count(&self) -> u32 {
+        self.count.unwrap_or(0)
+    }
+
+    pub fn clear_before_id(&mut self) {
+        self.before_id = None;
+    }
+
+    pub fn has_before_id(&self) -> bool {
+        self.before_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_before_id(&mut self, v: u64) {
+        self.before_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_before_id(&'a mut self) -> &'a mut u64 {
+        if self.before_id.is_none() {
+            self.before_id = Some(0);
+        };
+        self.before_id.get_mut_ref()
+    }
+
+    pub fn get_before_id(&self) -> u64 {
+        self.before_id.unwrap_or(0)
+    }
+}
+
+impl Message for GetMessageRangeT {
+    fn new() -> GetMessageRangeT {
+        GetMessageRangeT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_count();
+        self.clear_before_id();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.count.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint32();
+                    self.count = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.before_id = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.count.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.before_id.iter() {
+            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct RemoteMessage {
+    packet_type: Option<RemoteMessage_Type>,
+    network_id: Option<u64>,
+    buffer_id: Option<u64>,
+    message_id: Option<u64>,
+    message_time: Option<u64>,
+    tag: Option<u64>,
+    network_list: ~[NetworkListT],
+    disconnected: Option<DisconnectedT>,
+    buffer_list: ~[BufferListT],
+    new_buffer: Option<NewBufferT>,
+    information: Option<InformationT>,
+    error: Option<ErrorT>,
+    join: Option<JoinT>,
+    privmsg: Option<PrivmsgT>,
+    network_configuration: Option<NetworkConfigurationT>,
+    message_range: ~[MessageRangeT],
+    perform_list: Option<PerformListT>,
+    identity_list: ~[IdentityT],
+    away_state: Option<AwayStateT>,
+    message_msgid: Option<~str>,
+    message_account: Option<~str>,
+    message_prefix: Option<~str>,
+    user_update: Option<UserT>,
+    buddy_list: Option<BuddyListT>,
+    presence: Option<PresenceT>,
+    command_reply: Option<CommandReplyT>,
+    welcome: Option<WelcomeT>,
+    snapshot: Option<SnapshotT>,
+    topic: Option<TopicT>,
+    ping: Option<PingT>,
+}
+
+impl<'a> RemoteMessage {
+    pub fn new() -> RemoteMessage {
+        RemoteMessage {
+            packet_type: None,
+            network_id: None,
+            buffer_id: None,
+            message_id: None,
+            message_time: None,
+            tag: None,
+            network_list: ~[],
+            disconnected: None,
+            buffer_list: ~[],
+            new_buffer: None,
+            information: None,
+            error: None,
+            join: None,
+            privmsg: None,
+            network_configuration: None,
+            message_range: ~[],
+            perform_list: None,
+            identity_list: ~[],
+            away_state: None,
+            message_msgid: None,
+            message_account: None,
+            message_prefix: None,
+            user_update: None,
+            buddy_list: None,
+            presence: None,
+            command_reply: None,
+            welcome: None,
+            snapshot: None,
+            topic: None,
+            ping: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static RemoteMessage {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: RemoteMessage = RemoteMessage {
+//             packet_type: None,
+//             network_id: None,
+//             buffer_id: None,
+//             message_id: None,
+//             message_time: None,
+//             tag: None,
+//             network_list: ~[],
+//             disconnected: None,
+//             buffer_list: ~[],
+//             new_buffer: None,
+//             information: None,
+//             error: None,
+//             join: None,
+//             privmsg: None,
+//             network_configuration: None,
+//             message_range: ~[],
+//             perform_list: None,
+//             identity_list: ~[],
+//             away_state: None,
+//             message_msgid: None,
+//             message_account: None,
+//             message_prefix: None,
+//             user_update: None,
+//             buddy_list: None,
+//             presence: None,
+//             command_reply: None,
+//             welcome: None,
+//             snapshot: None,
+//             topic: None,
+//             ping: None,
+//         };
+//         &'static instance
+        fail!("TODO");
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.packet_type {
+            Some(ref v) => {
+                os.write_enum(1, *v as i32);
+            },
+            None => {},
+        };
+        match self.network_id {
+            Some(ref v) => {
+                os.write_uint64(2, *v);
+            },
+            None => {},
+        };
+        match self.buffer_id {
+            Some(ref v) => {
+                os.write_uint64(3, *v);
+            },
+            None => {},
+        };
+        match self.message_id {
+            Some(ref v) => {
+                os.write_uint64(4, *v);
+            },
+            None => {},
+        };
+        match self.message_time {
+            Some(ref v) => {
+                os.write_uint64(5, *v);
+            },
+            None => {},
+        };
+        match self.tag {
+            Some(ref v) => {
+                os.write_uint64(6, *v);
+            },
+            None => {},
+        };
+        for v in self.network_list.iter() {
+            os.write_tag(7, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+        match self.disconnected {
+            Some(ref v) => {
+                os.write_tag(8, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        for v in self.buffer_list.iter() {
+            os.write_tag(9, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+        match self.new_buffer {
+            Some(ref v) => {
+                os.write_tag(10, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.information {
+            Some(ref v) => {
+                os.write_tag(11, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.error {
+            Some(ref v) => {
+                os.write_tag(12, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.join {
+            Some(ref v) => {
+                os.write_tag(13, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.privmsg {
+            Some(ref v) => {
+                os.write_tag(14, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.network_configuration {
+            Some(ref v) => {
+                os.write_tag(15, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        for v in self.message_range.iter() {
+            os.write_tag(16, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+        match self.perform_list {
+            Some(ref v) => {
+                os.write_tag(17, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        for v in self.identity_list.iter() {
+            os.write_tag(18, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+        match self.away_state {
+            Some(ref v) => {
+                os.write_tag(19, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.message_msgid {
+            Some(ref v) => {
+                os.write_string(20, *v);
+            },
+            None => {},
+        };
+        match self.message_account {
+            Some(ref v) => {
+                os.write_string(21, *v);
+            },
+            None => {},
+        };
+        match self.message_prefix {
+            Some(ref v) => {
+                os.write_string(22, *v);
+            },
+            None => {},
+        };
+        match self.user_update {
+            Some(ref v) => {
+                os.write_tag(23, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.buddy_list {
+            Some(ref v) => {
+                os.write_tag(24, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.presence {
+            Some(ref v) => {
+                os.write_tag(25, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.command_reply {
+            Some(ref v) => {
+                os.write_tag(26, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.welcome {
+            Some(ref v) => {
+                os.write_tag(27, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.snapshot {
+            Some(ref v) => {
+                os.write_tag(28, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.topic {
+            Some(ref v) => {
+                os.write_tag(29, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.ping {
+            Some(ref v) => {
+                os.write_tag(30, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_packet_type(&mut self) {
+        self.packet_type = None;
+    }
+
+    pub fn has_packet_type(&self) -> bool {
+        self.packet_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet_type(&mut self, v: RemoteMessage_Type) {
+        self.packet_type = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_packet_type(&'a mut self) -> &'a mut RemoteMessage_Type {
+        if self.packet_type.is_none() {
+            self.packet_type = Some(RemoteMessage_Type::new(0));
+        };
+        self.packet_type.get_mut_ref()
+    }
+
+    pub fn get_packet_type(&self) -> RemoteMessage_Type {
+        self.packet_type.unwrap_or(RemoteMessage_Type::new(0))
+    }
+
+    pub fn clear_network_id(&mut self) {
+        self.network_id = None;
+    }
+
+    pub fn has_network_id(&self) -> bool {
+        self.network_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_network_id(&mut self, v: u64) {
+        self.network_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_network_id(&'a mut self) -> &'a mut u64 {
+        if self.network_id.is_none() {
+            self.network_id = Some(0);
+        };
+        self.network_id.get_mut_ref()
+    }
+
+    pub fn get_network_id(&self) -> u64 {
+        self.network_id.unwrap_or(0)
+    }
+
+    pub fn clear_buffer_id(&mut self) {
+        self.buffer_id = None;
+    }
+
+    pub fn has_buffer_id(&self) -> bool {
+        self.buffer_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_buffer_id(&mut self, v: u64) {
+        self.buffer_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_buffer_id(&'a mut self) -> &'a mut u64 {
+        if self.buffer_id.is_none() {
+            self.buffer_id = Some(0);
+        };
+        self.buffer_id.get_mut_ref()
+    }
+
+    pub fn get_buffer_id(&self) -> u64 {
+        self.buffer_id.unwrap_or(0)
+    }
+
+    pub fn clear_message_id(&mut self) {
+        self.message_id = None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message_id(&'a mut self) -> &'a mut u64 {
+        if self.message_id.is_none() {
+            self.message_id = Some(0);
+        };
+        self.message_id.get_mut_ref()
+    }
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+
+    pub fn clear_message_time(&mut self) {
+        self.message_time = None;
+    }
+
+    pub fn has_message_time(&self) -> bool {
+        self.message_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_time(&mut self, v: u64) {
+        self.message_time = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message_time(&'a mut self) -> &'a mut u64 {
+        if self.message_time.is_none() {
+            self.message_time = Some(0);
+        };
+        self.message_time.get_mut_ref()
+    }
+
+    pub fn get_message_time(&self) -> u64 {
+        self.message_time.unwrap_or(0)
+    }
+
+    pub fn clear_tag(&mut self) {
+        self.tag = None;
+    }
+
+    pub fn has_tag(&self) -> bool {
+        self.tag.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: u64) {
+        self.tag = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tag(&'a mut self) -> &'a mut u64 {
+        if self.tag.is_none() {
+            self.tag = Some(0);
+        };
+        self.tag.get_mut_ref()
+    }
+
+    pub fn get_tag(&self) -> u64 {
+        self.tag.unwrap_or(0)
+    }
+
+    pub fn clear_network_list(&mut self) {
+        self.network_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_network_list(&mut self, v: ~[NetworkListT]) {
+        self.network_list = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_network_list(&'a mut self) -> &'a mut ~[NetworkListT] {
+        &mut self.network_list
+    }
+
+    pub fn get_network_list(&'a self) -> &'a [NetworkListT] {
+        rt::as_slice_tmp(&self.network_list)
+    }
+
+    pub fn add_network_list(&mut self, v: NetworkListT) {
+        self.network_list.push(v);
+    }
+
+    pub fn clear_disconnected(&mut self) {
+        self.disconnected = None;
+    }
+
+    pub fn has_disconnected(&self) -> bool {
+        self.disconnected.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_disconnected(&mut self, v: DisconnectedT) {
+        self.disconnected = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_disconnected(&'a mut self) -> &'a mut DisconnectedT {
+        if self.disconnected.is_none() {
+            self.disconnected = Some(DisconnectedT::new());
+        };
+        self.disconnected.get_mut_ref()
+    }
+
+    pub fn get_disconnected(&'a self) -> &'a DisconnectedT {
+        match self.disconnected {
+            Some(ref v) => v,
+            None => DisconnectedT::default_instance(),
+        }
+    }
+
+    pub fn clear_buffer_list(&mut self) {
+        self.buffer_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_buffer_list(&mut self, v: ~[BufferListT]) {
+        self.buffer_list = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_buffer_list(&'a mut self) -> &'a mut ~[BufferListT] {
+        &mut self.buffer_list
+    }
+
+    pub fn get_buffer_list(&'a self) -> &'a [BufferListT] {
+        rt::as_slice_tmp(&self.buffer_list)
+    }
+
+    pub fn add_buffer_list(&mut self, v: BufferListT) {
+        self.buffer_list.push(v);
+    }
+
+    pub fn clear_new_buffer(&mut self) {
+        self.new_buffer = None;
+    }
+
+    pub fn has_new_buffer(&self) -> bool {
+        self.new_buffer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_buffer(&mut self, v: NewBufferT) {
+        self.new_buffer = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_buffer(&'a mut self) -> &'a mut NewBufferT {
+        if self.new_buffer.is_none() {
+            self.new_buffer = Some(NewBufferT::new());
+        };
+        self.new_buffer.get_mut_ref()
+    }
+
+    pub fn get_new_buffer(&'a self) -> &'a NewBufferT {
+        match self.new_buffer {
+            Some(ref v) => v,
+            None => NewBufferT::default_instance(),
+        }
+    }
+
+    pub fn clear_information(&mut self) {
+        self.information = None;
+    }
+
+    pub fn has_information(&self) -> bool {
+        self.information.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_information(&mut self, v: InformationT) {
+        self.information = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_information(&'a mut self) -> &'a mut InformationT {
+        if self.information.is_none() {
+            self.information = Some(InformationT::new());
+        };
+        self.information.get_mut_ref()
+    }
+
+    pub fn get_information(&'a self) -> &'a InformationT {
+        match self.information {
+            Some(ref v) => v,
+            None => InformationT::default_instance(),
+        }
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error = None;
+    }
+
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ErrorT) {
+        self.error = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&'a mut self) -> &'a mut ErrorT {
+        if self.error.is_none() {
+            self.error = Some(ErrorT::new());
+        };
+        self.error.get_mut_ref()
+    }
+
+    pub fn get_error(&'a self) -> &'a ErrorT {
+        match self.error {
+            Some(ref v) => v,
+            None => ErrorT::default_instance(),
+        }
+    }
+
+    pub fn clear_join(&mut self) {
+        self.join = None;
+    }
+
+    pub fn has_join(&self) -> bool {
+        self.join.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_join(&mut self, v: JoinT) {
+        self.join = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_join(&'a mut self) -> &'a mut JoinT {
+        if self.join.is_none() {
+            self.join = Some(JoinT::new());
+        };
+        self.join.get_mut_ref()
+    }
+
+    pub fn get_join(&'a self) -> &'a JoinT {
+        match self.join {
+            Some(ref v) => v,
+            None => JoinT::default_instance(),
+        }
+    }
+
+    pub fn clear_privmsg(&mut self) {
+        self.privmsg = None;
+    }
+
+    pub fn has_privmsg(&self) -> bool {
+        self.privmsg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_privmsg(&mut self, v: PrivmsgT) {
+        self.privmsg = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_privmsg(&'a mut self) -> &'a mut PrivmsgT {
+        if self.privmsg.is_none() {
+            self.privmsg = Some(PrivmsgT::new());
+        };
+        self.privmsg.get_mut_ref()
+    }
+
+    pub fn get_privmsg(&'a self) -> &'a PrivmsgT {
+        match self.privmsg {
+            Some(ref v) => v,
+            None => PrivmsgT::default_instance(),
+        }
+    }
+
+    pub fn clear_network_configuration(&mut self) {
+        self.network_configuration = None;
+    }
+
+    pub fn has_network_configuration(&self) -> bool {
+        self.network_configuration.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_network_configuration(&mut self, v: NetworkConfigurationT) {
+        self.network_configuration = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_network_configuration(&'a mut self) -> &'a mut NetworkConfigurationT {
+        if self.network_configuration.is_none() {
+            self.network_configuration = Some(NetworkConfigurationT::new());
+        };
+        self.network_configuration.get_mut_ref()
+    }
+
+    pub fn get_network_configuration(&'a self) -> &'a NetworkConfigurationT {
+        match self.network_configuration {
+            Some(ref v) => v,
+            None => NetworkConfigurationT::default_instance(),
+        }
+    }
+
+    pub fn clear_message_range(&mut self) {
+        self.message_range.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_range(&mut self, v: ~[MessageRangeT]) {
+        self.message_range = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_message_range(&'a mut self) -> &'a mut ~[MessageRangeT] {
+        &mut self.message_range
+    }
+
+    pub fn get_message_range(&'a self) -> &'a [MessageRangeT] {
+        rt::as_slice_tmp(&self.message_range)
+    }
+
+    pub fn add_message_range(&mut self, v: MessageRangeT) {
+        self.message_range.push(v);
+    }
+
+    pub fn clear_perform_list(&mut self) {
+        self.perform_list = None;
+    }
+
+    pub fn has_perform_list(&self) -> bool {
+        self.perform_list.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_perform_list(&mut self, v: PerformListT) {
+        self.perform_list = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_perform_list(&'a mut self) -> &'a mut PerformListT {
+        if self.perform_list.is_none() {
+            self.perform_list = Some(PerformListT::new());
+        };
+        self.perform_list.get_mut_ref()
+    }
+
+    pub fn get_perform_list(&'a self) -> &'a PerformListT {
+        match self.perform_list {
+            Some(ref v) => v,
+            None => PerformListT::default_instance(),
+        }
+    }
+
+    pub fn clear_identity_list(&mut self) {
+        self.identity_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identity_list(&mut self, v: ~[IdentityT]) {
+        self.identity_list = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_identity_list(&'a mut self) -> &'a mut ~[IdentityT] {
+        &mut self.identity_list
+    }
+
+    pub fn get_identity_list(&'a self) -> &'a [IdentityT] {
+        rt::as_slice_tmp(&self.identity_list)
+    }
+
+    pub fn add_identity_list(&mut self, v: IdentityT) {
+        self.identity_list.push(v);
+    }
+
+    pub fn clear_away_state(&mut self) {
+        self.away_state = None;
+    }
+
+    pub fn has_away_state(&self) -> bool {
+        self.away_state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_away_state(&mut self, v: AwayStateT) {
+        self.away_state = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_away_state(&'a mut self) -> &'a mut AwayStateT {
+        if self.away_state.is_none() {
+            self.away_state = Some(AwayStateT::new());
+        };
+        self.away_state.get_mut_ref()
+    }
+
+    pub fn get_away_state(&'a self) -> &'a AwayStateT {
+        match self.away_state {
+            Some(ref v) => v,
+            None => AwayStateT::default_instance(),
+        }
+    }
+
+    pub fn clear_message_msgid(&mut self) {
+        self.message_msgid = None;
+    }
+
+    pub fn has_message_msgid(&self) -> bool {
+        self.message_msgid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_msgid(&mut self, v: ~str) {
+        self.message_msgid = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message_msgid(&'a mut self) -> &'a mut ~str {
+        if self.message_msgid.is_none() {
+            self.message_msgid = Some(~"");
+        };
+        self.message_msgid.get_mut_ref()
+    }
+
+    pub fn get_message_msgid(&'a self) -> &'a str {
+        match self.message_msgid {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_message_account(&mut self) {
+        self.message_account = None;
+    }
+
+    pub fn has_message_account(&self) -> bool {
+        self.message_account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_account(&mut self, v: ~str) {
+        self.message_account = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message_account(&'a mut self) -> &'a mut ~str {
+        if self.message_account.is_none() {
+            self.message_account = Some(~"");
+        };
+        self.message_account.get_mut_ref()
+    }
+
+    pub fn get_message_account(&'a self) -> &'a str {
+        match self.message_account {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_message_prefix(&mut self) {
+        self.message_prefix = None;
+    }
+
+    pub fn has_message_prefix(&self) -> bool {
+        self.message_prefix.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_prefix(&mut self, v: ~str) {
+        self.message_prefix = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message_prefix(&'a mut self) -> &'a mut ~str {
+        if self.message_prefix.is_none() {
+            self.message_prefix = Some(~"");
+        };
+        self.message_prefix.get_mut_ref()
+    }
+
+    pub fn get_message_prefix(&'a self) -> &'a str {
+        match self.message_prefix {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_user_update(&mut self) {
+        self.user_update = None;
+    }
+
+    pub fn has_user_update(&self) -> bool {
+        self.user_update.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_update(&mut self, v: UserT) {
+        self.user_update = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_update(&'a mut self) -> &'a mut UserT {
+        if self.user_update.is_none() {
+            self.user_update = Some(UserT::new());
+        };
+        self.user_update.get_mut_ref()
+    }
+
+    pub fn get_user_update(&'a self) -> &'a UserT {
+        match self.user_update {
+            Some(ref v) => v,
+            None => UserT::default_instance(),
+        }
+    }
+
+    pub fn clear_buddy_list(&mut self) {
+        self.buddy_list = None;
+    }
+
+    pub fn has_buddy_list(&self) -> bool {
+        self.buddy_list.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_buddy_list(&mut self, v: BuddyListT) {
+        self.buddy_list = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_buddy_list(&'a mut self) -> &'a mut BuddyListT {
+        if self.buddy_list.is_none() {
+            self.buddy_list = Some(BuddyListT::new());
+        };
+        self.buddy_list.get_mut_ref()
+    }
+
+    pub fn get_buddy_list(&'a self) -> &'a BuddyListT {
+        match self.buddy_list {
+            Some(ref v) => v,
+            None => BuddyListT::default_instance(),
+        }
+    }
+
+    pub fn clear_presence(&mut self) {
+        self.presence = None;
+    }
+
+    pub fn has_presence(&self) -> bool {
+        self.presence.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_presence(&mut self, v: PresenceT) {
+        self.presence = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_presence(&'a mut self) -> &'a mut PresenceT {
+        if self.presence.is_none() {
+            self.presence = Some(PresenceT::new());
+        };
+        self.presence.get_mut_ref()
+    }
+
+    pub fn get_presence(&'a self) -> &'a PresenceT {
+        match self.presence {
+            Some(ref v) => v,
+            None => PresenceT::default_instance(),
+        }
+    }
+
+    pub fn clear_command_reply(&mut self) {
+        self.command_reply = None;
+    }
+
+    pub fn has_command_reply(&self) -> bool {
+        self.command_reply.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command_reply(&mut self, v: CommandReplyT) {
+        self.command_reply = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_command_reply(&'a mut self) -> &'a mut CommandReplyT {
+        if self.command_reply.is_none() {
+            self.command_reply = Some(CommandReplyT::new());
+        };
+        self.command_reply.get_mut_ref()
+    }
+
+    pub fn get_command_reply(&'a self) -> &'a CommandReplyT {
+        match self.command_reply {
+            Some(ref v) => v,
+            None => CommandReplyT::default_instance(),
+        }
+    }
+
+    pub fn clear_welcome(&mut self) {
+        self.welcome = None;
+    }
+
+    pub fn has_welcome(&self) -> bool {
+        self.welcome.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_welcome(&mut self, v: WelcomeT) {
+        self.welcome = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_welcome(&'a mut self) -> &'a mut WelcomeT {
+        if self.welcome.is_none() {
+            self.welcome = Some(WelcomeT::new());
+        };
+        self.welcome.get_mut_ref()
+    }
+
+    pub fn get_welcome(&'a self) -> &'a WelcomeT {
+        match self.welcome {
+            Some(ref v) => v,
+            None => WelcomeT::default_instance(),
+        }
+    }
+
+    pub fn clear_snapshot(&mut self) {
+        self.snapshot = None;
+    }
+
+    pub fn has_snapshot(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snapshot(&mut self, v: SnapshotT) {
+        self.snapshot = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snapshot(&'a mut self) -> &'a mut SnapshotT {
+        if self.snapshot.is_none() {
+            self.snapshot = Some(SnapshotT::new());
+        };
+        self.snapshot.get_mut_ref()
+    }
+
+    pub fn get_snapshot(&'a self) -> &'a SnapshotT {
+        match self.snapshot {
+            Some(ref v) => v,
+            None => SnapshotT::default_instance(),
+        }
+    }
+
+    pub fn clear_topic(&mut self) {
+        self.topic = None;
+    }
+
+    pub fn has_topic(&self) -> bool {
+        self.topic.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: TopicT) {
+        self.topic = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&'a mut self) -> &'a mut TopicT {
+        if self.topic.is_none() {
+            self.topic = Some(TopicT::new());
+        };
+        self.topic.get_mut_ref()
+    }
+
+    pub fn get_topic(&'a self) -> &'a TopicT {
+        match self.topic {
+            Some(ref v) => v,
+            None => TopicT::default_instance(),
+        }
+    }
+
+    pub fn clear_ping(&mut self) {
+        self.ping = None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        self.ping.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: PingT) {
+        self.ping = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ping(&'a mut self) -> &'a mut PingT {
+        if self.ping.is_none() {
+            self.ping = Some(PingT::new());
+        };
+        self.ping.get_mut_ref()
+    }
+
+    pub fn get_ping(&'a self) -> &'a PingT {
+        match self.ping {
+            Some(ref v) => v,
+            None => PingT::default_instance(),
+        }
+    }
+}
+
+impl Message for RemoteMessage {
+    fn new() -> RemoteMessage {
+        RemoteMessage::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_packet_type();
+        self.clear_network_id();
+        self.clear_buffer_id();
+        self.clear_message_id();
+        self.clear_message_time();
+        self.clear_tag();
+        self.clear_network_list();
+        self.clear_disconnected();
+        self.clear_buffer_list();
+        self.clear_new_buffer();
+        self.clear_information();
+        self.clear_error();
+        self.clear_join();
+        self.clear_privmsg();
+        self.clear_network_configuration();
+        self.clear_message_range();
+        self.clear_perform_list();
+        self.clear_identity_list();
+        self.clear_away_state();
+        self.clear_message_msgid();
+        self.clear_message_account();
+        self.clear_message_prefix();
+        self.clear_user_update();
+        self.clear_buddy_list();
+        self.clear_presence();
+        self.clear_command_reply();
+        self.clear_welcome();
+        self.clear_snapshot();
+        self.clear_topic();
+        self.clear_ping();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.packet_type.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = RemoteMessage_Type::new(is.read_int32());
+                    self.packet_type = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.network_id = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.buffer_id = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.message_id = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.message_time = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.tag = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = NetworkListT::new();
+                    is.merge_message(&mut tmp);
+                    self.network_list.push(tmp);
+                },
+                8 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = DisconnectedT::new();
+                    is.merge_message(&mut tmp);
+                    self.disconnected = Some(tmp);
+                },
+                9 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BufferListT::new();
+                    is.merge_message(&mut tmp);
+                    self.buffer_list.push(tmp);
+                },
+                10 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = NewBufferT::new();
+                    is.merge_message(&mut tmp);
+                    self.new_buffer = Some(tmp);
+                },
+                11 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = InformationT::new();
+                    is.merge_message(&mut tmp);
+                    self.information = Some(tmp);
+                },
+                12 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ErrorT::new();
+                    is.merge_message(&mut tmp);
+                    self.error = Some(tmp);
+                },
+                13 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = JoinT::new();
+                    is.merge_message(&mut tmp);
+                    self.join = Some(tmp);
+                },
+                14 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PrivmsgT::new();
+                    is.merge_message(&mut tmp);
+                    self.privmsg = Some(tmp);
+                },
+                15 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = NetworkConfigurationT::new();
+                    is.merge_message(&mut tmp);
+                    self.network_configuration = Some(tmp);
+                },
+                16 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = MessageRangeT::new();
+                    is.merge_message(&mut tmp);
+                    self.message_range.push(tmp);
+                },
+                17 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PerformListT::new();
+                    is.merge_message(&mut tmp);
+                    self.perform_list = Some(tmp);
+                },
+                18 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = IdentityT::new();
+                    is.merge_message(&mut tmp);
+                    self.identity_list.push(tmp);
+                },
+                19 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = AwayStateT::new();
+                    is.merge_message(&mut tmp);
+                    self.away_state = Some(tmp);
+                },
+                20 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.message_msgid = Some(tmp);
+                },
+                21 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.message_account = Some(tmp);
+                },
+                22 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.message_prefix = Some(tmp);
+                },
+                23 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = UserT::new();
+                    is.merge_message(&mut tmp);
+                    self.user_update = Some(tmp);
+                },
+                24 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BuddyListT::new();
+                    is.merge_message(&mut tmp);
+                    self.buddy_list = Some(tmp);
+                },
+                25 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PresenceT::new();
+                    is.merge_message(&mut tmp);
+                    self.presence = Some(tmp);
+                },
+                26 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = CommandReplyT::new();
+                    is.merge_message(&mut tmp);
+                    self.command_reply = Some(tmp);
+                },
+                27 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = WelcomeT::new();
+                    is.merge_message(&mut tmp);
+                    self.welcome = Some(tmp);
+                },
+                28 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = SnapshotT::new();
+                    is.merge_message(&mut tmp);
+                    self.snapshot = Some(tmp);
+                },
+                29 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = TopicT::new();
+                    is.merge_message(&mut tmp);
+                    self.topic = Some(tmp);
+                },
+                30 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = PingT::new();
+                    is.merge_message(&mut tmp);
+                    self.ping = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.packet_type.iter() {
+            my_size += rt::enum_size(1, *value);
+        };
+        for value in self.network_id.iter() {
+            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.buffer_id.iter() {
+            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.message_id.iter() {
+            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.message_time.iter() {
+            my_size += rt::value_size(5, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.tag.iter() {
+            my_size += rt::value_size(6, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.network_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.disconnected.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.buffer_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.new_buffer.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.information.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.error.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.join.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.privmsg.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.network_configuration.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.message_range.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.perform_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.identity_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.away_state.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.message_msgid.iter() {
+            my_size += rt::string_size(20, *value);
+        };
+        for value in self.message_account.iter() {
+            my_size += rt::string_size(21, *value);
+        };
+        for value in self.message_prefix.iter() {
+            my_size += rt::string_size(22, *value);
+        };
+        for value in self.user_update.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.buddy_list.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.presence.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.command_reply.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.welcome.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.snapshot.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.topic.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.ping.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub enum RemoteMessage_Type {
+    Error = 1,
+    Success = 2,
+    Welcome = 3,
+    Heartbeat = 4,
+    HeartbeatReply = 5,
+    Resync = 6,
+    NetworkList = 100,
+    IdentityList = 101,
+    Snapshot = 102,
+    Connected = 201,
+    Disconnected = 202,
+    BufferList = 203,
+    NewBuffer = 204,
+    NetworkConfiguration = 205,
+    PerformList = 206,
+    AwayState = 207,
+    UserUpdate = 208,
+    BuddyList = 209,
+    Presence = 210,
+    CommandReply = 211,
+    Topic = 212,
+    LastRead = 213,
+    Information = 305,
+    Join = 306,
+    Privmsg = 307,
+    MessageRange = 308,
+    Backfill = 309,
+}
+
+impl RemoteMessage_Type {
+    pub fn new(value: i32) -> RemoteMessage_Type {
+        match value {
+            1 => Error,
+            2 => Success,
+            3 => Welcome,
+            4 => Heartbeat,
+            5 => HeartbeatReply,
+            6 => Resync,
+            100 => NetworkList,
+            101 => IdentityList,
+            102 => Snapshot,
+            201 => Connected,
+            202 => Disconnected,
+            203 => BufferList,
+            204 => NewBuffer,
+            205 => NetworkConfiguration,
+            206 => PerformList,
+            207 => AwayState,
+            208 => UserUpdate,
+            209 => BuddyList,
+            210 => Presence,
+            211 => CommandReply,
+            212 => Topic,
+            213 => LastRead,
+            305 => Information,
+            306 => Join,
+            307 => Privmsg,
+            308 => MessageRange,
+            309 => Backfill,
+            _ => fail!()
+        }
+    }
+}
+
+impl ProtobufEnum for RemoteMessage_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct SnapshotT {
+    networks: ~[NetworkSnapshotT],
+}
+
+impl<'a> SnapshotT {
+    pub fn new() -> SnapshotT {
+        SnapshotT {
+            networks: ~[],
+        }
+    }
+
+    pub fn default_instance() -> &'static SnapshotT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: SnapshotT = SnapshotT {
+//             networks: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        for v in self.networks.iter() {
+            os.write_tag(1, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+    }
+
+    pub fn clear_networks(&mut self) {
+        self.networks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_networks(&mut self, v: ~[NetworkSnapshotT]) {
+        self.networks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_networks(&'a mut self) -> &'a mut ~[NetworkSnapshotT] {
+        &mut self.networks
+    }
+
+    pub fn get_networks(&'a self) -> &'a [NetworkSnapshotT] {
+        rt::as_slice_tmp(&self.networks)
+    }
+
+    pub fn add_networks(&mut self, v: NetworkSnapshotT) {
+        self.networks.push(v);
+    }
+}
+
+impl Message for SnapshotT {
+    fn new() -> SnapshotT {
+        SnapshotT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_networks();
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = NetworkSnapshotT::new();
+                    is.merge_message(&mut tmp);
+                    self.networks.push(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.networks.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct NetworkSnapshotT {
+    id: Option<u64>,
+    state: Option<NetworkListT_NetworkState>,
+    configuration: Option<NetworkConfigurationT>,
+    nickname: Option<~str>,
+    away: Option<~str>,
+    buffers: ~[BufferSnapshotT],
+}
+
+impl<'a> NetworkSnapshotT {
+    pub fn new() -> NetworkSnapshotT {
+        NetworkSnapshotT {
+            id: None,
+            state: None,
+            configuration: None,
+            nickname: None,
+            away: None,
+            buffers: ~[],
+        }
+    }
+
+    pub fn default_instance() -> &'static NetworkSnapshotT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: NetworkSnapshotT = NetworkSnapshotT {
+//             id: None,
+//             state: None,
+//             configuration: None,
+//             nickname: None,
+//             away: None,
+//             buffers: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.id {
+            Some(ref v) => {
+                os.write_uint64(1, *v);
+            },
+            None => {},
+        };
+        match self.state {
+            Some(ref v) => {
+                os.write_enum(2, *v as i32);
+            },
+            None => {},
+        };
+        match self.configuration {
+            Some(ref v) => {
+                os.write_tag(3, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.nickname {
+            Some(ref v) => {
+                os.write_string(4, *v);
+            },
+            None => {},
+        };
+        match self.away {
+            Some(ref v) => {
+                os.write_string(5, *v);
+            },
+            None => {},
+        };
+        for v in self.buffers.iter() {
+            os.write_tag(6, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+    }
+
+    pub fn clear_id(&mut self) {
+        self.id = None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
+        };
+        self.id.get_mut_ref()
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    pub fn clear_state(&mut self) {
+        self.state = None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: NetworkListT_NetworkState) {
+        self.state = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&'a mut self) -> &'a mut NetworkListT_NetworkState {
+        if self.state.is_none() {
+            self.state = Some(NetworkListT_NetworkState::new(0));
+        };
+        self.state.get_mut_ref()
+    }
+
+    pub fn get_state(&self) -> NetworkListT_NetworkState {
+        self.state.unwrap_or(NetworkListT_NetworkState::new(0))
+    }
+
+    pub fn clear_configuration(&mut self) {
+        self.configuration = None;
+    }
+
+    pub fn has_configuration(&self) -> bool {
+        self.configuration.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_configuration(&mut self, v: NetworkConfigurationT) {
+        self.configuration = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_configuration(&'a mut self) -> &'a mut NetworkConfigurationT {
+        if self.configuration.is_none() {
+            self.configuration = Some(NetworkConfigurationT::new());
+        };
+        self.configuration.get_mut_ref()
+    }
+
+    pub fn get_configuration(&'a self) -> &'a NetworkConfigurationT {
+        match self.configuration {
+            Some(ref v) => v,
+            None => NetworkConfigurationT::default_instance(),
+        }
+    }
+
+    pub fn clear_nickname(&mut self) {
+        self.nickname = None;
+    }
+
+    pub fn has_nickname(&self) -> bool {
+        self.nickname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nickname(&mut self, v: ~str) {
+        self.nickname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
+        if self.nickname.is_none() {
+            self.nickname = Some(~"");
+        };
+        self.nickname.get_mut_ref()
+    }
+
+    pub fn get_nickname(&'a self) -> &'a str {
+        match self.nickname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_away(&mut self) {
+        self.away = None;
+    }
+
+    pub fn has_away(&self) -> bool {
+        self.away.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_away(&mut self, v: ~str) {
+        self.away = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_away(&'a mut self) -> &'a mut ~str {
+        if self.away.is_none() {
+            self.away = Some(~"");
+        };
+        self.away.get_mut_ref()
+    }
+
+    pub fn get_away(&'a self) -> &'a str {
+        match self.away {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_buffers(&mut self) {
+        self.buffers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_buffers(&mut self, v: ~[BufferSnapshotT]) {
+        self.buffers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_buffers(&'a mut self) -> &'a mut ~[BufferSnapshotT] {
+        &mut self.buffers
+    }
+
+    pub fn get_buffers(&'a self) -> &'a [BufferSnapshotT] {
+        rt::as_slice_tmp(&self.buffers)
+    }
+
+    pub fn add_buffers(&mut self, v: BufferSnapshotT) {
+        self.buffers.push(v);
+    }
+}
+
+impl Message for NetworkSnapshotT {
+    fn new() -> NetworkSnapshotT {
+        NetworkSnapshotT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_state();
+        self.clear_configuration();
+        self.clear_nickname();
+        self.clear_away();
+        self.clear_buffers();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = NetworkListT_NetworkState::new(is.read_int32());
+                    self.state = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = NetworkConfigurationT::new();
+                    is.merge_message(&mut tmp);
+                    self.configuration = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.nickname = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.away = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BufferSnapshotT::new();
+                    is.merge_message(&mut tmp);
+                    self.buffers.push(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.state.iter() {
+            my_size += rt::enum_size(2, *value);
+        };
+        for value in self.configuration.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.nickname.iter() {
+            my_size += rt::string_size(4, *value);
+        };
+        for value in self.away.iter() {
+            my_size += rt::string_size(5, *value);
+        };
+        for value in self.buffers.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct BufferSnapshotT {
+    id: Option<u64>,
+    role: Option<BufferRole>,
+    stored_messages: Option<u64>,
+    last_read: Option<u64>,
+    unread: Option<u64>,
+    topic: Option<~str>,
+    members: ~[ChannelMemberT],
+}
+
+impl<'a> BufferSnapshotT {
+    pub fn new() -> BufferSnapshotT {
+        BufferSnapshotT {
+            id: None,
+            role: None,
+            stored_messages: None,
+            last_read: None,
+            unread: None,
+            topic: None,
+            members: ~[],
+        }
+    }
+
+    pub fn default_instance() -> &'static BufferSnapshotT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: BufferSnapshotT = BufferSnapshotT {
+//             id: None,
+//             role: None,
+//             stored_messages: None,
+//             last_read: None,
+//             unread: None,
+//             topic: None,
+//             members: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.id {
+            Some(ref v) => {
+                os.write_uint64(1, *v);
+            },
+            None => {},
+        };
+        match self.role {
+            Some(ref v) => {
+                os.write_tag(2, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.stored_messages {
+            Some(ref v) => {
+                os.write_uint64(3, *v);
+            },
+            None => {},
+        };
+        match self.last_read {
+            Some(ref v) => {
+                os.write_uint64(4, *v);
+            },
+            None => {},
+        };
+        match self.unread {
+            Some(ref v) => {
+                os.write_uint64(5, *v);
+            },
+            None => {},
+        };
+        match self.topic {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
+        for v in self.members.iter() {
+            os.write_tag(7, wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+        };
+    }
+
+    pub fn clear_id(&mut self) {
+        self.id = None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
+        };
+        self.id.get_mut_ref()
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    pub fn clear_role(&mut self) {
+        self.role = None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: BufferRole) {
+        self.role = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_role(&'a mut self) -> &'a mut BufferRole {
+        if self.role.is_none() {
+            self.role = Some(BufferRole::new());
+        };
+        self.role.get_mut_ref()
+    }
+
+    pub fn get_role(&'a self) -> &'a BufferRole {
+        match self.role {
+            Some(ref v) => v,
+            None => BufferRole::default_instance(),
+        }
+    }
+
+    pub fn clear_stored_messages(&mut self) {
+        self.stored_messages = None;
+    }
+
+    pub fn has_stored_messages(&self) -> bool {
+        self.stored_messages.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stored_messages(&mut self, v: u64) {
+        self.stored_messages = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stored_messages(&'a mut self) -> &'a mut u64 {
+        if self.stored_messages.is_none() {
+            self.stored_messages = Some(0);
+        };
+        self.stored_messages.get_mut_ref()
+    }
+
+    pub fn get_stored_messages(&self) -> u64 {
+        self.stored_messages.unwrap_or(0)
+    }
+
+    pub fn clear_last_read(&mut self) {
+        self.last_read = None;
+    }
+
+    pub fn has_last_read(&self) -> bool {
+        self.last_read.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_read(&mut self, v: u64) {
+        self.last_read = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last_read(&'a mut self) -> &'a mut u64 {
+        if self.last_read.is_none() {
+            self.last_read = Some(0);
+        };
+        self.last_read.get_mut_ref()
+    }
+
+    pub fn get_last_read(&self) -> u64 {
+        self.last_read.unwrap_or(0)
+    }
+
+    pub fn clear_unread(&mut self) {
+        self.unread = None;
+    }
+
+    pub fn has_unread(&self) -> bool {
+        self.unread.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unread(&mut self, v: u64) {
+        self.unread = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unread(&'a mut self) -> &'a mut u64 {
+        if self.unread.is_none() {
+            self.unread = Some(0);
+        };
+        self.unread.get_mut_ref()
+    }
+
+    pub fn get_unread(&self) -> u64 {
+        self.unread.unwrap_or(0)
+    }
+
+    pub fn clear_topic(&mut self) {
+        self.topic = None;
+    }
+
+    pub fn has_topic(&self) -> bool {
+        self.topic.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: ~str) {
+        self.topic = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&'a mut self) -> &'a mut ~str {
+        if self.topic.is_none() {
+            self.topic = Some(~"");
+        };
+        self.topic.get_mut_ref()
+    }
+
+    pub fn get_topic(&'a self) -> &'a str {
+        match self.topic {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_members(&mut self) {
+        self.members.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_members(&mut self, v: ~[ChannelMemberT]) {
+        self.members = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_members(&'a mut self) -> &'a mut ~[ChannelMemberT] {
+        &mut self.members
+    }
+
+    pub fn get_members(&'a self) -> &'a [ChannelMemberT] {
+        rt::as_slice_tmp(&self.members)
+    }
+
+    pub fn add_members(&mut self, v: ChannelMemberT) {
+        self.members.push(v);
+    }
+}
+
+impl Message for BufferSnapshotT {
+    fn new() -> BufferSnapshotT {
+        BufferSnapshotT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_role();
+        self.clear_stored_messages();
+        self.clear_last_read();
+        self.clear_unread();
+        self.clear_topic();
+        self.clear_members();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
+        if self.stored_messages.is_none() {
+            return false;
+        };
+        if self.unread.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BufferRole::new();
+                    is.merge_message(&mut tmp);
+                    self.role = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.stored_messages = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.last_read = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.unread = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.topic = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ChannelMemberT::new();
+                    is.merge_message(&mut tmp);
+                    self.members.push(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.stored_messages.iter() {
+            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.last_read.iter() {
+            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.unread.iter() {
+            my_size += rt::value_size(5, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.topic.iter() {
+            my_size += rt::string_size(6, *value);
+        };
+        for value in self.members.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct ChannelMemberT {
+    user: Option<UserT>,
+    prefixes: Option<~str>,
+}
+
+impl<'a> ChannelMemberT {
+    pub fn new() -> ChannelMemberT {
+        ChannelMemberT {
+            user: None,
+            prefixes: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static ChannelMemberT {
+        static instance: ChannelMemberT = ChannelMemberT {
+            user: None,
+            prefixes: None,
+        };
+        &'static instance
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.user {
+            Some(ref v) => {
+                os.write_tag(1, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.prefixes {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_user(&mut self) {
+        self.user = None;
+    }
+
+    pub fn has_user(&self) -> bool {
+        self.user.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user(&mut self, v: UserT) {
+        self.user = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user(&'a mut self) -> &'a mut UserT {
+        if self.user.is_none() {
+            self.user = Some(UserT::new());
+        };
+        self.user.get_mut_ref()
+    }
+
+    pub fn get_user(&'a self) -> &'a UserT {
+        match self.user {
+            Some(ref v) => v,
+            None => UserT::default_instance(),
+        }
+    }
+
+    pub fn clear_prefixes(&mut self) {
+        self.prefixes = None;
+    }
+
+    pub fn has_prefixes(&self) -> bool {
+        self.prefixes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefixes(&mut self, v: ~str) {
+        self.prefixes = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefixes(&'a mut self) -> &'a mut ~str {
+        if self.prefixes.is_none() {
+            self.prefixes = Some(~"");
+        };
+        self.prefixes.get_mut_ref()
+    }
+
+    pub fn get_prefixes(&'a self) -> &'a str {
+        match self.prefixes {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for ChannelMemberT {
+    fn new() -> ChannelMemberT {
+        ChannelMemberT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_user();
+        self.clear_prefixes();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.user.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = UserT::new();
+                    is.merge_message(&mut tmp);
+                    self.user = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.prefixes = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.user.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.prefixes.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct TopicT {
+    channel: Option<~str>,
+    topic: Option<~str>,
+}
+
+impl<'a> TopicT {
+    pub fn new() -> TopicT {
+        TopicT {
+            channel: None,
+            topic: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static TopicT {
+        static instance: TopicT = TopicT {
+            channel: None,
+            topic: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.channel {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.topic {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_channel(&mut self) {
+        self.channel = None;
+    }
+
+    pub fn has_channel(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ~str) {
+        self.channel = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&'a mut self) -> &'a mut ~str {
+        if self.channel.is_none() {
+            self.channel = Some(~"");
+        };
+        self.channel.get_mut_ref()
+    }
+
+    pub fn get_channel(&'a self) -> &'a str {
+        match self.channel {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_topic(&mut self) {
+        self.topic = None;
+    }
+
+    pub fn has_topic(&self) -> bool {
+        self.topic.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: ~str) {
+        self.topic = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&'a mut self) -> &'a mut ~str {
+        if self.topic.is_none() {
+            self.topic = Some(~"");
+        };
+        self.topic.get_mut_ref()
+    }
+
+    pub fn get_topic(&'a self) -> &'a str {
+        match self.topic {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for TopicT {
+    fn new() -> TopicT {
+        TopicT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_channel();
+        self.clear_topic();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.channel.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.channel = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.topic = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.channel.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        for value in self.topic.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct WelcomeT {
+    protocol_version: Option<u32>,
+    core_version: Option<~str>,
+    features: ~[~str],
+}
+
+impl<'a> WelcomeT {
+    pub fn new() -> WelcomeT {
+        WelcomeT {
+            protocol_version: None,
+            core_version: None,
+            features: ~[],
+        }
+    }
+
+    pub fn default_instance() -> &'static WelcomeT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: WelcomeT = WelcomeT {
+//             protocol_version: None,
+//             core_version: None,
+//             features: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.protocol_version {
+            Some(ref v) => {
+                os.write_uint32(1, *v);
+            },
+            None => {},
+        };
+        match self.core_version {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        for v in self.features.iter() {
+            os.write_string(3, *v);
+        };
+    }
+
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = None;
+    }
+
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_protocol_version(&'a mut self) -> &'a mut u32 {
+        if self.protocol_version.is_none() {
+            self.protocol_version = Some(0);
+        };
+        self.protocol_version.get_mut_ref()
+    }
+
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
+    }
+
+    pub fn clear_core_version(&mut self) {
+        self.core_version = None;
+    }
+
+    pub fn has_core_version(&self) -> bool {
+        self.core_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_core_version(&mut self, v: ~str) {
+        self.core_version = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_core_version(&'a mut self) -> &'a mut ~str {
+        if self.core_version.is_none() {
+            self.core_version = Some(~"");
+        };
+        self.core_version.get_mut_ref()
+    }
+
+    pub fn get_core_version(&'a self) -> &'a str {
+        match self.core_version {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_features(&mut self) {
+        self.features.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_features(&mut self, v: ~[~str]) {
+        self.features = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_features(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.features
+    }
+
+    pub fn get_features(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.features)
+    }
+
+    pub fn add_features(&mut self, v: ~str) {
+        self.features.push(v);
+    }
+}
+
+impl Message for WelcomeT {
+    fn new() -> WelcomeT {
+        WelcomeT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_protocol_version();
+        self.clear_core_version();
+        self.clear_features();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.protocol_version.is_none() {
+            return false;
+        };
+        if self.core_version.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint32();
+                    self.protocol_version = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.core_version = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.features.push(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.protocol_version.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.core_version.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.features.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct NetworkListT {
+    id: Option<u64>,
+    state: Option<NetworkListT_NetworkState>,
+}
+
+impl<'a> NetworkListT {
+    pub fn new() -> NetworkListT {
+        NetworkListT {
+            id: None,
+            state: None,
+        }
+    }
+
+/*    pub fn default_instance() -> &'static NetworkListT {
+        static instance: NetworkListT = NetworkListT {
+            id: None,
+            state: None,
+        };
+        &'static instance
+    }
+*/
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.id {
+            Some(ref v) => {
+                os.write_uint64(1, *v);
+            },
+            None => {},
+        };
+        match self.state {
+            Some(ref v) => {
+                os.write_enum(2, *v as i32);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_id(&mut self) {
+        self.id = None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
+        };
+        self.id.get_mut_ref()
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    pub fn clear_state(&mut self) {
+        self.state = None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: NetworkListT_NetworkState) {
+        self.state = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&'a mut self) -> &'a mut NetworkListT_NetworkState {
+        if self.state.is_none() {
+            self.state = Some(NetworkListT_NetworkState::new(0));
+        };
+        self.state.get_mut_ref()
+    }
+
+    pub fn get_state(&self) -> NetworkListT_NetworkState {
+        self.state.unwrap_or(NetworkListT_NetworkState::new(0))
+    }
+}
+
+impl Message for NetworkListT {
+    fn new() -> NetworkListT {
+        NetworkListT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_state();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = NetworkListT_NetworkState::new(is.read_int32());
+                    self.state = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.state.iter() {
+            my_size += rt::enum_size(2, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub enum NetworkListT_NetworkState {
+    NetworkDisconnected = 0,
+    NetworkConnecting = 1,
+    NetworkConnected = 2,
+}
+
+impl NetworkListT_NetworkState {
+    pub fn new(value: i32) -> NetworkListT_NetworkState {
+        match value {
+            0 => NetworkDisconnected,
+            1 => NetworkConnecting,
+            2 => NetworkConnected,
+            _ => fail!()
+        }
+    }
+}
+
+impl ProtobufEnum for NetworkListT_NetworkState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct DisconnectedT {
+    reason: Option<~str>,
+}
+
+impl<'a> DisconnectedT {
+    pub fn new() -> DisconnectedT {
+        DisconnectedT {
+            reason: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static DisconnectedT {
+        static instance: DisconnectedT = DisconnectedT {
+            reason: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.reason {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_reason(&mut self) {
+        self.reason = None;
+    }
+
+    pub fn has_reason(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ~str) {
+        self.reason = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&'a mut self) -> &'a mut ~str {
+        if self.reason.is_none() {
+            self.reason = Some(~"");
+        };
+        self.reason.get_mut_ref()
+    }
+
+    pub fn get_reason(&'a self) -> &'a str {
+        match self.reason {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for DisconnectedT {
+    fn new() -> DisconnectedT {
+        DisconnectedT::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_reason();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.reason.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.reason = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.reason.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct BufferRole {
+    buffer_type: Option<BufferRole_Type>,
+    name: Option<~str>,
+}
+
+impl<'a> BufferRole {
+    pub fn new() -> BufferRole {
+        BufferRole {
+            buffer_type: None,
+            name: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static BufferRole {
+        static instance: BufferRole = BufferRole {
+            buffer_type: None,
+            name: None,
+        };
+        &'static instance
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.buffer_type {
+            Some(ref v) => {
+                os.write_enum(1, *v as i32);
+            },
+            None => {},
+        };
+        match self.name {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_buffer_type(&mut self) {
+        self.buffer_type = None;
+    }
+
+    pub fn has_buffer_type(&self) -> bool {
+        self.buffer_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_buffer_type(&mut self, v: BufferRole_Type) {
+        self.buffer_type = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_buffer_type(&'a mut self) -> &'a mut BufferRole_Type {
+        if self.buffer_type.is_none() {
+            self.buffer_type = Some(BufferRole_Type::new(0));
+        };
+        self.buffer_type.get_mut_ref()
+    }
+
+    pub fn get_buffer_type(&self) -> BufferRole_Type {
+        self.buffer_type.unwrap_or(BufferRole_Type::new(0))
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ~str) {
+        self.name = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&'a mut self) -> &'a mut ~str {
+        if self.name.is_none() {
+            self.name = Some(~"");
+        };
+        self.name.get_mut_ref()
+    }
+
+    pub fn get_name(&'a self) -> &'a str {
+        match self.name {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+}
+
+impl Message for BufferRole {
+    fn new() -> BufferRole {
+        BufferRole::new()
+    }
+
+    fn clear(&mut self) {
+        self.clear_buffer_type();
+        self.clear_name();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.buffer_type.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = BufferRole_Type::new(is.read_int32());
+                    self.buffer_type = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.name = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.buffer_type.iter() {
+            my_size += rt::enum_size(1, *value);
         };
-        match self.error {
-            Some(ref v) => {
-                os.write_tag(12, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
-            },
-            None => {},
+        for value in self.name.iter() {
+            my_size += rt::string_size(2, *value);
         };
-        match self.join {
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub enum BufferRole_Type {
+    Status = 1,
+    Channel = 2,
+    Query = 3,
+}
+
+impl BufferRole_Type {
+    pub fn new(value: i32) -> BufferRole_Type {
+        match value {
+            1 => Status,
+            2 => Channel,
+            3 => Query,
+            _ => fail!()
+        }
+    }
+}
+
+impl ProtobufEnum for BufferRole_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[deriving(Clone,Eq)]
+pub struct BufferListT {
+    id: Option<u64>,
+    role: Option<BufferRole>,
+    stored_messages: Option<u64>,
+}
+
+impl<'a> BufferListT {
+    pub fn new() -> BufferListT {
+        BufferListT {
+            id: None,
+            role: None,
+            stored_messages: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static BufferListT {
+        static instance: BufferListT = BufferListT {
+            id: None,
+            role: None,
+            stored_messages: None,
+        };
+        &'static instance
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.id {
             Some(ref v) => {
-                os.write_tag(13, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                os.write_uint64(1, *v);
             },
             None => {},
         };
-        match self.privmsg {
+        match self.role {
             Some(ref v) => {
-                os.write_tag(14, wire_format::WireTypeLengthDelimited);
+                os.write_tag(2, wire_format::WireTypeLengthDelimited);
                 os.write_raw_varint32(sizes[*sizes_pos]);
                 *sizes_pos += 1;
                 v.write_to_with_computed_sizes(os, sizes, sizes_pos);
             },
             None => {},
         };
-        match self.network_configuration {
+        match self.stored_messages {
             Some(ref v) => {
-                os.write_tag(15, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                os.write_uint64(3, *v);
             },
             None => {},
         };
-        for v in self.message_range.iter() {
-            os.write_tag(16, wire_format::WireTypeLengthDelimited);
-            os.write_raw_varint32(sizes[*sizes_pos]);
-            *sizes_pos += 1;
-            v.write_to_with_computed_sizes(os, sizes, sizes_pos);
-        };
     }
 
-    pub fn clear_packet_type(&mut self) {
-        self.packet_type = None;
+    pub fn clear_id(&mut self) {
+        self.id = None;
     }
 
-    pub fn has_packet_type(&self) -> bool {
-        self.packet_type.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_packet_type(&mut self, v: RemoteMessage_Type) {
-        self.packet_type = Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_packet_type(&'a mut self) -> &'a mut RemoteMessage_Type {
-        if self.packet_type.is_none() {
-            self.packet_type = Some(RemoteMessage_Type::new(0));
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
         };
-        self.packet_type.get_mut_ref()
+        self.id.get_mut_ref()
     }
 
-    pub fn get_packet_type(&self) -> RemoteMessage_Type {
-        self.packet_type.unwrap_or(RemoteMessage_Type::new(0))
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    pub fn clear_network_id(&mut self) {
-        self.network_id = None;
+    pub fn clear_role(&mut self) {
+        self.role = None;
     }
 
-    pub fn has_network_id(&self) -> bool {
-        self.network_id.is_some()
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_network_id(&mut self, v: u64) {
-        self.network_id = Some(v);
+    pub fn set_role(&mut self, v: BufferRole) {
+        self.role = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_network_id(&'a mut self) -> &'a mut u64 {
-        if self.network_id.is_none() {
-            self.network_id = Some(0);
+    pub fn mut_role(&'a mut self) -> &'a mut BufferRole {
+        if self.role.is_none() {
+            self.role = Some(BufferRole::new());
         };
-        self.network_id.get_mut_ref()
+        self.role.get_mut_ref()
     }
 
-    pub fn get_network_id(&self) -> u64 {
-        self.network_id.unwrap_or(0)
+    pub fn get_role(&'a self) -> &'a BufferRole {
+        match self.role {
+            Some(ref v) => v,
+            None => BufferRole::default_instance(),
+        }
     }
 
-    pub fn clear_buffer_id(&mut self) {
-        self.buffer_id = None;
+    pub fn clear_stored_messages(&mut self) {
+        self.stored_messages = None;
     }
 
-    pub fn has_buffer_id(&self) -> bool {
-        self.buffer_id.is_some()
+    pub fn has_stored_messages(&self) -> bool {
+        self.stored_messages.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_buffer_id(&mut self, v: u64) {
-        self.buffer_id = Some(v);
+    pub fn set_stored_messages(&mut self, v: u64) {
+        self.stored_messages = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_buffer_id(&'a mut self) -> &'a mut u64 {
-        if self.buffer_id.is_none() {
-            self.buffer_id = Some(0);
+    pub fn mut_stored_messages(&'a mut self) -> &'a mut u64 {
+        if self.stored_messages.is_none() {
+            self.stored_messages = Some(0);
         };
-        self.buffer_id.get_mut_ref()
+        self.stored_messages.get_mut_ref()
     }
 
-    pub fn get_buffer_id(&self) -> u64 {
-        self.buffer_id.unwrap_or(0)
+    pub fn get_stored_messages(&self) -> u64 {
+        self.stored_messages.unwrap_or(0)
     }
+}
 
-    pub fn clear_message_id(&mut self) {
-        self.message_id = None;
+impl Message for BufferListT {
+    fn new() -> BufferListT {
+        BufferListT::new()
     }
 
-    pub fn has_message_id(&self) -> bool {
-        self.message_id.is_some()
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_role();
+        self.clear_stored_messages();
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
+        if self.stored_messages.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BufferRole::new();
+                    is.merge_message(&mut tmp);
+                    self.role = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.stored_messages = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
+        }
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.stored_messages.iter() {
+            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_message_id(&mut self, v: u64) {
-        self.message_id = Some(v);
+#[deriving(Clone,Eq)]
+pub struct NewBufferT {
+    id: Option<u64>,
+    role: Option<BufferRole>,
+}
+
+impl<'a> NewBufferT {
+    pub fn new() -> NewBufferT {
+        NewBufferT {
+            id: None,
+            role: None,
+        }
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_message_id(&'a mut self) -> &'a mut u64 {
-        if self.message_id.is_none() {
-            self.message_id = Some(0);
+    pub fn default_instance() -> &'static NewBufferT {
+        static instance: NewBufferT = NewBufferT {
+            id: None,
+            role: None,
         };
-        self.message_id.get_mut_ref()
+        &'static instance
     }
 
-    pub fn get_message_id(&self) -> u64 {
-        self.message_id.unwrap_or(0)
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.id {
+            Some(ref v) => {
+                os.write_uint64(1, *v);
+            },
+            None => {},
+        };
+        match self.role {
+            Some(ref v) => {
+                os.write_tag(2, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_message_time(&mut self) {
-        self.message_time = None;
+    pub fn clear_id(&mut self) {
+        self.id = None;
     }
 
-    pub fn has_message_time(&self) -> bool {
-        self.message_time.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_message_time(&mut self, v: u64) {
-        self.message_time = Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_message_time(&'a mut self) -> &'a mut u64 {
-        if self.message_time.is_none() {
-            self.message_time = Some(0);
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
         };
-        self.message_time.get_mut_ref()
+        self.id.get_mut_ref()
     }
 
-    pub fn get_message_time(&self) -> u64 {
-        self.message_time.unwrap_or(0)
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    pub fn clear_tag(&mut self) {
-        self.tag = None;
+    pub fn clear_role(&mut self) {
+        self.role = None;
     }
 
-    pub fn has_tag(&self) -> bool {
-        self.tag.is_some()
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_tag(&mut self, v: u64) {
-        self.tag = Some(v);
+    pub fn set_role(&mut self, v: BufferRole) {
+        self.role = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_tag(&'a mut self) -> &'a mut u64 {
-        if self.tag.is_none() {
-            self.tag = Some(0);
+    pub fn mut_role(&'a mut self) -> &'a mut BufferRole {
+        if self.role.is_none() {
+            self.role = Some(BufferRole::new());
         };
-        self.tag.get_mut_ref()
-    }
-
-    pub fn get_tag(&self) -> u64 {
-        self.tag.unwrap_or(0)
-    }
-
-    pub fn clear_network_list(&mut self) {
-        self.network_list.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_network_list(&mut self, v: ~[NetworkListT]) {
-        self.network_list = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_network_list(&'a mut self) -> &'a mut ~[NetworkListT] {
-        &mut self.network_list
-    }
-
-    pub fn get_network_list(&'a self) -> &'a [NetworkListT] {
-        rt::as_slice_tmp(&self.network_list)
-    }
-
-    pub fn add_network_list(&mut self, v: NetworkListT) {
-        self.network_list.push(v);
+        self.role.get_mut_ref()
     }
 
-    pub fn clear_disconnected(&mut self) {
-        self.disconnected = None;
+    pub fn get_role(&'a self) -> &'a BufferRole {
+        match self.role {
+            Some(ref v) => v,
+            None => BufferRole::default_instance(),
+        }
     }
+}
 
-    pub fn has_disconnected(&self) -> bool {
-        self.disconnected.is_some()
+impl Message for NewBufferT {
+    fn new() -> NewBufferT {
+        NewBufferT::new()
     }
 
-    // Param is passed by value, moved
-    pub fn set_disconnected(&mut self, v: DisconnectedT) {
-        self.disconnected = Some(v);
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_role();
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_disconnected(&'a mut self) -> &'a mut DisconnectedT {
-        if self.disconnected.is_none() {
-            self.disconnected = Some(DisconnectedT::new());
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
         };
-        self.disconnected.get_mut_ref()
+        if self.role.is_none() {
+            return false;
+        };
+        true
     }
 
-    pub fn get_disconnected(&'a self) -> &'a DisconnectedT {
-        match self.disconnected {
-            Some(ref v) => v,
-            None => DisconnectedT::default_instance(),
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = BufferRole::new();
+                    is.merge_message(&mut tmp);
+                    self.role = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
         }
     }
 
-    pub fn clear_buffer_list(&mut self) {
-        self.buffer_list.clear();
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_buffer_list(&mut self, v: ~[BufferListT]) {
-        self.buffer_list = v;
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_buffer_list(&'a mut self) -> &'a mut ~[BufferListT] {
-        &mut self.buffer_list
+#[deriving(Clone,Eq)]
+pub struct InformationT {
+    msg: Option<~str>,
+}
+
+impl<'a> InformationT {
+    pub fn new() -> InformationT {
+        InformationT {
+            msg: None,
+        }
     }
 
-    pub fn get_buffer_list(&'a self) -> &'a [BufferListT] {
-        rt::as_slice_tmp(&self.buffer_list)
+    pub fn default_instance() -> &'static InformationT {
+        static instance: InformationT = InformationT {
+            msg: None,
+        };
+        &'static instance
     }
 
-    pub fn add_buffer_list(&mut self, v: BufferListT) {
-        self.buffer_list.push(v);
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.msg {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_new_buffer(&mut self) {
-        self.new_buffer = None;
+    pub fn clear_msg(&mut self) {
+        self.msg = None;
     }
 
-    pub fn has_new_buffer(&self) -> bool {
-        self.new_buffer.is_some()
+    pub fn has_msg(&self) -> bool {
+        self.msg.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_new_buffer(&mut self, v: NewBufferT) {
-        self.new_buffer = Some(v);
+    pub fn set_msg(&mut self, v: ~str) {
+        self.msg = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_new_buffer(&'a mut self) -> &'a mut NewBufferT {
-        if self.new_buffer.is_none() {
-            self.new_buffer = Some(NewBufferT::new());
-        };
-        self.new_buffer.get_mut_ref()
-    }
-
-    pub fn get_new_buffer(&'a self) -> &'a NewBufferT {
-        match self.new_buffer {
-            Some(ref v) => v,
-            None => NewBufferT::default_instance(),
-        }
+    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
+        if self.msg.is_none() {
+            self.msg = Some(~"");
+        };
+        self.msg.get_mut_ref()
     }
 
-    pub fn clear_information(&mut self) {
-        self.information = None;
+    pub fn get_msg(&'a self) -> &'a str {
+        match self.msg {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
     }
+}
 
-    pub fn has_information(&self) -> bool {
-        self.information.is_some()
+impl Message for InformationT {
+    fn new() -> InformationT {
+        InformationT::new()
     }
 
-    // Param is passed by value, moved
-    pub fn set_information(&mut self, v: InformationT) {
-        self.information = Some(v);
+    fn clear(&mut self) {
+        self.clear_msg();
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_information(&'a mut self) -> &'a mut InformationT {
-        if self.information.is_none() {
-            self.information = Some(InformationT::new());
+    fn is_initialized(&self) -> bool {
+        if self.msg.is_none() {
+            return false;
         };
-        self.information.get_mut_ref()
+        true
     }
 
-    pub fn get_information(&'a self) -> &'a InformationT {
-        match self.information {
-            Some(ref v) => v,
-            None => InformationT::default_instance(),
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.msg = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
         }
     }
 
-    pub fn clear_error(&mut self) {
-        self.error = None;
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.msg.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
     }
 
-    pub fn has_error(&self) -> bool {
-        self.error.is_some()
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_error(&mut self, v: ErrorT) {
-        self.error = Some(v);
+#[deriving(Clone,Eq)]
+pub struct ErrorT {
+    msg: Option<~str>,
+}
+
+impl<'a> ErrorT {
+    pub fn new() -> ErrorT {
+        ErrorT {
+            msg: None,
+        }
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_error(&'a mut self) -> &'a mut ErrorT {
-        if self.error.is_none() {
-            self.error = Some(ErrorT::new());
+    pub fn default_instance() -> &'static ErrorT {
+        static instance: ErrorT = ErrorT {
+            msg: None,
         };
-        self.error.get_mut_ref()
+        &'static instance
     }
 
-    pub fn get_error(&'a self) -> &'a ErrorT {
-        match self.error {
-            Some(ref v) => v,
-            None => ErrorT::default_instance(),
-        }
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.msg {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_join(&mut self) {
-        self.join = None;
+    pub fn clear_msg(&mut self) {
+        self.msg = None;
     }
 
-    pub fn has_join(&self) -> bool {
-        self.join.is_some()
+    pub fn has_msg(&self) -> bool {
+        self.msg.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_join(&mut self, v: JoinT) {
-        self.join = Some(v);
+    pub fn set_msg(&mut self, v: ~str) {
+        self.msg = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_join(&'a mut self) -> &'a mut JoinT {
-        if self.join.is_none() {
-            self.join = Some(JoinT::new());
+    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
+        if self.msg.is_none() {
+            self.msg = Some(~"");
         };
-        self.join.get_mut_ref()
+        self.msg.get_mut_ref()
     }
 
-    pub fn get_join(&'a self) -> &'a JoinT {
-        match self.join {
-            Some(ref v) => v,
-            None => JoinT::default_instance(),
+    pub fn get_msg(&'a self) -> &'a str {
+        match self.msg {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
         }
     }
+}
 
-    pub fn clear_privmsg(&mut self) {
-        self.privmsg = None;
-    }
-
-    pub fn has_privmsg(&self) -> bool {
-        self.privmsg.is_some()
+impl Message for ErrorT {
+    fn new() -> ErrorT {
+        ErrorT::new()
     }
 
-    // Param is passed by value, moved
-    pub fn set_privmsg(&mut self, v: PrivmsgT) {
-        self.privmsg = Some(v);
+    fn clear(&mut self) {
+        self.clear_msg();
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_privmsg(&'a mut self) -> &'a mut PrivmsgT {
-        if self.privmsg.is_none() {
-            self.privmsg = Some(PrivmsgT::new());
+    fn is_initialized(&self) -> bool {
+        if self.msg.is_none() {
+            return false;
         };
-        self.privmsg.get_mut_ref()
+        true
     }
 
-    pub fn get_privmsg(&'a self) -> &'a PrivmsgT {
-        match self.privmsg {
-            Some(ref v) => v,
-            None => PrivmsgT::default_instance(),
+    fn merge_from(&mut self, is: &mut CodedInputStream) {
+        while !is.eof() {
+            let (field_number, wire_type) = is.read_tag_unpack();
+            match field_number {
+                1 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.msg = Some(tmp);
+                },
+                _ => {
+                    // TODO: store in unknown fields
+                    is.skip_field(wire_type);
+                },
+            };
         }
     }
 
-    pub fn clear_network_configuration(&mut self) {
-        self.network_configuration = None;
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut ~[u32]) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.msg.iter() {
+            my_size += rt::string_size(1, *value);
+        };
+        sizes[pos] = my_size;
+        // value is returned for convenience
+        my_size
     }
 
-    pub fn has_network_configuration(&self) -> bool {
-        self.network_configuration.is_some()
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized();
+        let mut sizes: ~[u32] = ~[];
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes, &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_network_configuration(&mut self, v: NetworkConfigurationT) {
-        self.network_configuration = Some(v);
+#[deriving(Clone,Eq)]
+pub struct JoinT {
+    who: Option<~str>,
+}
+
+impl<'a> JoinT {
+    pub fn new() -> JoinT {
+        JoinT {
+            who: None,
+        }
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_network_configuration(&'a mut self) -> &'a mut NetworkConfigurationT {
-        if self.network_configuration.is_none() {
-            self.network_configuration = Some(NetworkConfigurationT::new());
+    pub fn default_instance() -> &'static JoinT {
+        static instance: JoinT = JoinT {
+            who: None,
         };
-        self.network_configuration.get_mut_ref()
+        &'static instance
     }
 
-    pub fn get_network_configuration(&'a self) -> &'a NetworkConfigurationT {
-        match self.network_configuration {
-            Some(ref v) => v,
-            None => NetworkConfigurationT::default_instance(),
-        }
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.who {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_message_range(&mut self) {
-        self.message_range.clear();
+    pub fn clear_who(&mut self) {
+        self.who = None;
     }
 
-    // Param is passed by value, moved
-    pub fn set_message_range(&mut self, v: ~[MessageRangeT]) {
-        self.message_range = v;
+    pub fn has_who(&self) -> bool {
+        self.who.is_some()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_message_range(&'a mut self) -> &'a mut ~[MessageRangeT] {
-        &mut self.message_range
+    // Param is passed by value, moved
+    pub fn set_who(&mut self, v: ~str) {
+        self.who = Some(v);
     }
 
-    pub fn get_message_range(&'a self) -> &'a [MessageRangeT] {
-        rt::as_slice_tmp(&self.message_range)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_who(&'a mut self) -> &'a mut ~str {
+        if self.who.is_none() {
+            self.who = Some(~"");
+        };
+        self.who.get_mut_ref()
     }
 
-    pub fn add_message_range(&mut self, v: MessageRangeT) {
-        self.message_range.push(v);
+    pub fn get_who(&'a self) -> &'a str {
+        match self.who {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
     }
 }
 
-impl Message for RemoteMessage {
-    fn new() -> RemoteMessage {
-        RemoteMessage::new()
+impl Message for JoinT {
+    fn new() -> JoinT {
+        JoinT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_packet_type();
-        self.clear_network_id();
-        self.clear_buffer_id();
-        self.clear_message_id();
-        self.clear_message_time();
-        self.clear_tag();
-        self.clear_network_list();
-        self.clear_disconnected();
-        self.clear_buffer_list();
-        self.clear_new_buffer();
-        self.clear_information();
-        self.clear_error();
-        self.clear_join();
-        self.clear_privmsg();
-        self.clear_network_configuration();
-        self.clear_message_range();
+        self.clear_who();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.packet_type.is_none() {
+        if self.who.is_none() {
             return false;
         };
         true
@@ -2028,94 +8328,9 @@ impl Message for RemoteMessage {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
                 1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = RemoteMessage_Type::new(is.read_int32());
-                    self.packet_type = Some(tmp);
-                },
-                2 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.network_id = Some(tmp);
-                },
-                3 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.buffer_id = Some(tmp);
-                },
-                4 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.message_id = Some(tmp);
-                },
-                5 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.message_time = Some(tmp);
-                },
-                6 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.tag = Some(tmp);
-                },
-                7 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = NetworkListT::new();
-                    is.merge_message(&mut tmp);
-                    self.network_list.push(tmp);
-                },
-                8 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = DisconnectedT::new();
-                    is.merge_message(&mut tmp);
-                    self.disconnected = Some(tmp);
-                },
-                9 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = BufferListT::new();
-                    is.merge_message(&mut tmp);
-                    self.buffer_list.push(tmp);
-                },
-                10 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = NewBufferT::new();
-                    is.merge_message(&mut tmp);
-                    self.new_buffer = Some(tmp);
-                },
-                11 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = InformationT::new();
-                    is.merge_message(&mut tmp);
-                    self.information = Some(tmp);
-                },
-                12 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = ErrorT::new();
-                    is.merge_message(&mut tmp);
-                    self.error = Some(tmp);
-                },
-                13 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = JoinT::new();
-                    is.merge_message(&mut tmp);
-                    self.join = Some(tmp);
-                },
-                14 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = PrivmsgT::new();
-                    is.merge_message(&mut tmp);
-                    self.privmsg = Some(tmp);
-                },
-                15 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = NetworkConfigurationT::new();
-                    is.merge_message(&mut tmp);
-                    self.network_configuration = Some(tmp);
-                },
-                16 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = MessageRangeT::new();
-                    is.merge_message(&mut tmp);
-                    self.message_range.push(tmp);
+                    let tmp = is.read_string();
+                    self.who = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -2130,63 +8345,8 @@ impl Message for RemoteMessage {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.packet_type.iter() {
-            my_size += rt::enum_size(1, *value);
-        };
-        for value in self.network_id.iter() {
-            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.buffer_id.iter() {
-            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.message_id.iter() {
-            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.message_time.iter() {
-            my_size += rt::value_size(5, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.tag.iter() {
-            my_size += rt::value_size(6, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.network_list.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.disconnected.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.buffer_list.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.new_buffer.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.information.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.error.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.join.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.privmsg.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.network_configuration.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.message_range.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 2 + rt::compute_raw_varint32_size(len) + len;
+        for value in self.who.iter() {
+            my_size += rt::string_size(1, *value);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -2204,153 +8364,117 @@ impl Message for RemoteMessage {
 }
 
 #[deriving(Clone,Eq)]
-pub enum RemoteMessage_Type {
-    Error = 1,
-    Success = 2,
-    NetworkList = 100,
-    Connected = 201,
-    Disconnected = 202,
-    BufferList = 203,
-    NewBuffer = 204,
-    NetworkConfiguration = 205,
-    Information = 305,
-    Join = 306,
-    Privmsg = 307,
-    MessageRange = 308,
-}
-
-impl RemoteMessage_Type {
-    pub fn new(value: i32) -> RemoteMessage_Type {
-        match value {
-            1 => Error,
-            2 => Success,
-            100 => NetworkList,
-            201 => Connected,
-            202 => Disconnected,
-            203 => BufferList,
-            204 => NewBuffer,
-            205 => NetworkConfiguration,
-            305 => Information,
-            306 => Join,
-            307 => Privmsg,
-            308 => MessageRange,
-            _ => fail!()
-        }
-    }
-}
-
-impl ProtobufEnum for RemoteMessage_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-}
-
-#[deriving(Clone,Eq)]
-pub struct NetworkListT {
-    id: Option<u64>,
-    state: Option<NetworkListT_NetworkState>,
+pub struct PrivmsgT {
+    who: Option<~str>,
+    msg: Option<~str>,
 }
 
-impl<'a> NetworkListT {
-    pub fn new() -> NetworkListT {
-        NetworkListT {
-            id: None,
-            state: None,
+impl<'a> PrivmsgT {
+    pub fn new() -> PrivmsgT {
+        PrivmsgT {
+            who: None,
+            msg: None,
         }
     }
 
-/*    pub fn default_instance() -> &'static NetworkListT {
-        static instance: NetworkListT = NetworkListT {
-            id: None,
-            state: None,
+    pub fn default_instance() -> &'static PrivmsgT {
+        static instance: PrivmsgT = PrivmsgT {
+            who: None,
+            msg: None,
         };
         &'static instance
     }
-*/
+
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.id {
+        match self.who {
             Some(ref v) => {
-                os.write_uint64(1, *v);
+                os.write_string(1, *v);
             },
             None => {},
         };
-        match self.state {
+        match self.msg {
             Some(ref v) => {
-                os.write_enum(2, *v as i32);
+                os.write_string(2, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_id(&mut self) {
-        self.id = None;
+    pub fn clear_who(&mut self) {
+        self.who = None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_who(&self) -> bool {
+        self.who.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = Some(v);
+    pub fn set_who(&mut self, v: ~str) {
+        self.who = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&'a mut self) -> &'a mut u64 {
-        if self.id.is_none() {
-            self.id = Some(0);
+    pub fn mut_who(&'a mut self) -> &'a mut ~str {
+        if self.who.is_none() {
+            self.who = Some(~"");
         };
-        self.id.get_mut_ref()
+        self.who.get_mut_ref()
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_who(&'a self) -> &'a str {
+        match self.who {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
     }
 
-    pub fn clear_state(&mut self) {
-        self.state = None;
+    pub fn clear_msg(&mut self) {
+        self.msg = None;
     }
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+    pub fn has_msg(&self) -> bool {
+        self.msg.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: NetworkListT_NetworkState) {
-        self.state = Some(v);
+    pub fn set_msg(&mut self, v: ~str) {
+        self.msg = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_state(&'a mut self) -> &'a mut NetworkListT_NetworkState {
-        if self.state.is_none() {
-            self.state = Some(NetworkListT_NetworkState::new(0));
+    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
+        if self.msg.is_none() {
+            self.msg = Some(~"");
         };
-        self.state.get_mut_ref()
+        self.msg.get_mut_ref()
     }
 
-    pub fn get_state(&self) -> NetworkListT_NetworkState {
-        self.state.unwrap_or(NetworkListT_NetworkState::new(0))
+    pub fn get_msg(&'a self) -> &'a str {
+        match self.msg {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
     }
 }
 
-impl Message for NetworkListT {
-    fn new() -> NetworkListT {
-        NetworkListT::new()
+impl Message for PrivmsgT {
+    fn new() -> PrivmsgT {
+        PrivmsgT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_state();
+        self.clear_who();
+        self.clear_msg();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
+        if self.who.is_none() {
             return false;
         };
-        if self.state.is_none() {
+        if self.msg.is_none() {
             return false;
         };
         true
@@ -2361,14 +8485,14 @@ impl Message for NetworkListT {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
                 1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.id = Some(tmp);
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.who = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = NetworkListT_NetworkState::new(is.read_int32());
-                    self.state = Some(tmp);
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.msg = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -2383,11 +8507,11 @@ impl Message for NetworkListT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
+        for value in self.who.iter() {
+            my_size += rt::string_size(1, *value);
         };
-        for value in self.state.iter() {
-            my_size += rt::enum_size(2, *value);
+        for value in self.msg.iter() {
+            my_size += rt::string_size(2, *value);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -2405,99 +8529,317 @@ impl Message for NetworkListT {
 }
 
 #[deriving(Clone,Eq)]
-pub enum NetworkListT_NetworkState {
-    NetworkDisconnected = 0,
-    NetworkConnecting = 1,
-    NetworkConnected = 2,
+pub struct NetworkConfigurationT {
+    server: Option<~str>,
+    nickname: Option<~str>,
+    service_auth: Option<ServiceAuthT>,
+    identity_id: Option<u64>,
+    proxy: Option<ProxyT>,
+    bind_address: Option<~str>,
+    webirc: Option<WebIrcT>,
 }
 
-impl NetworkListT_NetworkState {
-    pub fn new(value: i32) -> NetworkListT_NetworkState {
-        match value {
-            0 => NetworkDisconnected,
-            1 => NetworkConnecting,
-            2 => NetworkConnected,
-            _ => fail!()
+impl<'a> NetworkConfigurationT {
+    pub fn new() -> NetworkConfigurationT {
+        NetworkConfigurationT {
+            server: None,
+            nickname: None,
+            service_auth: None,
+            identity_id: None,
+            proxy: None,
+            bind_address: None,
+            webirc: None,
+        }
+    }
+
+    pub fn default_instance() -> &'static NetworkConfigurationT {
+        static instance: NetworkConfigurationT = NetworkConfigurationT {
+            server: None,
+            nickname: None,
+            service_auth: None,
+            identity_id: None,
+            proxy: None,
+            bind_address: None,
+            webirc: None,
+        };
+        &'static instance
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        match self.server {
+            Some(ref v) => {
+                os.write_string(1, *v);
+            },
+            None => {},
+        };
+        match self.nickname {
+            Some(ref v) => {
+                os.write_string(2, *v);
+            },
+            None => {},
+        };
+        match self.service_auth {
+            Some(ref v) => {
+                os.write_tag(3, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.identity_id {
+            Some(ref v) => {
+                os.write_uint64(4, *v);
+            },
+            None => {},
+        };
+        match self.proxy {
+            Some(ref v) => {
+                os.write_tag(5, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+        match self.bind_address {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
+        match self.webirc {
+            Some(ref v) => {
+                os.write_tag(7, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+            },
+            None => {},
+        };
+    }
+
+    pub fn clear_server(&mut self) {
+        self.server = None;
+    }
+
+    pub fn has_server(&self) -> bool {
+        self.server.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server(&mut self, v: ~str) {
+        self.server = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_server(&'a mut self) -> &'a mut ~str {
+        if self.server.is_none() {
+            self.server = Some(~"");
+        };
+        self.server.get_mut_ref()
+    }
+
+    pub fn get_server(&'a self) -> &'a str {
+        match self.server {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_nickname(&mut self) {
+        self.nickname = None;
+    }
+
+    pub fn has_nickname(&self) -> bool {
+        self.nickname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nickname(&mut self, v: ~str) {
+        self.nickname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
+        if self.nickname.is_none() {
+            self.nickname = Some(~"");
+        };
+        self.nickname.get_mut_ref()
+    }
+
+    pub fn get_nickname(&'a self) -> &'a str {
+        match self.nickname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_service_auth(&mut self) {
+        self.service_auth = None;
+    }
+
+    pub fn has_service_auth(&self) -> bool {
+        self.service_auth.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_auth(&mut self, v: ServiceAuthT) {
+        self.service_auth = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_auth(&'a mut self) -> &'a mut ServiceAuthT {
+        if self.service_auth.is_none() {
+            self.service_auth = Some(ServiceAuthT::new());
+        };
+        self.service_auth.get_mut_ref()
+    }
+
+    pub fn get_service_auth(&'a self) -> &'a ServiceAuthT {
+        match self.service_auth {
+            Some(ref v) => v,
+            None => ServiceAuthT::default_instance(),
         }
     }
-}
 
-impl ProtobufEnum for NetworkListT_NetworkState {
-    fn value(&self) -> i32 {
-        *self as i32
+    pub fn clear_identity_id(&mut self) {
+        self.identity_id = None;
+    }
+
+    pub fn has_identity_id(&self) -> bool {
+        self.identity_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identity_id(&mut self, v: u64) {
+        self.identity_id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identity_id(&'a mut self) -> &'a mut u64 {
+        if self.identity_id.is_none() {
+            self.identity_id = Some(0);
+        };
+        self.identity_id.get_mut_ref()
+    }
+
+    pub fn get_identity_id(&self) -> u64 {
+        self.identity_id.unwrap_or(0)
+    }
+
+    pub fn clear_proxy(&mut self) {
+        self.proxy = None;
     }
-}
 
-#[deriving(Clone,Eq)]
-pub struct DisconnectedT {
-    reason: Option<~str>,
-}
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
 
-impl<'a> DisconnectedT {
-    pub fn new() -> DisconnectedT {
-        DisconnectedT {
-            reason: None,
-        }
+    // Param is passed by value, moved
+    pub fn set_proxy(&mut self, v: ProxyT) {
+        self.proxy = Some(v);
     }
 
-    pub fn default_instance() -> &'static DisconnectedT {
-        static instance: DisconnectedT = DisconnectedT {
-            reason: None,
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proxy(&'a mut self) -> &'a mut ProxyT {
+        if self.proxy.is_none() {
+            self.proxy = Some(ProxyT::new());
         };
-        &'static instance
+        self.proxy.get_mut_ref()
     }
 
-    #[allow(unused_variable)]
-    pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.reason {
-            Some(ref v) => {
-                os.write_string(1, *v);
-            },
-            None => {},
-        };
+    pub fn get_proxy(&'a self) -> &'a ProxyT {
+        match self.proxy {
+            Some(ref v) => v,
+            None => ProxyT::default_instance(),
+        }
     }
 
-    pub fn clear_reason(&mut self) {
-        self.reason = None;
+    pub fn clear_bind_address(&mut self) {
+        self.bind_address = None;
     }
 
-    pub fn has_reason(&self) -> bool {
-        self.reason.is_some()
+    pub fn has_bind_address(&self) -> bool {
+        self.bind_address.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_reason(&mut self, v: ~str) {
-        self.reason = Some(v);
+    pub fn set_bind_address(&mut self, v: ~str) {
+        self.bind_address = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_reason(&'a mut self) -> &'a mut ~str {
-        if self.reason.is_none() {
-            self.reason = Some(~"");
+    pub fn mut_bind_address(&'a mut self) -> &'a mut ~str {
+        if self.bind_address.is_none() {
+            self.bind_address = Some(~"");
         };
-        self.reason.get_mut_ref()
+        self.bind_address.get_mut_ref()
     }
 
-    pub fn get_reason(&'a self) -> &'a str {
-        match self.reason {
+    pub fn get_bind_address(&'a self) -> &'a str {
+        match self.bind_address {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
+
+    pub fn clear_webirc(&mut self) {
+        self.webirc = None;
+    }
+
+    pub fn has_webirc(&self) -> bool {
+        self.webirc.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webirc(&mut self, v: WebIrcT) {
+        self.webirc = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_webirc(&'a mut self) -> &'a mut WebIrcT {
+        if self.webirc.is_none() {
+            self.webirc = Some(WebIrcT::new());
+        };
+        self.webirc.get_mut_ref()
+    }
+
+    pub fn get_webirc(&'a self) -> &'a WebIrcT {
+        match self.webirc {
+            Some(ref v) => v,
+            None => WebIrcT::default_instance(),
+        }
+    }
 }
 
-impl Message for DisconnectedT {
-    fn new() -> DisconnectedT {
-        DisconnectedT::new()
+impl Message for NetworkConfigurationT {
+    fn new() -> NetworkConfigurationT {
+        NetworkConfigurationT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_reason();
+        self.clear_server();
+        self.clear_nickname();
+        self.clear_service_auth();
+        self.clear_identity_id();
+        self.clear_proxy();
+        self.clear_bind_address();
+        self.clear_webirc();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.reason.is_none() {
+        if self.server.is_none() {
+            return false;
+        };
+        if self.nickname.is_none() {
             return false;
         };
         true
@@ -2510,7 +8852,40 @@ impl Message for DisconnectedT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.reason = Some(tmp);
+                    self.server = Some(tmp);
+                },
+                2 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.nickname = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ServiceAuthT::new();
+                    is.merge_message(&mut tmp);
+                    self.service_auth = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_uint64();
+                    self.identity_id = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = ProxyT::new();
+                    is.merge_message(&mut tmp);
+                    self.proxy = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.bind_address = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let mut tmp = WebIrcT::new();
+                    is.merge_message(&mut tmp);
+                    self.webirc = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -2525,9 +8900,30 @@ impl Message for DisconnectedT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.reason.iter() {
+        for value in self.server.iter() {
             my_size += rt::string_size(1, *value);
         };
+        for value in self.nickname.iter() {
+            my_size += rt::string_size(2, *value);
+        };
+        for value in self.service_auth.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.identity_id.iter() {
+            my_size += rt::value_size(4, *value, wire_format::WireTypeVarint);
+        };
+        for value in self.proxy.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.bind_address.iter() {
+            my_size += rt::string_size(6, *value);
+        };
+        for value in self.webirc.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        };
         sizes[pos] = my_size;
         // value is returned for convenience
         my_size
@@ -2544,114 +8940,338 @@ impl Message for DisconnectedT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct BufferRole {
-    buffer_type: Option<BufferRole_Type>,
-    name: Option<~str>,
+pub struct IdentityT {
+    id: Option<u64>,
+    ident: Option<~str>,
+    realname: Option<~str>,
+    alternate_nicks: ~[~str],
+    quit_message: Option<~str>,
+    part_message: Option<~str>,
+    away_message: Option<~str>,
+    auto_away: Option<bool>,
 }
 
-impl<'a> BufferRole {
-    pub fn new() -> BufferRole {
-        BufferRole {
-            buffer_type: None,
-            name: None,
+impl<'a> IdentityT {
+    pub fn new() -> IdentityT {
+        IdentityT {
+            id: None,
+            ident: None,
+            realname: None,
+            alternate_nicks: ~[],
+            quit_message: None,
+            part_message: None,
+            away_message: None,
+            auto_away: None,
         }
     }
 
-    pub fn default_instance() -> &'static BufferRole {
-        static instance: BufferRole = BufferRole {
-            buffer_type: None,
-            name: None,
-        };
-        &'static instance
+    pub fn default_instance() -> &'static IdentityT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: IdentityT = IdentityT {
+//             id: None,
+//             ident: None,
+//             realname: None,
+//             alternate_nicks: ~[],
+//             quit_message: None,
+//             part_message: None,
+//             away_message: None,
+//             auto_away: None,
+//         };
+//         &'static instance
+        fail!("TODO");
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.buffer_type {
+        match self.id {
             Some(ref v) => {
-                os.write_enum(1, *v as i32);
+                os.write_uint64(1, *v);
             },
             None => {},
         };
-        match self.name {
+        match self.ident {
             Some(ref v) => {
                 os.write_string(2, *v);
             },
             None => {},
         };
+        match self.realname {
+            Some(ref v) => {
+                os.write_string(3, *v);
+            },
+            None => {},
+        };
+        for v in self.alternate_nicks.iter() {
+            os.write_string(4, *v);
+        };
+        match self.quit_message {
+            Some(ref v) => {
+                os.write_string(5, *v);
+            },
+            None => {},
+        };
+        match self.part_message {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
+        match self.away_message {
+            Some(ref v) => {
+                os.write_string(7, *v);
+            },
+            None => {},
+        };
+        match self.auto_away {
+            Some(ref v) => {
+                os.write_bool(8, *v);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_buffer_type(&mut self) {
-        self.buffer_type = None;
+    pub fn clear_id(&mut self) {
+        self.id = None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&'a mut self) -> &'a mut u64 {
+        if self.id.is_none() {
+            self.id = Some(0);
+        };
+        self.id.get_mut_ref()
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    pub fn clear_ident(&mut self) {
+        self.ident = None;
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: ~str) {
+        self.ident = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&'a mut self) -> &'a mut ~str {
+        if self.ident.is_none() {
+            self.ident = Some(~"");
+        };
+        self.ident.get_mut_ref()
+    }
+
+    pub fn get_ident(&'a self) -> &'a str {
+        match self.ident {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_realname(&mut self) {
+        self.realname = None;
+    }
+
+    pub fn has_realname(&self) -> bool {
+        self.realname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_realname(&mut self, v: ~str) {
+        self.realname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_realname(&'a mut self) -> &'a mut ~str {
+        if self.realname.is_none() {
+            self.realname = Some(~"");
+        };
+        self.realname.get_mut_ref()
+    }
+
+    pub fn get_realname(&'a self) -> &'a str {
+        match self.realname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_alternate_nicks(&mut self) {
+        self.alternate_nicks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_alternate_nicks(&mut self, v: ~[~str]) {
+        self.alternate_nicks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_alternate_nicks(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.alternate_nicks
+    }
+
+    pub fn get_alternate_nicks(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.alternate_nicks)
+    }
+
+    pub fn add_alternate_nicks(&mut self, v: ~str) {
+        self.alternate_nicks.push(v);
+    }
+
+    pub fn clear_quit_message(&mut self) {
+        self.quit_message = None;
+    }
+
+    pub fn has_quit_message(&self) -> bool {
+        self.quit_message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quit_message(&mut self, v: ~str) {
+        self.quit_message = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_quit_message(&'a mut self) -> &'a mut ~str {
+        if self.quit_message.is_none() {
+            self.quit_message = Some(~"");
+        };
+        self.quit_message.get_mut_ref()
+    }
+
+    pub fn get_quit_message(&'a self) -> &'a str {
+        match self.quit_message {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_part_message(&mut self) {
+        self.part_message = None;
     }
 
-    pub fn has_buffer_type(&self) -> bool {
-        self.buffer_type.is_some()
+    pub fn has_part_message(&self) -> bool {
+        self.part_message.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_buffer_type(&mut self, v: BufferRole_Type) {
-        self.buffer_type = Some(v);
+    pub fn set_part_message(&mut self, v: ~str) {
+        self.part_message = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_buffer_type(&'a mut self) -> &'a mut BufferRole_Type {
-        if self.buffer_type.is_none() {
-            self.buffer_type = Some(BufferRole_Type::new(0));
+    pub fn mut_part_message(&'a mut self) -> &'a mut ~str {
+        if self.part_message.is_none() {
+            self.part_message = Some(~"");
         };
-        self.buffer_type.get_mut_ref()
+        self.part_message.get_mut_ref()
     }
 
-    pub fn get_buffer_type(&self) -> BufferRole_Type {
-        self.buffer_type.unwrap_or(BufferRole_Type::new(0))
+    pub fn get_part_message(&'a self) -> &'a str {
+        match self.part_message {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
     }
 
-    pub fn clear_name(&mut self) {
-        self.name = None;
+    pub fn clear_away_message(&mut self) {
+        self.away_message = None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_away_message(&self) -> bool {
+        self.away_message.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ~str) {
-        self.name = Some(v);
+    pub fn set_away_message(&mut self, v: ~str) {
+        self.away_message = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&'a mut self) -> &'a mut ~str {
-        if self.name.is_none() {
-            self.name = Some(~"");
+    pub fn mut_away_message(&'a mut self) -> &'a mut ~str {
+        if self.away_message.is_none() {
+            self.away_message = Some(~"");
         };
-        self.name.get_mut_ref()
+        self.away_message.get_mut_ref()
     }
 
-    pub fn get_name(&'a self) -> &'a str {
-        match self.name {
+    pub fn get_away_message(&'a self) -> &'a str {
+        match self.away_message {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
+
+    pub fn clear_auto_away(&mut self) {
+        self.auto_away = None;
+    }
+
+    pub fn has_auto_away(&self) -> bool {
+        self.auto_away.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auto_away(&mut self, v: bool) {
+        self.auto_away = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_auto_away(&'a mut self) -> &'a mut bool {
+        if self.auto_away.is_none() {
+            self.auto_away = Some(false);
+        };
+        self.auto_away.get_mut_ref()
+    }
+
+    pub fn get_auto_away(&self) -> bool {
+        self.auto_away.unwrap_or(false)
+    }
 }
 
-impl Message for BufferRole {
-    fn new() -> BufferRole {
-        BufferRole::new()
+impl Message for IdentityT {
+    fn new() -> IdentityT {
+        IdentityT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_buffer_type();
-        self.clear_name();
+        self.clear_id();
+        self.clear_ident();
+        self.clear_realname();
+        self.clear_alternate_nicks();
+        self.clear_quit_message();
+        self.clear_part_message();
+        self.clear_away_message();
+        self.clear_auto_away();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.buffer_type.is_none() {
+        if self.ident.is_none() {
             return false;
         };
-        if self.name.is_none() {
+        if self.realname.is_none() {
             return false;
         };
         true
@@ -2663,13 +9283,43 @@ impl Message for BufferRole {
             match field_number {
                 1 => {
                     assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = BufferRole_Type::new(is.read_int32());
-                    self.buffer_type = Some(tmp);
+                    let tmp = is.read_uint64();
+                    self.id = Some(tmp);
                 },
                 2 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.name = Some(tmp);
+                    self.ident = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.realname = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.alternate_nicks.push(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.quit_message = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.part_message = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.away_message = Some(tmp);
+                },
+                8 => {
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_bool();
+                    self.auto_away = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -2684,12 +9334,30 @@ impl Message for BufferRole {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.buffer_type.iter() {
-            my_size += rt::enum_size(1, *value);
+        for value in self.id.iter() {
+            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
         };
-        for value in self.name.iter() {
+        for value in self.ident.iter() {
             my_size += rt::string_size(2, *value);
         };
+        for value in self.realname.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        for value in self.alternate_nicks.iter() {
+            my_size += rt::string_size(4, *value);
+        };
+        for value in self.quit_message.iter() {
+            my_size += rt::string_size(5, *value);
+        };
+        for value in self.part_message.iter() {
+            my_size += rt::string_size(6, *value);
+        };
+        for value in self.away_message.iter() {
+            my_size += rt::string_size(7, *value);
+        };
+        for value in self.auto_away.iter() {
+            my_size += 2;
+        };
         sizes[pos] = my_size;
         // value is returned for convenience
         my_size
@@ -2706,181 +9374,103 @@ impl Message for BufferRole {
 }
 
 #[deriving(Clone,Eq)]
-pub enum BufferRole_Type {
-    Status = 1,
-    Channel = 2,
-    Query = 3,
-}
-
-impl BufferRole_Type {
-    pub fn new(value: i32) -> BufferRole_Type {
-        match value {
-            1 => Status,
-            2 => Channel,
-            3 => Query,
-            _ => fail!()
-        }
-    }
-}
-
-impl ProtobufEnum for BufferRole_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-}
-
-#[deriving(Clone,Eq)]
-pub struct BufferListT {
-    id: Option<u64>,
-    role: Option<BufferRole>,
-    stored_messages: Option<u64>,
+pub struct PerformListT {
+    commands: ~[~str],
+    delay_ms: Option<u32>,
 }
 
-impl<'a> BufferListT {
-    pub fn new() -> BufferListT {
-        BufferListT {
-            id: None,
-            role: None,
-            stored_messages: None,
+impl<'a> PerformListT {
+    pub fn new() -> PerformListT {
+        PerformListT {
+            commands: ~[],
+            delay_ms: None,
         }
     }
 
-    pub fn default_instance() -> &'static BufferListT {
-        static instance: BufferListT = BufferListT {
-            id: None,
-            role: None,
-            stored_messages: None,
-        };
-        &'static instance
+    pub fn default_instance() -> &'static PerformListT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: PerformListT = PerformListT {
+//             commands: ~[],
+//             delay_ms: None,
+//         };
+//         &'static instance
+        fail!("TODO");
     }
 
+    #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.id {
-            Some(ref v) => {
-                os.write_uint64(1, *v);
-            },
-            None => {},
-        };
-        match self.role {
-            Some(ref v) => {
-                os.write_tag(2, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
-            },
-            None => {},
+        for v in self.commands.iter() {
+            os.write_string(1, *v);
         };
-        match self.stored_messages {
+        match self.delay_ms {
             Some(ref v) => {
-                os.write_uint64(3, *v);
+                os.write_uint32(2, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_id(&mut self) {
-        self.id = None;
-    }
-
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn clear_commands(&mut self) {
+        self.commands.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = Some(v);
+    pub fn set_commands(&mut self, v: ~[~str]) {
+        self.commands = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&'a mut self) -> &'a mut u64 {
-        if self.id.is_none() {
-            self.id = Some(0);
-        };
-        self.id.get_mut_ref()
-    }
-
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
-    }
-
-    pub fn clear_role(&mut self) {
-        self.role = None;
-    }
-
-    pub fn has_role(&self) -> bool {
-        self.role.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_role(&mut self, v: BufferRole) {
-        self.role = Some(v);
+    pub fn mut_commands(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.commands
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_role(&'a mut self) -> &'a mut BufferRole {
-        if self.role.is_none() {
-            self.role = Some(BufferRole::new());
-        };
-        self.role.get_mut_ref()
+    pub fn get_commands(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.commands)
     }
 
-    pub fn get_role(&'a self) -> &'a BufferRole {
-        match self.role {
-            Some(ref v) => v,
-            None => BufferRole::default_instance(),
-        }
+    pub fn add_commands(&mut self, v: ~str) {
+        self.commands.push(v);
     }
 
-    pub fn clear_stored_messages(&mut self) {
-        self.stored_messages = None;
+    pub fn clear_delay_ms(&mut self) {
+        self.delay_ms = None;
     }
 
-    pub fn has_stored_messages(&self) -> bool {
-        self.stored_messages.is_some()
+    pub fn has_delay_ms(&self) -> bool {
+        self.delay_ms.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_stored_messages(&mut self, v: u64) {
-        self.stored_messages = Some(v);
+    pub fn set_delay_ms(&mut self, v: u32) {
+        self.delay_ms = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_stored_messages(&'a mut self) -> &'a mut u64 {
-        if self.stored_messages.is_none() {
-            self.stored_messages = Some(0);
+    pub fn mut_delay_ms(&'a mut self) -> &'a mut u32 {
+        if self.delay_ms.is_none() {
+            self.delay_ms = Some(0);
         };
-        self.stored_messages.get_mut_ref()
+        self.delay_ms.get_mut_ref()
     }
 
-    pub fn get_stored_messages(&self) -> u64 {
-        self.stored_messages.unwrap_or(0)
+    pub fn get_delay_ms(&self) -> u32 {
+        self.delay_ms.unwrap_or(0)
     }
 }
 
-impl Message for BufferListT {
-    fn new() -> BufferListT {
-        BufferListT::new()
+impl Message for PerformListT {
+    fn new() -> PerformListT {
+        PerformListT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_role();
-        self.clear_stored_messages();
+        self.clear_commands();
+        self.clear_delay_ms();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
-        if self.role.is_none() {
-            return false;
-        };
-        if self.stored_messages.is_none() {
-            return false;
-        };
         true
     }
 
@@ -2889,20 +9479,14 @@ impl Message for BufferListT {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
                 1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.id = Some(tmp);
-                },
-                2 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = BufferRole::new();
-                    is.merge_message(&mut tmp);
-                    self.role = Some(tmp);
+                    let tmp = is.read_string();
+                    self.commands.push(tmp);
                 },
-                3 => {
+                2 => {
                     assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.stored_messages = Some(tmp);
+                    let tmp = is.read_uint32();
+                    self.delay_ms = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -2917,15 +9501,11 @@ impl Message for BufferListT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.role.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        for value in self.commands.iter() {
+            my_size += rt::string_size(1, *value);
         };
-        for value in self.stored_messages.iter() {
-            my_size += rt::value_size(3, *value, wire_format::WireTypeVarint);
+        for value in self.delay_ms.iter() {
+            my_size += rt::value_size(2, *value, wire_format::WireTypeVarint);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -2942,119 +9522,75 @@ impl Message for BufferListT {
     }
 }
 
-#[deriving(Clone,Eq)]
-pub struct NewBufferT {
-    id: Option<u64>,
-    role: Option<BufferRole>,
-}
-
-impl<'a> NewBufferT {
-    pub fn new() -> NewBufferT {
-        NewBufferT {
-            id: None,
-            role: None,
+#[deriving(Clone,Eq)]
+pub struct AwayStateT {
+    message: Option<~str>,
+}
+
+impl<'a> AwayStateT {
+    pub fn new() -> AwayStateT {
+        AwayStateT {
+            message: None,
         }
     }
 
-    pub fn default_instance() -> &'static NewBufferT {
-        static instance: NewBufferT = NewBufferT {
-            id: None,
-            role: None,
+    pub fn default_instance() -> &'static AwayStateT {
+        static instance: AwayStateT = AwayStateT {
+            message: None,
         };
         &'static instance
     }
 
+    #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.id {
-            Some(ref v) => {
-                os.write_uint64(1, *v);
-            },
-            None => {},
-        };
-        match self.role {
+        match self.message {
             Some(ref v) => {
-                os.write_tag(2, wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                os.write_string(1, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_id(&mut self) {
-        self.id = None;
-    }
-
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = Some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&'a mut self) -> &'a mut u64 {
-        if self.id.is_none() {
-            self.id = Some(0);
-        };
-        self.id.get_mut_ref()
-    }
-
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
-    }
-
-    pub fn clear_role(&mut self) {
-        self.role = None;
+    pub fn clear_message(&mut self) {
+        self.message = None;
     }
 
-    pub fn has_role(&self) -> bool {
-        self.role.is_some()
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_role(&mut self, v: BufferRole) {
-        self.role = Some(v);
+    pub fn set_message(&mut self, v: ~str) {
+        self.message = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_role(&'a mut self) -> &'a mut BufferRole {
-        if self.role.is_none() {
-            self.role = Some(BufferRole::new());
+    pub fn mut_message(&'a mut self) -> &'a mut ~str {
+        if self.message.is_none() {
+            self.message = Some(~"");
         };
-        self.role.get_mut_ref()
+        self.message.get_mut_ref()
     }
 
-    pub fn get_role(&'a self) -> &'a BufferRole {
-        match self.role {
-            Some(ref v) => v,
-            None => BufferRole::default_instance(),
+    pub fn get_message(&'a self) -> &'a str {
+        match self.message {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
         }
     }
 }
 
-impl Message for NewBufferT {
-    fn new() -> NewBufferT {
-        NewBufferT::new()
+impl Message for AwayStateT {
+    fn new() -> AwayStateT {
+        AwayStateT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_role();
+        self.clear_message();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
-        if self.role.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3063,15 +9599,9 @@ impl Message for NewBufferT {
             let (field_number, wire_type) = is.read_tag_unpack();
             match field_number {
                 1 => {
-                    assert_eq!(wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
-                    self.id = Some(tmp);
-                },
-                2 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let mut tmp = BufferRole::new();
-                    is.merge_message(&mut tmp);
-                    self.role = Some(tmp);
+                    let tmp = is.read_string();
+                    self.message = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3086,12 +9616,8 @@ impl Message for NewBufferT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += rt::value_size(1, *value, wire_format::WireTypeVarint);
-        };
-        for value in self.role.iter() {
-            let len = value.compute_sizes(sizes);
-            my_size += 1 + rt::compute_raw_varint32_size(len) + len;
+        for value in self.message.iter() {
+            my_size += rt::string_size(1, *value);
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -3109,27 +9635,27 @@ impl Message for NewBufferT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct InformationT {
-    msg: Option<~str>,
+pub struct SendCommandT {
+    line: Option<~str>,
 }
 
-impl<'a> InformationT {
-    pub fn new() -> InformationT {
-        InformationT {
-            msg: None,
+impl<'a> SendCommandT {
+    pub fn new() -> SendCommandT {
+        SendCommandT {
+            line: None,
         }
     }
 
-    pub fn default_instance() -> &'static InformationT {
-        static instance: InformationT = InformationT {
-            msg: None,
+    pub fn default_instance() -> &'static SendCommandT {
+        static instance: SendCommandT = SendCommandT {
+            line: None,
         };
         &'static instance
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.msg {
+        match self.line {
             Some(ref v) => {
                 os.write_string(1, *v);
             },
@@ -3137,47 +9663,47 @@ impl<'a> InformationT {
         };
     }
 
-    pub fn clear_msg(&mut self) {
-        self.msg = None;
+    pub fn clear_line(&mut self) {
+        self.line = None;
     }
 
-    pub fn has_msg(&self) -> bool {
-        self.msg.is_some()
+    pub fn has_line(&self) -> bool {
+        self.line.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_msg(&mut self, v: ~str) {
-        self.msg = Some(v);
+    pub fn set_line(&mut self, v: ~str) {
+        self.line = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
-        if self.msg.is_none() {
-            self.msg = Some(~"");
+    pub fn mut_line(&'a mut self) -> &'a mut ~str {
+        if self.line.is_none() {
+            self.line = Some(~"");
         };
-        self.msg.get_mut_ref()
+        self.line.get_mut_ref()
     }
 
-    pub fn get_msg(&'a self) -> &'a str {
-        match self.msg {
+    pub fn get_line(&'a self) -> &'a str {
+        match self.line {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
 }
 
-impl Message for InformationT {
-    fn new() -> InformationT {
-        InformationT::new()
+impl Message for SendCommandT {
+    fn new() -> SendCommandT {
+        SendCommandT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_msg();
+        self.clear_line();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.msg.is_none() {
+        if self.line.is_none() {
             return false;
         };
         true
@@ -3190,7 +9716,7 @@ impl Message for InformationT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.msg = Some(tmp);
+                    self.line = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3205,7 +9731,7 @@ impl Message for InformationT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.msg.iter() {
+        for value in self.line.iter() {
             my_size += rt::string_size(1, *value);
         };
         sizes[pos] = my_size;
@@ -3224,77 +9750,67 @@ impl Message for InformationT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct ErrorT {
-    msg: Option<~str>,
+pub struct CommandReplyT {
+    lines: ~[~str],
 }
 
-impl<'a> ErrorT {
-    pub fn new() -> ErrorT {
-        ErrorT {
-            msg: None,
+impl<'a> CommandReplyT {
+    pub fn new() -> CommandReplyT {
+        CommandReplyT {
+            lines: ~[],
         }
     }
 
-    pub fn default_instance() -> &'static ErrorT {
-        static instance: ErrorT = ErrorT {
-            msg: None,
-        };
-        &'static instance
+    pub fn default_instance() -> &'static CommandReplyT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: CommandReplyT = CommandReplyT {
+//             lines: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.msg {
-            Some(ref v) => {
-                os.write_string(1, *v);
-            },
-            None => {},
+        for v in self.lines.iter() {
+            os.write_string(1, *v);
         };
     }
 
-    pub fn clear_msg(&mut self) {
-        self.msg = None;
-    }
-
-    pub fn has_msg(&self) -> bool {
-        self.msg.is_some()
+    pub fn clear_lines(&mut self) {
+        self.lines.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_msg(&mut self, v: ~str) {
-        self.msg = Some(v);
+    pub fn set_lines(&mut self, v: ~[~str]) {
+        self.lines = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
-        if self.msg.is_none() {
-            self.msg = Some(~"");
-        };
-        self.msg.get_mut_ref()
+    pub fn mut_lines(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.lines
     }
 
-    pub fn get_msg(&'a self) -> &'a str {
-        match self.msg {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_lines(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.lines)
+    }
+
+    pub fn add_lines(&mut self, v: ~str) {
+        self.lines.push(v);
     }
 }
 
-impl Message for ErrorT {
-    fn new() -> ErrorT {
-        ErrorT::new()
+impl Message for CommandReplyT {
+    fn new() -> CommandReplyT {
+        CommandReplyT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_msg();
+        self.clear_lines();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.msg.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3305,7 +9821,7 @@ impl Message for ErrorT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.msg = Some(tmp);
+                    self.lines.push(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3320,7 +9836,7 @@ impl Message for ErrorT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.msg.iter() {
+        for value in self.lines.iter() {
             my_size += rt::string_size(1, *value);
         };
         sizes[pos] = my_size;
@@ -3339,77 +9855,67 @@ impl Message for ErrorT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct JoinT {
-    who: Option<~str>,
+pub struct BuddyListT {
+    nicks: ~[~str],
 }
 
-impl<'a> JoinT {
-    pub fn new() -> JoinT {
-        JoinT {
-            who: None,
+impl<'a> BuddyListT {
+    pub fn new() -> BuddyListT {
+        BuddyListT {
+            nicks: ~[],
         }
     }
 
-    pub fn default_instance() -> &'static JoinT {
-        static instance: JoinT = JoinT {
-            who: None,
-        };
-        &'static instance
+    pub fn default_instance() -> &'static BuddyListT {
+//         // doesn't work, because rust doen't implement static constants of types like ~str
+//         // https://github.com/mozilla/rust/issues/8406
+//         static instance: BuddyListT = BuddyListT {
+//             nicks: ~[],
+//         };
+//         &'static instance
+        fail!("TODO");
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.who {
-            Some(ref v) => {
-                os.write_string(1, *v);
-            },
-            None => {},
+        for v in self.nicks.iter() {
+            os.write_string(1, *v);
         };
     }
 
-    pub fn clear_who(&mut self) {
-        self.who = None;
-    }
-
-    pub fn has_who(&self) -> bool {
-        self.who.is_some()
+    pub fn clear_nicks(&mut self) {
+        self.nicks.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_who(&mut self, v: ~str) {
-        self.who = Some(v);
+    pub fn set_nicks(&mut self, v: ~[~str]) {
+        self.nicks = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_who(&'a mut self) -> &'a mut ~str {
-        if self.who.is_none() {
-            self.who = Some(~"");
-        };
-        self.who.get_mut_ref()
+    pub fn mut_nicks(&'a mut self) -> &'a mut ~[~str] {
+        &mut self.nicks
     }
 
-    pub fn get_who(&'a self) -> &'a str {
-        match self.who {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_nicks(&'a self) -> &'a [~str] {
+        rt::as_slice_tmp(&self.nicks)
+    }
+
+    pub fn add_nicks(&mut self, v: ~str) {
+        self.nicks.push(v);
     }
 }
 
-impl Message for JoinT {
-    fn new() -> JoinT {
-        JoinT::new()
+impl Message for BuddyListT {
+    fn new() -> BuddyListT {
+        BuddyListT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_who();
+        self.clear_nicks();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.who.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3420,7 +9926,7 @@ impl Message for JoinT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.who = Some(tmp);
+                    self.nicks.push(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3435,7 +9941,7 @@ impl Message for JoinT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.who.iter() {
+        for value in self.nicks.iter() {
             my_size += rt::string_size(1, *value);
         };
         sizes[pos] = my_size;
@@ -3454,117 +9960,114 @@ impl Message for JoinT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct PrivmsgT {
-    who: Option<~str>,
-    msg: Option<~str>,
+pub struct PresenceT {
+    nick: Option<~str>,
+    online: Option<bool>,
 }
 
-impl<'a> PrivmsgT {
-    pub fn new() -> PrivmsgT {
-        PrivmsgT {
-            who: None,
-            msg: None,
+impl<'a> PresenceT {
+    pub fn new() -> PresenceT {
+        PresenceT {
+            nick: None,
+            online: None,
         }
     }
 
-    pub fn default_instance() -> &'static PrivmsgT {
-        static instance: PrivmsgT = PrivmsgT {
-            who: None,
-            msg: None,
+    pub fn default_instance() -> &'static PresenceT {
+        static instance: PresenceT = PresenceT {
+            nick: None,
+            online: None,
         };
         &'static instance
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.who {
+        match self.nick {
             Some(ref v) => {
                 os.write_string(1, *v);
             },
             None => {},
         };
-        match self.msg {
+        match self.online {
             Some(ref v) => {
-                os.write_string(2, *v);
+                os.write_bool(2, *v);
             },
             None => {},
         };
     }
 
-    pub fn clear_who(&mut self) {
-        self.who = None;
+    pub fn clear_nick(&mut self) {
+        self.nick = None;
     }
 
-    pub fn has_who(&self) -> bool {
-        self.who.is_some()
+    pub fn has_nick(&self) -> bool {
+        self.nick.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_who(&mut self, v: ~str) {
-        self.who = Some(v);
+    pub fn set_nick(&mut self, v: ~str) {
+        self.nick = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_who(&'a mut self) -> &'a mut ~str {
-        if self.who.is_none() {
-            self.who = Some(~"");
+    pub fn mut_nick(&'a mut self) -> &'a mut ~str {
+        if self.nick.is_none() {
+            self.nick = Some(~"");
         };
-        self.who.get_mut_ref()
+        self.nick.get_mut_ref()
     }
 
-    pub fn get_who(&'a self) -> &'a str {
-        match self.who {
+    pub fn get_nick(&'a self) -> &'a str {
+        match self.nick {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
 
-    pub fn clear_msg(&mut self) {
-        self.msg = None;
+    pub fn clear_online(&mut self) {
+        self.online = None;
     }
 
-    pub fn has_msg(&self) -> bool {
-        self.msg.is_some()
+    pub fn has_online(&self) -> bool {
+        self.online.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_msg(&mut self, v: ~str) {
-        self.msg = Some(v);
+    pub fn set_online(&mut self, v: bool) {
+        self.online = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_msg(&'a mut self) -> &'a mut ~str {
-        if self.msg.is_none() {
-            self.msg = Some(~"");
+    pub fn mut_online(&'a mut self) -> &'a mut bool {
+        if self.online.is_none() {
+            self.online = Some(false);
         };
-        self.msg.get_mut_ref()
+        self.online.get_mut_ref()
     }
 
-    pub fn get_msg(&'a self) -> &'a str {
-        match self.msg {
-            Some(ref v) => v.as_slice(),
-            None => &'a "",
-        }
+    pub fn get_online(&self) -> bool {
+        self.online.unwrap_or(false)
     }
 }
 
-impl Message for PrivmsgT {
-    fn new() -> PrivmsgT {
-        PrivmsgT::new()
+impl Message for PresenceT {
+    fn new() -> PresenceT {
+        PresenceT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_who();
-        self.clear_msg();
+        self.clear_nick();
+        self.clear_online();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.who.is_none() {
+        if self.nick.is_none() {
             return false;
         };
-        if self.msg.is_none() {
+        if self.online.is_none() {
             return false;
         };
         true
@@ -3577,12 +10080,12 @@ impl Message for PrivmsgT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.who = Some(tmp);
+                    self.nick = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
-                    self.msg = Some(tmp);
+                    assert_eq!(wire_format::WireTypeVarint, wire_type);
+                    let tmp = is.read_bool();
+                    self.online = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3597,11 +10100,11 @@ impl Message for PrivmsgT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.who.iter() {
+        for value in self.nick.iter() {
             my_size += rt::string_size(1, *value);
         };
-        for value in self.msg.iter() {
-            my_size += rt::string_size(2, *value);
+        for value in self.online.iter() {
+            my_size += 2;
         };
         sizes[pos] = my_size;
         // value is returned for convenience
@@ -3619,117 +10122,270 @@ impl Message for PrivmsgT {
 }
 
 #[deriving(Clone,Eq)]
-pub struct NetworkConfigurationT {
-    server: Option<~str>,
-    nickname: Option<~str>,
+pub struct UserT {
+    nick: Option<~str>,
+    ident: Option<~str>,
+    host: Option<~str>,
+    realname: Option<~str>,
+    account: Option<~str>,
+    away: Option<~str>,
 }
 
-impl<'a> NetworkConfigurationT {
-    pub fn new() -> NetworkConfigurationT {
-        NetworkConfigurationT {
-            server: None,
-            nickname: None,
+impl<'a> UserT {
+    pub fn new() -> UserT {
+        UserT {
+            nick: None,
+            ident: None,
+            host: None,
+            realname: None,
+            account: None,
+            away: None,
         }
     }
 
-    pub fn default_instance() -> &'static NetworkConfigurationT {
-        static instance: NetworkConfigurationT = NetworkConfigurationT {
-            server: None,
-            nickname: None,
+    pub fn default_instance() -> &'static UserT {
+        static instance: UserT = UserT {
+            nick: None,
+            ident: None,
+            host: None,
+            realname: None,
+            account: None,
+            away: None,
         };
         &'static instance
     }
 
     #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
-        match self.server {
+        match self.nick {
             Some(ref v) => {
                 os.write_string(1, *v);
             },
             None => {},
         };
-        match self.nickname {
+        match self.ident {
             Some(ref v) => {
                 os.write_string(2, *v);
             },
             None => {},
         };
+        match self.host {
+            Some(ref v) => {
+                os.write_string(3, *v);
+            },
+            None => {},
+        };
+        match self.realname {
+            Some(ref v) => {
+                os.write_string(4, *v);
+            },
+            None => {},
+        };
+        match self.account {
+            Some(ref v) => {
+                os.write_string(5, *v);
+            },
+            None => {},
+        };
+        match self.away {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
     }
 
-    pub fn clear_server(&mut self) {
-        self.server = None;
+    pub fn clear_nick(&mut self) {
+        self.nick = None;
     }
 
-    pub fn has_server(&self) -> bool {
-        self.server.is_some()
+    pub fn has_nick(&self) -> bool {
+        self.nick.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_server(&mut self, v: ~str) {
-        self.server = Some(v);
+    pub fn set_nick(&mut self, v: ~str) {
+        self.nick = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_server(&'a mut self) -> &'a mut ~str {
-        if self.server.is_none() {
-            self.server = Some(~"");
+    pub fn mut_nick(&'a mut self) -> &'a mut ~str {
+        if self.nick.is_none() {
+            self.nick = Some(~"");
         };
-        self.server.get_mut_ref()
+        self.nick.get_mut_ref()
     }
 
-    pub fn get_server(&'a self) -> &'a str {
-        match self.server {
+    pub fn get_nick(&'a self) -> &'a str {
+        match self.nick {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
 
-    pub fn clear_nickname(&mut self) {
-        self.nickname = None;
+    pub fn clear_ident(&mut self) {
+        self.ident = None;
     }
 
-    pub fn has_nickname(&self) -> bool {
-        self.nickname.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_nickname(&mut self, v: ~str) {
-        self.nickname = Some(v);
+    pub fn set_ident(&mut self, v: ~str) {
+        self.ident = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_nickname(&'a mut self) -> &'a mut ~str {
-        if self.nickname.is_none() {
-            self.nickname = Some(~"");
+    pub fn mut_ident(&'a mut self) -> &'a mut ~str {
+        if self.ident.is_none() {
+            self.ident = Some(~"");
         };
-        self.nickname.get_mut_ref()
+        self.ident.get_mut_ref()
     }
 
-    pub fn get_nickname(&'a self) -> &'a str {
-        match self.nickname {
+    pub fn get_ident(&'a self) -> &'a str {
+        match self.ident {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_host(&mut self) {
+        self.host = None;
+    }
+
+    pub fn has_host(&self) -> bool {
+        self.host.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host(&mut self, v: ~str) {
+        self.host = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host(&'a mut self) -> &'a mut ~str {
+        if self.host.is_none() {
+            self.host = Some(~"");
+        };
+        self.host.get_mut_ref()
+    }
+
+    pub fn get_host(&'a self) -> &'a str {
+        match self.host {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_realname(&mut self) {
+        self.realname = None;
+    }
+
+    pub fn has_realname(&self) -> bool {
+        self.realname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_realname(&mut self, v: ~str) {
+        self.realname = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_realname(&'a mut self) -> &'a mut ~str {
+        if self.realname.is_none() {
+            self.realname = Some(~"");
+        };
+        self.realname.get_mut_ref()
+    }
+
+    pub fn get_realname(&'a self) -> &'a str {
+        match self.realname {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_account(&mut self) {
+        self.account = None;
+    }
+
+    pub fn has_account(&self) -> bool {
+        self.account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account(&mut self, v: ~str) {
+        self.account = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account(&'a mut self) -> &'a mut ~str {
+        if self.account.is_none() {
+            self.account = Some(~"");
+        };
+        self.account.get_mut_ref()
+    }
+
+    pub fn get_account(&'a self) -> &'a str {
+        match self.account {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_away(&mut self) {
+        self.away = None;
+    }
+
+    pub fn has_away(&self) -> bool {
+        self.away.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_away(&mut self, v: ~str) {
+        self.away = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_away(&'a mut self) -> &'a mut ~str {
+        if self.away.is_none() {
+            self.away = Some(~"");
+        };
+        self.away.get_mut_ref()
+    }
+
+    pub fn get_away(&'a self) -> &'a str {
+        match self.away {
             Some(ref v) => v.as_slice(),
             None => &'a "",
         }
     }
 }
 
-impl Message for NetworkConfigurationT {
-    fn new() -> NetworkConfigurationT {
-        NetworkConfigurationT::new()
+impl Message for UserT {
+    fn new() -> UserT {
+        UserT::new()
     }
 
     fn clear(&mut self) {
-        self.clear_server();
-        self.clear_nickname();
+        self.clear_nick();
+        self.clear_ident();
+        self.clear_host();
+        self.clear_realname();
+        self.clear_account();
+        self.clear_away();
     }
 
     fn is_initialized(&self) -> bool {
-        if self.server.is_none() {
-            return false;
-        };
-        if self.nickname.is_none() {
+        if self.nick.is_none() {
             return false;
         };
         true
@@ -3742,12 +10398,32 @@ impl Message for NetworkConfigurationT {
                 1 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.server = Some(tmp);
+                    self.nick = Some(tmp);
                 },
                 2 => {
                     assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
                     let tmp = is.read_string();
-                    self.nickname = Some(tmp);
+                    self.ident = Some(tmp);
+                },
+                3 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.host = Some(tmp);
+                },
+                4 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.realname = Some(tmp);
+                },
+                5 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.account = Some(tmp);
+                },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.away = Some(tmp);
                 },
                 _ => {
                     // TODO: store in unknown fields
@@ -3762,12 +10438,24 @@ impl Message for NetworkConfigurationT {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.server.iter() {
+        for value in self.nick.iter() {
             my_size += rt::string_size(1, *value);
         };
-        for value in self.nickname.iter() {
+        for value in self.ident.iter() {
             my_size += rt::string_size(2, *value);
         };
+        for value in self.host.iter() {
+            my_size += rt::string_size(3, *value);
+        };
+        for value in self.realname.iter() {
+            my_size += rt::string_size(4, *value);
+        };
+        for value in self.account.iter() {
+            my_size += rt::string_size(5, *value);
+        };
+        for value in self.away.iter() {
+            my_size += rt::string_size(6, *value);
+        };
         sizes[pos] = my_size;
         // value is returned for convenience
         my_size
@@ -3790,6 +10478,9 @@ pub struct MessageRangeT {
     information: Option<InformationT>,
     join: Option<JoinT>,
     privmsg: Option<PrivmsgT>,
+    msgid: Option<~str>,
+    account: Option<~str>,
+    prefix: Option<~str>,
 }
 
 impl<'a> MessageRangeT {
@@ -3800,6 +10491,9 @@ impl<'a> MessageRangeT {
             information: None,
             join: None,
             privmsg: None,
+            msgid: None,
+            account: None,
+            prefix: None,
         }
     }
 
@@ -3810,6 +10504,9 @@ impl<'a> MessageRangeT {
             information: None,
             join: None,
             privmsg: None,
+            msgid: None,
+            account: None,
+            prefix: None,
         };
         &'static instance
     }
@@ -3854,6 +10551,24 @@ impl<'a> MessageRangeT {
             },
             None => {},
         };
+        match self.msgid {
+            Some(ref v) => {
+                os.write_string(6, *v);
+            },
+            None => {},
+        };
+        match self.account {
+            Some(ref v) => {
+                os.write_string(7, *v);
+            },
+            None => {},
+        };
+        match self.prefix {
+            Some(ref v) => {
+                os.write_string(8, *v);
+            },
+            None => {},
+        };
     }
 
     pub fn clear_id(&mut self) {
@@ -3994,6 +10709,93 @@ impl<'a> MessageRangeT {
             None => PrivmsgT::default_instance(),
         }
     }
+
+    pub fn clear_msgid(&mut self) {
+        self.msgid = None;
+    }
+
+    pub fn has_msgid(&self) -> bool {
+        self.msgid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msgid(&mut self, v: ~str) {
+        self.msgid = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msgid(&'a mut self) -> &'a mut ~str {
+        if self.msgid.is_none() {
+            self.msgid = Some(~"");
+        };
+        self.msgid.get_mut_ref()
+    }
+
+    pub fn get_msgid(&'a self) -> &'a str {
+        match self.msgid {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_account(&mut self) {
+        self.account = None;
+    }
+
+    pub fn has_account(&self) -> bool {
+        self.account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account(&mut self, v: ~str) {
+        self.account = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account(&'a mut self) -> &'a mut ~str {
+        if self.account.is_none() {
+            self.account = Some(~"");
+        };
+        self.account.get_mut_ref()
+    }
+
+    pub fn get_account(&'a self) -> &'a str {
+        match self.account {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
+
+    pub fn clear_prefix(&mut self) {
+        self.prefix = None;
+    }
+
+    pub fn has_prefix(&self) -> bool {
+        self.prefix.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: ~str) {
+        self.prefix = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&'a mut self) -> &'a mut ~str {
+        if self.prefix.is_none() {
+            self.prefix = Some(~"");
+        };
+        self.prefix.get_mut_ref()
+    }
+
+    pub fn get_prefix(&'a self) -> &'a str {
+        match self.prefix {
+            Some(ref v) => v.as_slice(),
+            None => &'a "",
+        }
+    }
 }
 
 impl Message for MessageRangeT {
@@ -4007,6 +10809,9 @@ impl Message for MessageRangeT {
         self.clear_information();
         self.clear_join();
         self.clear_privmsg();
+        self.clear_msgid();
+        self.clear_account();
+        self.clear_prefix();
     }
 
     fn is_initialized(&self) -> bool {
@@ -4051,6 +10856,21 @@ impl Message for MessageRangeT {
                     is.merge_message(&mut tmp);
                     self.privmsg = Some(tmp);
                 },
+                6 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.msgid = Some(tmp);
+                },
+                7 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.account = Some(tmp);
+                },
+                8 => {
+                    assert_eq!(wire_format::WireTypeLengthDelimited, wire_type);
+                    let tmp = is.read_string();
+                    self.prefix = Some(tmp);
+                },
                 _ => {
                     // TODO: store in unknown fields
                     is.skip_field(wire_type);
@@ -4082,6 +10902,15 @@ impl Message for MessageRangeT {
             let len = value.compute_sizes(sizes);
             my_size += 1 + rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.msgid.iter() {
+            my_size += rt::string_size(6, *value);
+        };
+        for value in self.account.iter() {
+            my_size += rt::string_size(7, *value);
+        };
+        for value in self.prefix.iter() {
+            my_size += rt::string_size(8, *value);
+        };
         sizes[pos] = my_size;
         // value is returned for convenience
         my_size
@@ -0,0 +1,459 @@
+// JSON form of the remote protocol. Packets are transcoded to and from the protobuf wire
+// format using the schema in protocol.proto, so a JSON object has the fields of the
+// corresponding message, with enum values given by name and repeated fields as lists.
+// Numbers are JSON doubles, so 64-bit values above 2^53 lose precision.
+
+use collections::{HashMap, TreeMap};
+use serialize::json;
+use serialize::json::Json;
+use std::str;
+
+static WIRE_VARINT: u64 = 0;
+static WIRE_FIXED64: u64 = 1;
+static WIRE_LENGTH_DELIMITED: u64 = 2;
+static WIRE_FIXED32: u64 = 5;
+
+#[deriving(Clone)]
+enum Kind {
+    UInt64Field,
+    UInt32Field,
+    BoolField,
+    StringField,
+    EnumField(~str), // qualified name, e.g. RemoteCommand.Type
+    MessageField(~str)
+}
+
+struct Field {
+    name: ~str,
+    number: u64,
+    kind: Kind,
+    required: bool,
+    repeated: bool
+}
+
+enum WireValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8])
+}
+
+/// Messages and enums of a .proto file
+pub struct Schema {
+    priv messages: HashMap<~str, ~[Field]>,
+    priv enums: HashMap<~str, ~[(~str, i32)]>
+}
+
+// Splits a .proto file into words and symbols, dropping comments
+fn tokenize(proto: &str) -> ~[~str] {
+    let mut tokens = ~[];
+    let mut word = ~"";
+    let mut i = 0;
+    while i < proto.len() {
+        let c = proto.char_at(i);
+        if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' {
+            word.push_char(c);
+            i += c.len_utf8_bytes();
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(word);
+            word = ~"";
+        }
+        let rest = proto.slice_from(i);
+        if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if rest.starts_with("/*") {
+            i += rest.find_str("*/").map_or(rest.len(), |end| end + 2);
+        } else {
+            if !c.is_whitespace() {
+                tokens.push(str::from_char(c));
+            }
+            i += c.len_utf8_bytes();
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+struct Parser {
+    tokens: ~[~str],
+    pos: uint
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<~str, ~str> {
+        if self.pos >= self.tokens.len() {
+            return Err(~"unexpected end of file");
+        }
+        self.pos += 1;
+        Ok(self.tokens[self.pos - 1].clone())
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ~str> {
+        let token = try!(self.next());
+        if token.as_slice() == expected { Ok(()) } else { Err(format!("expected {}, found {}", expected, token)) }
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, ~str> {
+        let token = try!(self.next());
+        match from_str(token) {
+            Some(n) => Ok(n),
+            None => Err(format!("expected a number, found {}", token))
+        }
+    }
+
+    // Parses `{ Name = 1; ... }` after `enum Name`
+    fn enum_body(&mut self) -> Result<~[(~str, i32)], ~str> {
+        let mut values = ~[];
+        try!(self.expect("{"));
+        loop {
+            let name = try!(self.next());
+            match name.as_slice() {
+                "}" => return Ok(values),
+                ";" => continue,
+                _ => ()
+            }
+            try!(self.expect("="));
+            let value = try!(self.number());
+            try!(self.expect(";"));
+            values.push((name, value));
+        }
+    }
+}
+
+impl Schema {
+    /// Reads the messages and enums of a .proto file. Only scalar types used by the
+    /// remote protocol are supported.
+    pub fn parse(proto: &str) -> Result<Schema, ~str> {
+        let mut parser = Parser { tokens: tokenize(proto), pos: 0 };
+        let mut schema = Schema { messages: HashMap::new(), enums: HashMap::new() };
+        // Field types are resolved once everything is known
+        let mut unresolved = ~[];
+
+        while parser.pos < parser.tokens.len() {
+            match try!(parser.next()).as_slice() {
+                "package" => { try!(parser.next()); try!(parser.expect(";")); },
+                ";" => (),
+                "enum" => {
+                    let name = try!(parser.next());
+                    let values = try!(parser.enum_body());
+                    schema.enums.insert(name, values);
+                },
+                "message" => {
+                    let message = try!(parser.next());
+                    let mut fields = ~[];
+                    try!(parser.expect("{"));
+                    loop {
+                        let token = try!(parser.next());
+                        let (required, repeated) = match token.as_slice() {
+                            "}" => break,
+                            ";" => continue,
+                            "enum" => {
+                                let name = try!(parser.next());
+                                let values = try!(parser.enum_body());
+                                schema.enums.insert(format!("{}.{}", message, name), values);
+                                continue;
+                            },
+                            "required" => (true, false),
+                            "optional" => (false, false),
+                            "repeated" => (false, true),
+                            _ => return Err(format!("unexpected {} in message {}", token, message))
+                        };
+                        let type_name = try!(parser.next());
+                        let name = try!(parser.next());
+                        try!(parser.expect("="));
+                        let number = try!(parser.number());
+                        try!(parser.expect(";"));
+                        let kind = match type_name.as_slice() {
+                            "uint64" => UInt64Field,
+                            "uint32" => UInt32Field,
+                            "bool" => BoolField,
+                            "string" => StringField,
+                            _ => {
+                                unresolved.push((message.clone(), fields.len(), type_name.clone()));
+                                MessageField(type_name)
+                            }
+                        };
+                        fields.push(Field { name: name, number: number, kind: kind, required: required,
+                                            repeated: repeated });
+                    }
+                    schema.messages.insert(message, fields);
+                },
+                token => return Err(format!("unexpected {}", token))
+            }
+        }
+
+        for (message, index, type_name) in unresolved.move_iter() {
+            // Nested types shadow top-level ones
+            let nested = format!("{}.{}", message, type_name);
+            let kind = if schema.enums.contains_key(&nested) {
+                EnumField(nested)
+            } else if schema.enums.contains_key(&type_name) {
+                EnumField(type_name)
+            } else if schema.messages.contains_key(&type_name) {
+                MessageField(type_name)
+            } else {
+                return Err(format!("unknown type {} in message {}", type_name, message));
+            };
+            schema.messages.get_mut(&message)[index].kind = kind;
+        }
+        Ok(schema)
+    }
+
+    fn fields<'a>(&'a self, message: &str) -> Result<&'a [Field], ~str> {
+        match self.messages.find_equiv(&message) {
+            Some(fields) => Ok(fields.as_slice()),
+            None => Err(format!("unknown message {}", message))
+        }
+    }
+
+    /// Converts the JSON text of a `message` to protobuf.
+    pub fn encode(&self, message: &str, text: &str) -> Result<~[u8], ~str> {
+        match json::from_str(text) {
+            Ok(value) => self.to_protobuf(message, &value),
+            Err(e) => Err(format!("{}", e))
+        }
+    }
+
+    /// Converts a protobuf `message` to JSON text.
+    pub fn decode(&self, message: &str, data: &[u8]) -> Result<~str, ~str> {
+        self.to_json(message, data).map(|value| value.to_str())
+    }
+
+    /// Checks that `data` is a `message` the generated protobuf parser accepts, as it
+    /// fails the task on anything else: known fields must have their wire type and a
+    /// valid value, nested messages included, and required fields must be present.
+    pub fn validate(&self, message: &str, data: &[u8]) -> Result<(), ~str> {
+        let fields = try!(self.fields(message));
+        let mut seen = ~[];
+        let mut pos = 0;
+        while pos < data.len() {
+            let key = try!(read_varint(data, &mut pos));
+            let field = fields.iter().find(|f| f.number == key >> 3);
+            let wire = match key & 7 {
+                WIRE_VARINT => Varint(try!(read_varint(data, &mut pos))),
+                WIRE_LENGTH_DELIMITED => {
+                    let len = try!(read_varint(data, &mut pos));
+                    if len > (data.len() - pos) as u64 {
+                        return Err(~"truncated field");
+                    }
+                    pos += len as uint;
+                    Bytes(data.slice(pos - len as uint, pos))
+                },
+                // Unknown fields are skipped
+                WIRE_FIXED64 | WIRE_FIXED32 if field.is_none() => {
+                    pos += if key & 7 == WIRE_FIXED64 { 8 } else { 4 };
+                    if pos > data.len() {
+                        return Err(~"truncated field");
+                    }
+                    continue;
+                },
+                wire_type => return Err(format!("unsupported wire type {}", wire_type))
+            };
+            let field = match field {
+                Some(field) => field,
+                None => continue
+            };
+            let valid = match (&field.kind, wire) {
+                (&UInt64Field, Varint(_)) | (&BoolField, Varint(_)) => true,
+                (&UInt32Field, Varint(v)) => v <= ::std::u32::MAX as u64,
+                (&EnumField(ref name), Varint(v)) =>
+                    self.enums.get(name).iter().any(|&(_, n)| n as i64 as u64 == v),
+                (&StringField, Bytes(b)) => str::is_utf8(b),
+                (&MessageField(ref name), Bytes(b)) => { try!(self.validate(*name, b)); true },
+                _ => false
+            };
+            if !valid {
+                return Err(format!("invalid value for {}.{}", message, field.name));
+            }
+            seen.push(field.number);
+        }
+        match fields.iter().find(|f| f.required && !seen.contains(&f.number)) {
+            Some(field) => Err(format!("missing {}.{}", message, field.name)),
+            None => Ok(())
+        }
+    }
+
+    fn to_protobuf(&self, message: &str, value: &Json) -> Result<~[u8], ~str> {
+        let fields = try!(self.fields(message));
+        let object = match *value {
+            json::Object(ref object) => object,
+            _ => return Err(format!("{} must be an object", message))
+        };
+        let mut out = ~[];
+        for (name, value) in object.iter() {
+            let field = match fields.iter().find(|f| f.name == *name) {
+                Some(field) => field,
+                None => return Err(format!("unknown field {}.{}", message, *name))
+            };
+            match *value {
+                json::List(ref values) if field.repeated => {
+                    for value in values.iter() {
+                        try!(self.write_field(&mut out, field, value));
+                    }
+                },
+                _ if field.repeated => return Err(format!("{}.{} must be a list", message, field.name)),
+                json::Null => (),
+                _ => try!(self.write_field(&mut out, field, value))
+            }
+        }
+        Ok(out)
+    }
+
+    fn write_field(&self, out: &mut ~[u8], field: &Field, value: &Json) -> Result<(), ~str> {
+        let varint = match (&field.kind, value) {
+            (&UInt64Field, &json::Number(n)) if n >= 0.0 && n == (n as u64) as f64 => Some(n as u64),
+            (&UInt32Field, &json::Number(n)) if n >= 0.0 && n == (n as u32) as f64 => Some(n as u64),
+            (&BoolField, &json::Boolean(b)) => Some(b as u64),
+            (&EnumField(ref name), &json::String(ref s)) => {
+                match self.enums.get(name).iter().find(|&&(ref n, _)| n == s) {
+                    Some(&(_, v)) => Some(v as i64 as u64),
+                    None => return Err(format!("unknown value {} for {}", *s, field.name))
+                }
+            },
+            _ => None
+        };
+        let bytes = match (&field.kind, value) {
+            (&StringField, &json::String(ref s)) => Some(s.as_bytes().to_owned()),
+            (&MessageField(ref name), _) => Some(try!(self.to_protobuf(*name, value))),
+            _ => None
+        };
+        match (varint, bytes) {
+            (Some(v), _) => {
+                write_varint(out, field.number << 3 | WIRE_VARINT);
+                write_varint(out, v);
+            },
+            (_, Some(b)) => {
+                write_varint(out, field.number << 3 | WIRE_LENGTH_DELIMITED);
+                write_varint(out, b.len() as u64);
+                out.push_all(b);
+            },
+            _ => return Err(format!("invalid value for {}", field.name))
+        }
+        Ok(())
+    }
+
+    fn to_json(&self, message: &str, data: &[u8]) -> Result<Json, ~str> {
+        let fields = try!(self.fields(message));
+        let mut object = ~TreeMap::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let key = try!(read_varint(data, &mut pos));
+            let wire = match key & 7 {
+                WIRE_VARINT => Varint(try!(read_varint(data, &mut pos))),
+                WIRE_LENGTH_DELIMITED => {
+                    let len = try!(read_varint(data, &mut pos)) as uint;
+                    if len > data.len() - pos {
+                        return Err(~"truncated field");
+                    }
+                    pos += len;
+                    Bytes(data.slice(pos - len, pos))
+                },
+                WIRE_FIXED64 => { pos += 8; continue; },
+                WIRE_FIXED32 => { pos += 4; continue; },
+                wire_type => return Err(format!("unsupported wire type {}", wire_type))
+            };
+            // Unknown fields are skipped, as the protobuf parser does
+            let field = match fields.iter().find(|f| f.number == key >> 3) {
+                Some(field) => field,
+                None => continue
+            };
+            let value = match (&field.kind, wire) {
+                (&UInt64Field, Varint(v)) | (&UInt32Field, Varint(v)) => json::Number(v as f64),
+                (&BoolField, Varint(v)) => json::Boolean(v != 0),
+                (&EnumField(ref name), Varint(v)) => {
+                    match self.enums.get(name).iter().find(|&&(_, n)| n == v as i32) {
+                        Some(&(ref n, _)) => json::String(n.clone()),
+                        None => json::Number(v as i32 as f64)
+                    }
+                },
+                (&StringField, Bytes(b)) => match str::from_utf8(b) {
+                    Some(s) => json::String(s.to_owned()),
+                    None => return Err(format!("{} is not UTF-8", field.name))
+                },
+                (&MessageField(ref name), Bytes(b)) => try!(self.to_json(*name, b)),
+                _ => return Err(format!("wrong wire type for {}", field.name))
+            };
+            if field.repeated {
+                if !object.contains_key(&field.name) {
+                    object.insert(field.name.clone(), json::List(~[]));
+                }
+                match object.find_mut(&field.name) {
+                    Some(&json::List(ref mut values)) => values.push(value),
+                    _ => ()
+                }
+            } else {
+                object.insert(field.name.clone(), value);
+            }
+        }
+        if pos > data.len() {
+            return Err(~"truncated field");
+        }
+        Ok(json::Object(object))
+    }
+}
+
+fn write_varint(out: &mut ~[u8], mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(data: &[u8], pos: &mut uint) -> Result<u64, ~str> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        if *pos >= data.len() || shift > 63 {
+            return Err(~"truncated varint");
+        }
+        let b = data[*pos];
+        *pos += 1;
+        value |= (b & 0x7f) as u64 << shift;
+        if b & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+#[test]
+fn test_encode() {
+    let schema = Schema::parse(include_str!("protocol.proto")).unwrap();
+    let login = schema.encode("RemoteCommand", r#"{"packet_type": "Login", "login": {"name": "a", "password": "b"}}"#);
+    // Fields are written in name order
+    assert_eq!(login, Ok(~[0x92u8, 0x01, 0x06, 0x0a, 0x01, 'a' as u8, 0x12, 0x01, 'b' as u8, 0x08, 0x02]));
+    assert!(schema.encode("RemoteCommand", r#"{"packet_type": "Logout"}"#).is_err());
+    assert!(schema.encode("RemoteCommand", r#"{"packet_type": "Login", "network_id": -1}"#).is_err());
+    assert!(schema.encode("RemoteCommand", r#"{"packet_type": "Login", "color": "blue"}"#).is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    let schema = Schema::parse(include_str!("protocol.proto")).unwrap();
+    let welcome = r#"{"packet_type":"Welcome","tag":7,"welcome":{"core_version":"0.1","features":["login","heartbeat"],"protocol_version":1}}"#;
+    let packet = schema.encode("RemoteMessage", welcome).unwrap();
+    assert_eq!(schema.decode("RemoteMessage", packet), Ok(welcome.to_owned()));
+    assert!(schema.decode("RemoteMessage", packet.slice_to(packet.len() - 1)).is_err());
+}
+
+#[test]
+fn test_validate() {
+    let schema = Schema::parse(include_str!("protocol.proto")).unwrap();
+    let login = schema.encode("RemoteCommand", r#"{"packet_type": "Login", "login": {"name": "a", "password": "b"}}"#)
+                      .unwrap();
+    assert_eq!(schema.validate("RemoteCommand", login), Ok(()));
+    // Truncated, without the required password, a non-UTF-8 name and an unknown packet type
+    assert!(schema.validate("RemoteCommand", login.slice_to(login.len() - 1)).is_err());
+    assert!(schema.validate("RemoteCommand", [0x92u8, 0x01, 0x03, 0x0a, 0x01, 'a' as u8, 0x08, 0x02]).is_err());
+    assert!(schema.validate("RemoteCommand", [0x92u8, 0x01, 0x06, 0x0a, 0x01, 0xff, 0x12, 0x01, 'b' as u8,
+                                              0x08, 0x02]).is_err());
+    assert!(schema.validate("RemoteCommand", [0x08u8, 0x7f]).is_err());
+    // The packet type as a fixed32 instead of a varint
+    assert!(schema.validate("RemoteCommand", [0x0du8, 0x02, 0, 0, 0]).is_err());
+    // Unknown fields are fine
+    assert_eq!(schema.validate("RemoteCommand", [0x08u8, 0x02, 0xf8, 0x07, 0x01, 0x92, 0x01, 0x06, 0x0a, 0x01,
+                                                 'a' as u8, 0x12, 0x01, 'b' as u8]), Ok(()));
+}
@@ -18,8 +18,18 @@ mod encoding;
 mod buffer;
 mod envelope;
 mod database;
+mod identity;
+mod channel;
+mod crypto;
+mod account;
+mod tls;
+mod websocket;
+mod protojson;
+mod unixsocket;
 
 fn main() {
+    use serialize::hex::FromHex;
+
 /*
     let (mut session, session_tx, session_rx) = session::Session::new();
     let network = network::Network::new();
@@ -35,8 +45,95 @@ fn main() {
     db.create_tables();
     db.bootstrap();
 
+    // q2 adduser <name> [session id...], reading the password from the first line of stdin
+    // so it doesn't show up in the process list
+    let args = std::os::args();
+    if args.len() >= 3 && args[1].as_slice() == "adduser" {
+        let sessions: ~[u64] = args.slice_from(3).iter().filter_map(|s| from_str(*s)).collect();
+        let _ = std::io::stderr().write_str("Password: ");
+        let password = match std::io::stdin().read_line() {
+            Ok(line) => line.trim_right_chars(&'\n').trim_right_chars(&'\r').to_owned(),
+            Err(_)   => ~""
+        };
+        if password.is_empty() {
+            println!("No password given");
+            return;
+        }
+        let account = account::Account::new(args[2].clone(), password.as_slice());
+        match db.create_account(&account, sessions) {
+            Ok(()) => (),
+            Err(e) => println!("{}", e)
+        }
+        return;
+    }
+
+    // q2 setfingerprint <name> [sha256 hex]
+    if args.len() >= 3 && args[1].as_slice() == "setfingerprint" {
+        let fingerprint = args.get_opt(3).map(|f| f.from_hex().ok().expect("Invalid fingerprint"));
+        if !db.set_certificate_fingerprint(args[2], fingerprint.as_ref().map(|f| f.as_slice())) {
+            println!("No such user: {}", args[2]);
+        }
+        return;
+    }
+
+    // q2 setunixuser <name> [uid]
+    if args.len() >= 3 && args[1].as_slice() == "setunixuser" {
+        let uid = args.get_opt(3).map(|u| from_str::<u32>(*u).expect("Invalid uid"));
+        if !db.set_unix_uid(args[2], uid) {
+            println!("No such user: {}", args[2]);
+        }
+        return;
+    }
+
+    // q2 [--tls <certificate> <key> [--require-client-cert]]
+    let tls_context = match args.iter().position(|a| a.as_slice() == "--tls") {
+        Some(i) if args.len() > i + 2 => {
+            let required = args.iter().any(|a| a.as_slice() == "--require-client-cert");
+            Some(tls::Context::new(args[i + 1], args[i + 2], required).ok().expect("Failed to set up TLS"))
+        },
+        Some(_) => fail!("--tls needs a certificate and a key"),
+        None    => None
+    };
+
     let mut rc = db.load_core();
 
+    // q2 [--heartbeat <seconds>]
+    match args.iter().position(|a| a.as_slice() == "--heartbeat").and_then(|i| args.get_opt(i + 1)) {
+        Some(secs) => match from_str::<u64>(*secs) {
+            Some(secs) if secs > 0 => rc.set_heartbeat_interval(secs * 1000),
+            _ => fail!("Invalid heartbeat interval, it must be a positive number of seconds")
+        },
+        None       => ()
+    }
+
     rc.run();
-    rc.listen(from_str("0.0.0.0:9006").unwrap());
+    // q2 [--websocket <address> [--websocket-origin <origin>...] [--websocket-codec json|protobuf]],
+    // e.g. 0.0.0.0:9007 and https://example.com, the origins being the sites whose pages may
+    // connect. Without a codec each remote's Hello picks one.
+    let origins: ~[~str] = args.iter().enumerate()
+        .filter(|&(_, a)| a.as_slice() == "--websocket-origin")
+        .filter_map(|(i, _)| args.get_opt(i + 1).map(|o| o.clone())).collect();
+    let codec = args.iter().position(|a| a.as_slice() == "--websocket-codec").and_then(|i| args.get_opt(i + 1));
+    let codec = match codec {
+        Some(codec) => match codec.as_slice() {
+            "json"     => Some(remotecontrol::Json),
+            "protobuf" => Some(remotecontrol::Protobuf),
+            _          => fail!("Invalid WebSocket codec, it must be json or protobuf")
+        },
+        None => None
+    };
+    match args.iter().position(|a| a.as_slice() == "--websocket").and_then(|i| args.get_opt(i + 1)) {
+        Some(addr) => rc.listen_websocket(from_str(*addr).expect("Invalid WebSocket address"), tls_context.clone(),
+                                          origins, codec),
+        None       => ()
+    }
+    // q2 [--unix <path>], connecting needs membership in the socket's group
+    match args.iter().position(|a| a.as_slice() == "--unix").and_then(|i| args.get_opt(i + 1)) {
+        Some(path) => {
+            let mode = std::io::UserRead | std::io::UserWrite | std::io::GroupRead | std::io::GroupWrite;
+            rc.listen_unix(&Path::new(path.as_slice()), mode).ok().expect("Failed to listen on Unix socket");
+        },
+        None       => ()
+    }
+    rc.listen(from_str("0.0.0.0:9006").unwrap(), tls_context);
 }
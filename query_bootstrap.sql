@@ -1,8 +1,8 @@
 BEGIN;
-INSERT INTO session VALUES (
+INSERT INTO session (id) VALUES (
   0
 );
-INSERT INTO network VALUES (
+INSERT INTO network (id, session_id, network_encoding, message_encoding, server, nickname) VALUES (
   0,
   last_insert_rowid(),
   "ASCII",
@@ -1,5 +1,37 @@
+CREATE TABLE IF NOT EXISTS user(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name STRING NOT NULL UNIQUE,
+
+    password_hash STRING NOT NULL, -- hex
+    salt STRING NOT NULL, -- hex
+    iterations INTEGER NOT NULL,
+
+    certificate_fingerprint STRING UNIQUE, -- hex SHA-256 of a TLS client certificate
+    unix_uid INTEGER UNIQUE -- local user logged in on the Unix socket
+);
 CREATE TABLE IF NOT EXISTS session(
-    id INTEGER PRIMARY KEY AUTOINCREMENT
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    owner_id INTEGER,
+
+    webirc_hostname STRING,
+    webirc_ip STRING,
+
+    FOREIGN KEY(owner_id) REFERENCES user(id)
+);
+CREATE TABLE IF NOT EXISTS identity(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id INTEGER NOT NULL,
+
+    ident STRING NOT NULL,
+    realname STRING NOT NULL,
+    alternate_nicks STRING NOT NULL, -- space separated
+
+    quit_message STRING NOT NULL,
+    part_message STRING NOT NULL,
+    away_message STRING NOT NULL,
+    auto_away INTEGER NOT NULL DEFAULT 0,
+
+    FOREIGN KEY(session_id) REFERENCES session(id)
 );
 CREATE TABLE IF NOT EXISTS network(
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -11,7 +43,40 @@ CREATE TABLE IF NOT EXISTS network(
     server STRING,
     nickname STRING,
 
-    FOREIGN KEY(session_id) REFERENCES session(id)
+    perform_delay INTEGER NOT NULL DEFAULT 0,
+
+    auth_service STRING,
+    auth_command STRING,
+    auth_password STRING,
+
+    identity_id INTEGER,
+
+    proxy_type INTEGER, -- 0 = SOCKS5, 1 = HTTP CONNECT
+    proxy_host STRING,
+    proxy_port INTEGER,
+    proxy_username STRING,
+    proxy_password STRING,
+
+    bind_address STRING,
+
+    webirc_password STRING,
+    webirc_gateway STRING,
+
+    FOREIGN KEY(session_id) REFERENCES session(id),
+    FOREIGN KEY(identity_id) REFERENCES identity(id)
+);
+CREATE TABLE IF NOT EXISTS network_perform(
+    network_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    command STRING NOT NULL,
+
+    FOREIGN KEY(network_id) REFERENCES network(id)
+);
+CREATE TABLE IF NOT EXISTS network_buddy(
+    network_id INTEGER NOT NULL,
+    nick STRING NOT NULL,
+
+    FOREIGN KEY(network_id) REFERENCES network(id)
 );
 CREATE TABLE IF NOT EXISTS buffer(
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -22,6 +87,9 @@ CREATE TABLE IF NOT EXISTS buffer(
 
     last_read INTEGER,
 
+    autojoin INTEGER NOT NULL DEFAULT 0,
+    channel_key STRING,
+
     FOREIGN KEY(network_id) REFERENCES network(id),
     FOREIGN KEY(last_read) REFERENCES message(id)
 );
@@ -30,8 +98,13 @@ CREATE TABLE IF NOT EXISTS message(
     buffer_id INTEGER NOT NULL,
 
     time_ns INTEGER NOT NULL,
+    received_ns INTEGER NOT NULL,
     type INTEGER NOT NULL,
 
+    msgid STRING,
+    account STRING,
+    prefix STRING,
+
     FOREIGN KEY(buffer_id) REFERENCES buffer(id)
 );
 CREATE TABLE IF NOT EXISTS message_information(
@@ -53,6 +126,7 @@ CREATE TABLE IF NOT EXISTS message_privmsg(
 
     FOREIGN KEY(message_id) REFERENCES message(id)
 );
+CREATE INDEX IF NOT EXISTS message_msgid_index ON message(buffer_id, msgid);
 CREATE UNIQUE INDEX IF NOT EXISTS message_information_index ON message_information(message_id);
 CREATE UNIQUE INDEX IF NOT EXISTS message_join_index ON message_join(message_id);
 CREATE UNIQUE INDEX IF NOT EXISTS message_privmsg_index ON message_privmsg(message_id);
@@ -2,7 +2,8 @@ SELECT
   id, time_ns, type,
   information.message,
   'join'.who,
-  privmsg.who, privmsg.message
+  privmsg.who, privmsg.message,
+  received_ns, msgid, account, prefix
 FROM message
 LEFT JOIN message_information information ON information.message_id = message.id
 LEFT JOIN message_join 'join' ON 'join'.message_id = message.id
@@ -2,6 +2,9 @@ SELECT
   id,
   role,
   name,
-  (SELECT COUNT(*) FROM message WHERE message.buffer_id = buffer.id) AS message_count
+  (SELECT COUNT(*) FROM message WHERE message.buffer_id = buffer.id) AS message_count,
+  autojoin,
+  channel_key,
+  last_read
 FROM buffer
-WHERE network_id = 0;
+WHERE network_id = ?;
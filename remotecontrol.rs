@@ -1,19 +1,38 @@
 use session::{Session};
 use collections::HashMap;
-use std::io::net::ip::SocketAddr;
-use std::io::net::tcp::{TcpListener, TcpStream};
-use std::io::{Acceptor, Listener};
+use std::io::net::ip::{SocketAddr, IpAddr};
+use std::io::net::tcp::{TcpListener, TcpStream, TcpAcceptor};
+use std::io::{Acceptor, Listener, IoResult, IoError, EndOfFile, InvalidInput, standard_error};
+use std::io::{Timer, FilePermission};
+use std::sync::atomics::{AtomicUint, SeqCst};
+use sync::Arc;
 use std;
+use std::str;
 use protobuf;
 use protobuf::Message;
 use network;
 use session;
 use buffer;
+use identity::Identity;
+use account;
+use channel::User;
+use account::Account;
 use envelope::Envelope;
+use irc;
+use tls;
+use websocket;
+use unixsocket;
+use protojson;
+use time;
 
 #[allow(dead_code)]
 mod protocol;
 
+// Failed logins are answered after this delay, and the remote is disconnected after this
+// many in a row
+static LOGIN_FAILURE_DELAY_MS: u64 = 1000;
+static MAX_LOGIN_FAILURES: uint = 3;
+
 /*
 tag system:
     each remote gets an unique tag when it connects.
@@ -26,40 +45,359 @@ tag system:
 */
 // TODO fixme don't use embedded tags, use envelope remote_tag field
 
+/// Remotes have to send a Hello with this version as their first packet
+static PROTOCOL_VERSION: u32 = 1;
+static CORE_VERSION: &'static str = "0.1";
+/// Optional protocol features the core supports. A remote gets the ones it also
+/// listed in its Hello, and the commands and updates of the others are refused or
+/// withheld. Client certificates aren't one, being checked in TLS before the Hello.
+static FEATURES: &'static [&'static str] = &["login", "buddy-list", "backfill", "heartbeat", "resync"];
+
+// Remotes that haven't finished the TLS, WebSocket and Hello handshakes after this long
+// are disconnected
+static HANDSHAKE_TIMEOUT_MS: u64 = 30 * 1000;
+// Packets larger than this end the connection
+static MAX_PACKET_SIZE: uint = 1024 * 1024;
+// Remotes that send nothing for this long while no session is attached, or don't log in
+// within this long of the handshake, are disconnected. Attached remotes may stay quiet.
+static IDLE_TIMEOUT_MS: u64 = 5 * 60 * 1000;
+static LOGIN_TIMEOUT_MS: u64 = 60 * 1000;
+// How often timeouts and lagging remotes are checked
+static TICK_MS: u64 = 1000;
+// Remotes with the heartbeat feature are pinged this often unless configured otherwise,
+// and dropped after this many pings go unanswered
+static DEFAULT_HEARTBEAT_INTERVAL_MS: u64 = 30 * 1000;
+static MAX_MISSED_HEARTBEATS: uint = 3;
+// Packets waiting for a remote's writer task before we stop sending it updates, and how
+// long it may stay behind before it is dropped
+static MAX_QUEUED_PACKETS: uint = 1000;
+static MAX_LAG_MS: u64 = 60 * 1000;
+// Replies may be queued this far beyond a full queue, so a lagging remote still gets them
+static MAX_QUEUED_REPLIES: uint = 100;
+
+/// How packets are delimited on a remote's connection
+#[deriving(Clone, Eq)]
+enum Framing {
+    LengthPrefixed, // u32 little-endian length before each packet
+    WebSocketFrames // one binary WebSocket message per packet
+}
+
+/// Encoding of a remote's packets, set for a listener or chosen by the encoding of the
+/// remote's Hello
+#[deriving(Clone, Eq)]
+pub enum Codec {
+    Protobuf,
+    Json // the messages of protocol.proto as JSON objects, see protojson
+}
+
+// How the remotes of a listener are spoken to
+#[deriving(Clone)]
+struct ListenOptions {
+    framing: Framing,
+    codec: Option<Codec>, // None picks it by the encoding of each remote's Hello
+    origins: ~[~str] // pages browsers may connect from over WebSocket
+}
+
+// Control frames the remote sends over WebSocket are answered through `writer`
+fn read_packet<R: Reader>(r: &mut R, framing: Framing, writer: &RemoteWriter) -> IoResult<~[u8]> {
+    match framing {
+        LengthPrefixed => {
+            let len = try!(r.read_le_u32()) as uint;
+            if len > MAX_PACKET_SIZE {
+                return Err(IoError { kind: InvalidInput, desc: "packet too large", detail: Some(format!("{} bytes", len)) });
+            }
+            r.read_bytes(len)
+        },
+        WebSocketFrames => {
+            match try!(websocket::read_message(r, MAX_PACKET_SIZE, |reply| { writer.send_raw(reply); })) {
+                Some(packet) => Ok(packet),
+                None => Err(standard_error(EndOfFile))
+            }
+        }
+    }
+}
+
+fn frame_packet(framing: Framing, codec: Codec, packet: &[u8]) -> ~[u8] {
+    match framing {
+        LengthPrefixed => {
+            let len = packet.len() as u32;
+            let mut out = ~[len as u8, (len >> 8) as u8, (len >> 16) as u8, (len >> 24) as u8];
+            out.push_all(packet);
+            out
+        },
+        WebSocketFrames => websocket::frame(if codec == Json { websocket::OP_TEXT } else { websocket::OP_BINARY },
+                                            packet)
+    }
+}
+
+// Converts a protobuf packet to `codec` and frames it
+fn encode_packet(schema: &protojson::Schema, framing: Framing, codec: Codec,
+                 packet: &[u8]) -> Result<~[u8], ~str> {
+    match codec {
+        Protobuf => Ok(frame_packet(framing, codec, packet)),
+        Json => schema.decode("RemoteMessage", packet).map(|text| frame_packet(framing, codec, text.as_bytes()))
+    }
+}
+
+/// A remote's connection, plain or wrapped in TLS, or local
+#[deriving(Clone)]
+enum RemoteStream {
+    Plain(TcpStream),
+    Tls(tls::TlsStream),
+    Unix(unixsocket::UnixStream)
+}
+
+impl Reader for RemoteStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        match *self {
+            Plain(ref mut s) => s.read(buf),
+            Tls(ref mut s)   => s.read(buf),
+            Unix(ref mut s)  => s.read(buf)
+        }
+    }
+}
+
+impl RemoteStream {
+    /// Makes reads fail, ending the remote's reader task
+    fn close_read(&mut self) -> IoResult<()> {
+        match *self {
+            Plain(ref mut s) => s.close_read(),
+            Tls(ref mut s)   => s.close_read(),
+            Unix(ref mut s)  => s.close_read()
+        }
+    }
+}
+
+impl Writer for RemoteStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        match *self {
+            Plain(ref mut s) => s.write(buf),
+            Tls(ref mut s)   => s.write(buf),
+            Unix(ref mut s)  => s.write(buf)
+        }
+    }
+}
+
+// What the transport tells about a remote, for logging it in without a password
+struct PeerCredentials {
+    fingerprint: Option<~[u8]>, // of the TLS client certificate
+    certificate_required: bool,
+    unix_uid: Option<u32> // of the process at the other end of the Unix socket
+}
+
+// The account a remote is logged in as by its certificate or Unix user, if any
+fn credentials_account(accounts: &HashMap<~str, Account>, credentials: &PeerCredentials) -> Option<u64> {
+    accounts.values().find(|a|
+        (credentials.fingerprint.is_some() && a.certificate_fingerprint == credentials.fingerprint) ||
+        (credentials.unix_uid.is_some() && a.unix_uid == credentials.unix_uid))
+        .map(|a| a.id)
+}
+
+// What a remote's writer task is given to write
+enum Outgoing {
+    Encode(Codec, ~[u8]), // a protobuf packet, converted and framed by the writer task
+    Raw(~[u8]) // already framed
+}
+
+/// Queues packets for a remote's writer task, so a slow remote only holds up itself
+#[deriving(Clone)]
+struct RemoteWriter {
+    tx: Sender<Outgoing>,
+    queued: Arc<AtomicUint>, // packets not written yet
+    framing: Framing,
+    codec: Codec, // packets are given as protobuf and converted to this
+    schema: Arc<protojson::Schema>
+}
+
+impl RemoteWriter {
+    fn spawn(stream: RemoteStream, framing: Framing, schema: Arc<protojson::Schema>) -> RemoteWriter {
+        let (tx, rx) = channel::<Outgoing>();
+        let queued = Arc::new(AtomicUint::new(0));
+        let writer = RemoteWriter { tx: tx, queued: queued.clone(), framing: framing, codec: Protobuf,
+                                    schema: schema.clone() };
+        std::task::task().named("remotecontrol.WriterTask").spawn(proc() {
+            let mut stream = stream;
+            for outgoing in rx.iter() {
+                let written = match outgoing {
+                    Raw(data) => stream.write(data).is_ok(),
+                    Encode(codec, packet) => match encode_packet(schema.get(), framing, codec, packet) {
+                        Ok(data) => stream.write(data).is_ok(),
+                        Err(e) => {
+                            // Rather than have the remote miss the packet, it is dropped
+                            println!("Failed to convert packet for remote: {}", e);
+                            false
+                        }
+                    }
+                };
+                queued.get().fetch_sub(1, SeqCst);
+                if !written {
+                    // Ends the reader task, which gets the remote purged
+                    stream.close_read();
+                    return;
+                }
+            }
+        });
+        writer
+    }
+
+    /// Queues an update. Fails if the queue is full or the writer task has ended.
+    fn send(&self, packet: &[u8]) -> bool {
+        self.send_limited(packet, MAX_QUEUED_PACKETS)
+    }
+
+    /// Queues a reply, which may go beyond a full queue. Fails if even that is full or
+    /// the writer task has ended.
+    fn send_reply(&self, packet: &[u8]) -> bool {
+        self.send_limited(packet, MAX_QUEUED_PACKETS + MAX_QUEUED_REPLIES)
+    }
+
+    fn send_limited(&self, packet: &[u8], limit: uint) -> bool {
+        self.queue(Encode(self.codec, packet.to_owned()), limit)
+    }
+
+    // Queues bytes that are already framed, as a reply
+    fn send_raw(&self, data: ~[u8]) -> bool {
+        self.queue(Raw(data), MAX_QUEUED_PACKETS + MAX_QUEUED_REPLIES)
+    }
+
+    fn queue(&self, data: Outgoing, limit: uint) -> bool {
+        if self.queued() >= limit {
+            return false;
+        }
+        self.queued.get().fetch_add(1, SeqCst);
+        self.tx.try_send(data)
+    }
+
+    fn queued(&self) -> uint {
+        self.queued.get().load(SeqCst)
+    }
+}
+
+// Sent to the control task about each connection
+enum Wakeup {
+    Accepted(u64, RemoteStream), // tag, connection starting its handshakes, which time out
+    Greeted(NewRemote), // the handshakes finished
+    Closed(u64) // the connection with this tag ended
+}
+
+// A remote that finished its handshakes
+struct NewRemote {
+    rx: Receiver<Envelope<msg::Command>>,
+    stream: RemoteStream,
+    writer: RemoteWriter,
+    tag: u64,
+    credentials: PeerCredentials,
+    features: ~[~str]
+}
+
 struct RemoteData {
     rx: Receiver<Envelope<msg::Command>>,
-    stream: TcpStream,
+    stream: RemoteStream, // for closing, writes go through the writer
+    writer: RemoteWriter,
+    lagging_since: Option<u64>, // set when the queue was full, updates are dropped until it drains
     session_id: Option<u64>,
+    account_id: Option<u64>, // set by a successful Login or a known client certificate
+    login_pending: bool, // a Login is being checked, further ones are refused until it is
+    failed_logins: uint, // failed Logins since the last successful one
+    features: ~[~str], // negotiated in the handshake
+    last_activity: u64, // time of the last packet, in ns
+    connected_at: u64, // time the handshake finished, in ns
+    pending_heartbeats: ~[(u64, u64)], // ids and send times of unanswered heartbeats, oldest first
+    missed_heartbeats: uint,
+    rtt_ms: Option<u64>, // round-trip time of the last answered heartbeat
+    synced: bool, // false until the snapshot of the attached session has been sent
     tag: u64
 }
 
 impl RemoteData {
+    // Sends a reply or another packet the remote can't do without, even while it lags. If
+    // that fails the remote is too far behind and is disconnected.
     fn write_packet(&mut self, packet: &[u8]) {
-        self.stream.write_le_u32(packet.len() as u32);
-        self.stream.write(packet);
+        if !self.writer.send_reply(packet) {
+            println!("Remote {} is too far behind, disconnecting", self.tag);
+            self.stream.close_read();
+        }
+    }
+
+    // Sends an update of the attached session. Once the queue is full they are dropped
+    // until the remote catches up and resyncs, or it is disconnected if it can't resync.
+    fn write_update(&mut self, packet: &[u8]) {
+        if self.lagging_since.is_some() {
+            return;
+        }
+        if !self.writer.send(packet) {
+            if !self.has_feature("resync") {
+                println!("Remote {} is lagging and can't resync, disconnecting", self.tag);
+                self.stream.close_read();
+                return;
+            }
+            self.lagging_since = Some(time::precise_time_ns());
+            self.synced = false;
+        }
+    }
+
+    // Answers the command that `bare` was taken from with an Error
+    fn write_error(&mut self, bare: &Envelope<()>, error: ~str) {
+        self.write_packet(pack_remote_packet(bare.copy_with(msg::Error(error))));
+    }
+
+    fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f.as_slice() == feature)
+    }
+
+    // Sends the next heartbeat, returning false if too many have gone unanswered
+    fn heartbeat(&mut self, id: u64) -> bool {
+        if !self.pending_heartbeats.is_empty() {
+            self.missed_heartbeats += 1;
+            if self.missed_heartbeats >= MAX_MISSED_HEARTBEATS {
+                return false;
+            }
+        }
+        self.pending_heartbeats.push((id, time::precise_time_ns()));
+        let rtt_ms = self.rtt_ms;
+        self.write_packet(pack_remote_packet(Envelope::empty(msg::Heartbeat(id, rtt_ms))));
+        true
     }
 
-    fn write_error(&mut self, error: ~str) {
-        //self.write_packet(pack_remote_packet(RmError(error)).val0());
+    // A late answer still counts, and answers the heartbeats sent before it too
+    fn heartbeat_answered(&mut self, id: u64) {
+        match self.pending_heartbeats.iter().position(|&(pending, _)| pending == id) {
+            Some(i) => {
+                let (_, sent) = self.pending_heartbeats[i];
+                self.rtt_ms = Some((time::precise_time_ns() - sent) / 1000000);
+                self.pending_heartbeats = self.pending_heartbeats.slice_from(i + 1).to_owned();
+                self.missed_heartbeats = 0;
+            },
+            None => ()
+        }
     }
 }
 
 pub struct SessionData {
     session: Option<Session>,
+    owner: Option<u64>, // account allowed to attach
     tx: Sender<Envelope<session::msg::Command>>,
     rx: Receiver<Envelope<session::msg::Message>>
 }
 
 pub struct RemoteControl {
     priv sessions: Option<HashMap<u64, SessionData>>,
-    priv wakeup_rx: Option<Receiver<(Receiver<Envelope<msg::Command>>, TcpStream, u64)>>,
-    priv wakeup_tx: Sender<(Receiver<Envelope<msg::Command>>, TcpStream, u64)>
+    priv accounts: Option<HashMap<~str, Account>>, // by name
+    priv heartbeat_interval_ms: u64,
+    priv next_tag: Arc<AtomicUint>, // shared by all listeners
+    priv schema: Arc<protojson::Schema>, // for remotes speaking JSON
+    priv wakeup_rx: Option<Receiver<Wakeup>>,
+    priv wakeup_tx: Sender<Wakeup>
 }
 
 pub mod msg {
     use session;
 
     pub enum Command {
+        Hello(u32, ~[~str]), // protocol version, features
+        Login(~str, ~str), // name, password
+        Ping(u64, Option<u64>), // id, round-trip time measured by the remote in ms
+        Pong(u64), // id of the heartbeat answered
         AttachSession(u64),
         SessionCommand(session::msg::Command)
     }
@@ -67,6 +405,10 @@ pub mod msg {
     pub enum Message {
         Error(~str),
         Success,
+        Welcome(u32, ~str, ~[~str]), // protocol version, core version, features
+        Heartbeat(u64, Option<u64>), // id, round-trip time measured by the core in ms
+        Resync, // updates were dropped, a new snapshot follows if attached
+        HeartbeatReply(u64, Option<u64>), // answer to a Ping
         SessionMessage(session::msg::Message)
     }
 }
@@ -74,8 +416,16 @@ pub mod msg {
 impl RemoteControl {
     pub fn new() -> RemoteControl {
         let (wtx, wrx) = channel();
+        let schema = match protojson::Schema::parse(include_str!("protocol.proto")) {
+            Ok(schema) => schema,
+            Err(e) => fail!("Invalid protocol.proto: {}", e)
+        };
         RemoteControl {
             sessions: Some(HashMap::new()),
+            accounts: Some(HashMap::new()),
+            heartbeat_interval_ms: DEFAULT_HEARTBEAT_INTERVAL_MS,
+            next_tag: Arc::new(AtomicUint::new(0)),
+            schema: Arc::new(schema),
             wakeup_rx: Some(wrx),
             wakeup_tx: wtx
         }
@@ -85,50 +435,69 @@ impl RemoteControl {
         self.sessions.get_mut_ref().insert(id, session);
     }
 
-    pub fn listen(&mut self, addr: SocketAddr) {
-        let listener = TcpListener::bind(addr);
-        let mut acceptor = listener.listen();
-        let mut next_tag = 0;
-
-        for stream in acceptor.incoming() {
-            // TODO handle errors?
-            let stream = stream.unwrap();
-            let wakeup_tx = self.wakeup_tx.clone();
-            let tag = next_tag;
-            next_tag += 1;
-            std::task::task().named("remotecontrol.ReaderTask").spawn(proc() {
-                let mut stream = stream;
-                let (remote_tx, remote_rx) = channel();
-                wakeup_tx.send((remote_rx, stream.clone(), tag));
-
-                println!("New client");
-
-                loop {
-                    let len = match stream.read_le_u32() {
-                        Ok(len) => len,
-                        Err(io_err) => fail!("Remote client failed during packet length: {}", io_err)
-                    };
-                    println!("Remote waiting for packet of length {}", len);
-                    // TODO check for too large packets
-                    // read_bytes -> read_exact in update
-                    let packet = match stream.read_bytes(len as uint) {
-                        Ok(packet) => packet,
-                        Err(io_err) => fail!("Remote client failed during packet: {}", io_err)
-                    };
-                    let cmd = parse_remote_packet(packet, tag);
-                    println!("<< {:?}", cmd);
-                    match cmd {
-                        Some(cmd) => remote_tx.send(cmd),
-                        None => {
-                            // FIXME code duplication + client_tag is lost
-                            let data = pack_remote_packet(Envelope::empty(msg::Error(~"invalid packet")));
-                            stream.write_le_u32(data.len() as u32);
-                            stream.write(data);
-                        }
-                    }
-                }
-            });
-        }
+    pub fn add_account(&mut self, account: Account) {
+        self.accounts.get_mut_ref().insert(account.name.clone(), account);
+    }
+
+    /// Sets how often remotes with the heartbeat feature are pinged. Takes effect on `run`.
+    pub fn set_heartbeat_interval(&mut self, interval_ms: u64) {
+        assert!(interval_ms > 0);
+        self.heartbeat_interval_ms = interval_ms;
+    }
+
+    /// Accepts remotes on `addr`, over TLS if a context is given.
+    pub fn listen(&mut self, addr: SocketAddr, tls_context: Option<tls::Context>) {
+        let acceptor = TcpListener::bind(addr).listen();
+        let listener = ListenOptions { framing: LengthPrefixed, codec: None, origins: ~[] };
+        accept_remotes(acceptor, tls_context, listener, self.next_tag.clone(), self.schema.clone(),
+                       self.wakeup_tx.clone());
+    }
+
+    /// Accepts remotes speaking WebSocket on `addr`, e.g. browsers, each packet being a
+    /// message, in `codec` if given. Browsers are only let in from pages at `origins`,
+    /// given like `https://example.com`. Unlike `listen` this returns at once, accepting
+    /// in its own task.
+    pub fn listen_websocket(&mut self, addr: SocketAddr, tls_context: Option<tls::Context>, origins: ~[~str],
+                            codec: Option<Codec>) {
+        let acceptor = TcpListener::bind(addr).listen();
+        let listener = ListenOptions { framing: WebSocketFrames, codec: codec, origins: origins };
+        let next_tag = self.next_tag.clone();
+        let schema = self.schema.clone();
+        let wakeup_tx = self.wakeup_tx.clone();
+        std::task::task().named("remotecontrol.WebSocketListener").spawn(proc() {
+            accept_remotes(acceptor, tls_context, listener, next_tag, schema, wakeup_tx);
+        });
+    }
+
+    /// Accepts local remotes on a Unix socket at `path`, whose file gets `mode` as its
+    /// permissions. Remotes run by a user set as an account's Unix user are logged in as
+    /// that account. Returns at once, accepting in its own task.
+    pub fn listen_unix(&mut self, path: &Path, mode: FilePermission) -> IoResult<()> {
+        let acceptor = try!(unixsocket::UnixAcceptor::bind(path, mode));
+        let next_tag = self.next_tag.clone();
+        let schema = self.schema.clone();
+        let wakeup_tx = self.wakeup_tx.clone();
+        std::task::task().named("remotecontrol.UnixListener").spawn(proc() {
+            let mut acceptor = acceptor;
+            loop {
+                let stream = match acceptor.accept() {
+                    Ok(stream) => stream,
+                    Err(e) => { println!("Unix socket failed: {}", e); return; }
+                };
+                let credentials = PeerCredentials { fingerprint: None, certificate_required: false,
+                                                    unix_uid: stream.peer_uid().ok() };
+                let schema = schema.clone();
+                let wakeup_tx = wakeup_tx.clone();
+                let tag = next_tag.get().fetch_add(1, SeqCst) as u64;
+                wakeup_tx.send(Accepted(tag, Unix(stream.clone())));
+                std::task::task().named("remotecontrol.ReaderTask").spawn(proc() {
+                    let listener = ListenOptions { framing: LengthPrefixed, codec: None, origins: ~[] };
+                    serve_remote(Unix(stream), &listener, schema, tag, credentials, &wakeup_tx);
+                    wakeup_tx.send(Closed(tag));
+                });
+            }
+        });
+        Ok(())
     }
 
     pub fn run(&mut self) {
@@ -136,10 +505,14 @@ impl RemoteControl {
         enum Source {
             FromSession(u64),
             FromRemote(uint),
-            FromWakeup
+            FromWakeup,
+            FromTimer,
+            FromLogin
         }
         let wakeup = self.wakeup_rx.take_unwrap();
         let mut sessions = self.sessions.take_unwrap();
+        let accounts = self.accounts.take_unwrap();
+        let heartbeat_interval_ms = self.heartbeat_interval_ms;
         for (_, session) in sessions.mut_iter() {
             let sess = session.session.take_unwrap();
             std::task::task().named("remotecontrol.SessionTask").spawn(proc() {
@@ -149,6 +522,15 @@ impl RemoteControl {
         }
         std::task::task().named("remotecontrol.ControlTask").spawn(proc() {
             let mut remotes: ~[RemoteData] = ~[];
+            // Connections still in their handshakes, with their tags and times accepted
+            let mut handshaking: ~[(u64, RemoteStream, u64)] = ~[];
+            // Passwords are hashed in a task per Login, which reports the remote's tag, the
+            // command and the account logged in as, if any
+            let (login_tx, login_rx) = channel::<(u64, Envelope<()>, Option<u64>)>();
+            let mut timer = Timer::new().unwrap();
+            let ticks = timer.periodic(TICK_MS);
+            let mut next_heartbeat = 0u64;
+            let mut last_heartbeat = time::precise_time_ns();
             loop {
                 let src = {
                     let sel = std::comm::Select::new();
@@ -168,10 +550,12 @@ impl RemoteControl {
                     }
 
                     let mut wakeup_handle = sel.handle(&wakeup);
-
-                    println!("Selecting wakeup + {} sessions + {} remotes", session_handles.len(), remote_handles.len());
+                    let mut timer_handle = sel.handle(&ticks);
+                    let mut login_handle = sel.handle(&login_rx);
 
                     unsafe { wakeup_handle.add(); }
+                    unsafe { timer_handle.add(); }
+                    unsafe { login_handle.add(); }
                     for &(_, ref mut handle) in session_handles.mut_iter() { unsafe { handle.add(); } }
                     for handle in remote_handles.mut_iter() { unsafe { handle.add(); } }
 
@@ -179,6 +563,10 @@ impl RemoteControl {
                     if ready_id == wakeup_handle.id() {
                         // A remote was added, select again
                         FromWakeup
+                    } else if ready_id == timer_handle.id() {
+                        FromTimer
+                    } else if ready_id == login_handle.id() {
+                        FromLogin
                     } else {
                          session_handles.iter().find(|& &(_, ref handle)| handle.id() == ready_id)
                                                .map(|&(session_id, _)| FromSession(session_id))
@@ -188,15 +576,108 @@ impl RemoteControl {
                     }
                 };
 
-                println!("RC IN << {}", src);
                 match src {
                     FromWakeup => {
                         // New remote added, spin again
-                        let (rx, stream, tag) = match wakeup.recv_opt() {
-                            Some(x) => x,
+                        let new = match wakeup.recv_opt() {
+                            Some(Accepted(tag, stream)) => {
+                                handshaking.push((tag, stream, time::precise_time_ns()));
+                                continue;
+                            },
+                            Some(Greeted(new)) => new,
+                            Some(Closed(tag)) => {
+                                handshaking.retain(|&(t, _, _)| t != tag);
+                                continue;
+                            },
                             None    => { println!("!!! remotecontrol: wakeup is dead? !!!"); return; }
                         };
-                        remotes.push(RemoteData { rx: rx, stream: stream, session_id: None, tag: tag });
+                        let NewRemote { rx, stream, writer, tag, credentials, features } = new;
+                        handshaking.retain(|&(t, _, _)| t != tag);
+                        let account_id = credentials_account(&accounts, &credentials);
+                        let mut remote = RemoteData { rx: rx, stream: stream, writer: writer,
+                                                      lagging_since: None, session_id: None,
+                                                      account_id: account_id, login_pending: false,
+                                                      failed_logins: 0, features: features,
+                                                      last_activity: time::precise_time_ns(),
+                                                      connected_at: time::precise_time_ns(),
+                                                      pending_heartbeats: ~[], missed_heartbeats: 0,
+                                                      rtt_ms: None,
+                                                      synced: false, tag: tag };
+                        if credentials.certificate_required && account_id.is_none() {
+                            remote.write_packet(pack_remote_packet(Envelope::empty(msg::Error(~"unknown certificate"))));
+                            remote.stream.close_read();
+                            continue;
+                        }
+                        remotes.push(remote);
+                    },
+                    FromTimer => {
+                        ticks.recv();
+                        let now = time::precise_time_ns();
+                        // Closing the connection fails whatever handshake read is stuck
+                        let (expired, waiting) = std::mem::replace(&mut handshaking, ~[]).partition(
+                            |&(_, _, accepted)| now - accepted > HANDSHAKE_TIMEOUT_MS * 1000000);
+                        handshaking = waiting;
+                        for (tag, mut stream, _) in expired.move_iter() {
+                            println!("Remote {}: handshake timeout", tag);
+                            let _ = stream.close_read();
+                        }
+                        let heartbeat_due = now - last_heartbeat >= heartbeat_interval_ms * 1000000;
+                        if heartbeat_due {
+                            next_heartbeat += 1;
+                            last_heartbeat = now;
+                        }
+                        for remote in remotes.mut_iter() {
+                            catch_up(&sessions, remote);
+                            let error = if remote.session_id.is_none() &&
+                                           now - remote.last_activity > IDLE_TIMEOUT_MS * 1000000 {
+                                Some(~"idle timeout")
+                            } else if remote.account_id.is_none() &&
+                                      now - remote.connected_at > LOGIN_TIMEOUT_MS * 1000000 {
+                                Some(~"login timeout")
+                            } else if remote.lagging_since.map_or(false, |t| now - t > MAX_LAG_MS * 1000000) {
+                                Some(~"too slow")
+                            } else if heartbeat_due && remote.has_feature("heartbeat") &&
+                                      !remote.heartbeat(next_heartbeat) {
+                                Some(~"heartbeat timeout")
+                            } else {
+                                None
+                            };
+                            match error {
+                                Some(error) => {
+                                    println!("Remote {}: {}", remote.tag, error);
+                                    remote.write_packet(pack_remote_packet(Envelope::empty(msg::Error(error))));
+                                    // Its reader task ends and the remote is purged below
+                                    remote.stream.close_read();
+                                },
+                                None => ()
+                            }
+                        }
+                    },
+                    FromLogin => {
+                        let (tag, bare, logged_in) = login_rx.recv();
+                        // The remote may have gone away meanwhile
+                        let remote = match remotes.mut_iter().find(|r| r.tag == tag) {
+                            Some(remote) => remote,
+                            None         => continue
+                        };
+                        remote.login_pending = false;
+                        match logged_in {
+                            Some(id) => {
+                                remote.account_id = Some(id);
+                                remote.failed_logins = 0;
+                                remote.write_packet(pack_remote_packet(bare.copy_with(msg::Success)));
+                            },
+                            None => {
+                                remote.failed_logins += 1;
+                                remote.write_packet(pack_remote_packet(bare.copy_with(msg::Error(~"invalid login"))));
+                                if remote.failed_logins >= MAX_LOGIN_FAILURES {
+                                    println!("Remote {}: too many failed logins", remote.tag);
+                                    remote.write_packet(pack_remote_packet(
+                                        Envelope::empty(msg::Error(~"too many failed logins"))));
+                                    remote.stream.close_read();
+                                }
+                            }
+                        }
                     },
                     FromSession(session_id) => {
                         // Handle received message from session with id
@@ -205,10 +686,25 @@ impl RemoteControl {
                             None    => { println!("!!! remotecontrol: session is dead? !!!"); return; }
                         };
                         let tag = msg.remote_tag;
+                        let feature = message_feature(&msg.contents);
+                        let is_snapshot = match msg.contents { session::msg::Snapshot(..) => true, _ => false };
                         let packet = pack_remote_packet(msg.encapsulate(msg::SessionMessage));
                         for remote in remotes.mut_iter().filter(|r| r.session_id.is_some() && r.session_id.unwrap() == session_id) {
-                            if tag.is_none() || tag.unwrap() == remote.tag {
-                                remote.write_packet(packet);
+                            catch_up(&sessions, remote);
+                            if feature.map_or(false, |f| !remote.has_feature(f)) {
+                                continue;
+                            }
+                            match tag {
+                                // Replies always go out, the snapshot among them
+                                Some(t) if t == remote.tag => {
+                                    if is_snapshot {
+                                        remote.synced = true;
+                                    }
+                                    remote.write_packet(packet);
+                                },
+                                // Updates before the snapshot are already part of it
+                                None if remote.synced => remote.write_update(packet),
+                                _ => ()
                             }
                         }
                     },
@@ -219,28 +715,88 @@ impl RemoteControl {
                         };
                         match in_cmd {
                             Some(cmd) => {
-                                println!("RC ACK: {:?}", cmd);
+                                remotes[remote_idx].last_activity = time::precise_time_ns();
                                 let bare = cmd.bare();
+                                let account_id = remotes[remote_idx].account_id;
+                                let missing = command_feature(&cmd.contents)
+                                    .filtered(|f| !remotes[remote_idx].has_feature(*f));
                                 match cmd.contents {
+                                    _ if missing.is_some() => {
+                                        let error = format!("feature {} not negotiated", missing.unwrap());
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::Error(error))));
+                                    },
+                                    msg::Hello(..) => {
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::Error(~"already greeted"))));
+                                    },
+                                    msg::Ping(id, _) => {
+                                        let own_rtt = remotes[remote_idx].rtt_ms;
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::HeartbeatReply(id, own_rtt))));
+                                    },
+                                    msg::Pong(id) => remotes[remote_idx].heartbeat_answered(id),
+                                    msg::Login(_, _) if remotes[remote_idx].login_pending => {
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::Error(~"login in progress"))));
+                                    },
+                                    // Switching accounts would keep the session attached as the
+                                    // previous one
+                                    msg::Login(_, _) if account_id.is_some() => {
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::Error(~"already logged in"))));
+                                    },
+                                    msg::Login(name, password) => {
+                                        remotes[remote_idx].login_pending = true;
+                                        let account = accounts.find(&name).map(|a| a.clone());
+                                        let tag = remotes[remote_idx].tag;
+                                        let login_tx = login_tx.clone();
+                                        std::task::task().named("remotecontrol.LoginTask").spawn(proc() {
+                                            let ok = account::check_login(account.as_ref(), password.as_slice());
+                                            let logged_in = if ok {
+                                                account.map(|a| a.id)
+                                            } else {
+                                                std::io::timer::sleep(LOGIN_FAILURE_DELAY_MS);
+                                                None
+                                            };
+                                            login_tx.try_send((tag, bare, logged_in));
+                                        });
+                                    },
+                                    _ if account_id.is_none() => {
+                                        remotes[remote_idx].write_packet(
+                                            pack_remote_packet(bare.copy_with(msg::Error(~"not logged in"))));
+                                    },
                                     msg::AttachSession(session_id) => {
+                                        match sessions.find(&session_id) {
+                                            Some(sess) if sess.owner.is_some() && sess.owner == account_id => (),
+                                            _ => {
+                                                remotes[remote_idx].write_packet(
+                                                    pack_remote_packet(bare.copy_with(msg::Error(~"invalid session"))));
+                                                continue;
+                                            }
+                                        }
+                                        let previous = remotes[remote_idx].session_id;
                                         remotes[remote_idx].session_id = Some(session_id);
-                                        // FIXME check that session id is valid
-                                        remotes[remote_idx].write_packet(
-                                            pack_remote_packet(bare.copy_with(msg::Success)))
+                                        remotes[remote_idx].synced = false;
+                                        // The snapshot is the reply, live updates follow it
+                                        sessions.get(&session_id).tx.send(bare.copy_with(session::msg::GetSnapshot));
+                                        previous.map(|sid| update_detached(&sessions, remotes, sid));
+                                        update_detached(&sessions, remotes, session_id);
                                     },
                                     msg::SessionCommand(sess_cmd) => {
                                         let sess = remotes[remote_idx].session_id.and_then(|sid|
                                             sessions.find(&sid));
                                         match sess {
                                             Some(sess) => sess.tx.send(bare.copy_with(sess_cmd)),
-                                            None => remotes[remote_idx].write_error(~"No session attached")
+                                            None => remotes[remote_idx].write_error(&bare, ~"No session attached")
                                         }
                                     }
                                 }
                             },
                             None      => {
                                 println!("Remote is dead, purging");
-                                remotes.remove(remote_idx);
+                                let removed = remotes.remove(remote_idx);
+                                removed.and_then(|r| r.session_id).map(|sid| update_detached(&sessions, remotes, sid));
                             }
                         }
                     }
@@ -250,29 +806,249 @@ impl RemoteControl {
     }
 }
 
-fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>> {
+// Starts a reader task for each connection, which does the TLS handshake if configured
+// and then serves the remote
+fn accept_remotes(mut acceptor: IoResult<TcpAcceptor>, tls_context: Option<tls::Context>, listener: ListenOptions,
+                  next_tag: Arc<AtomicUint>, schema: Arc<protojson::Schema>, wakeup_tx: Sender<Wakeup>) {
+    for stream in acceptor.incoming() {
+        // TODO handle errors?
+        let stream = stream.unwrap();
+        let schema = schema.clone();
+        let wakeup_tx = wakeup_tx.clone();
+        let tls_context = tls_context.clone();
+        let listener = listener.clone();
+        let tag = next_tag.get().fetch_add(1, SeqCst) as u64;
+        // Closing the TCP side also fails a stuck TLS handshake
+        wakeup_tx.send(Accepted(tag, Plain(stream.clone())));
+        std::task::task().named("remotecontrol.ReaderTask").spawn(proc() {
+            let accepted = match tls_context {
+                Some(ctx) => match ctx.accept(stream) {
+                    Ok(s) => {
+                        let fingerprint = s.peer_fingerprint();
+                        Some((Tls(s), fingerprint, ctx.requires_client_certificate()))
+                    },
+                    Err(e) => { println!("TLS handshake with remote failed: {}", e); None }
+                },
+                None => Some((Plain(stream), None, false))
+            };
+            match accepted {
+                Some((stream, fingerprint, certificate_required)) => {
+                    let credentials = PeerCredentials { fingerprint: fingerprint,
+                                                        certificate_required: certificate_required, unix_uid: None };
+                    serve_remote(stream, &listener, schema, tag, credentials, &wakeup_tx);
+                },
+                None => ()
+            }
+            wakeup_tx.send(Closed(tag));
+        });
+    }
+}
+
+// Once a lagging remote's queue has drained, tells it to resync and requests a new
+// snapshot of its session in place of the updates it missed
+fn catch_up(sessions: &HashMap<u64, SessionData>, remote: &mut RemoteData) {
+    if remote.lagging_since.is_none() || remote.writer.queued() > 0 {
+        return;
+    }
+    remote.lagging_since = None;
+    remote.write_packet(pack_remote_packet(Envelope::empty(msg::Resync)));
+    match remote.session_id.and_then(|sid| sessions.find(&sid)) {
+        Some(sess) => sess.tx.send(Envelope { client_tag: None, remote_tag: Some(remote.tag),
+                                              contents: session::msg::GetSnapshot }),
+        None => ()
+    }
+}
+
+// Runs a remote's connection after any TLS handshake: greets it, registers it with the
+// control task and passes its commands on until it disconnects. Packets that can't be
+// parsed are answered with an Error. Returning drops the remote's command sender, which
+// makes the control task forget it.
+fn serve_remote(mut stream: RemoteStream, listener: &ListenOptions, schema: Arc<protojson::Schema>, tag: u64,
+                credentials: PeerCredentials, wakeup_tx: &Sender<Wakeup>) {
+    let framing = listener.framing;
+    if framing == WebSocketFrames {
+        match websocket::accept_handshake(&mut stream, listener.origins.as_slice()) {
+            Ok(()) => (),
+            Err(e) => { println!("WebSocket handshake with remote failed: {}", e); return; }
+        }
+    }
+    let mut writer = RemoteWriter::spawn(stream.clone(), framing, schema.clone());
+    writer.codec = listener.codec.unwrap_or(Protobuf);
+    let features = match handshake(&mut stream, framing, listener.codec.is_none(), &mut writer, tag) {
+        Some(features) => features,
+        None => { println!("Remote failed the handshake"); return; }
+    };
+
+    let (remote_tx, remote_rx) = channel();
+    wakeup_tx.send(Greeted(NewRemote { rx: remote_rx, stream: stream.clone(), writer: writer.clone(), tag: tag,
+                                       credentials: credentials, features: features }));
+
+    println!("Remote {} connected", tag);
+
+    loop {
+        let packet = match read_packet(&mut stream, framing, &writer) {
+            Ok(packet) => packet,
+            Err(ref e) if e.kind == EndOfFile => { println!("Remote {} disconnected", tag); return; },
+            Err(e) => {
+                if e.kind == InvalidInput {
+                    writer.send_reply(pack_remote_packet(Envelope::empty(msg::Error(e.desc.to_owned()))));
+                }
+                println!("Remote {} failed: {}", tag, e);
+                return;
+            }
+        };
+        match parse_packet(packet, writer.codec, schema.get(), tag) {
+            Ok(cmd) => {
+                if !remote_tx.try_send(cmd) {
+                    // The control task dropped us
+                    return;
+                }
+            },
+            Err(error) => { writer.send_reply(pack_remote_packet(error)); }
+        }
+    }
+}
+
+// Reads the remote's Hello and answers with a Welcome listing the features both sides
+// support. Returns those, or None after refusing the remote. With `detect_codec` a Hello
+// in JSON switches the remote to JSON.
+fn handshake<R: Reader>(stream: &mut R, framing: Framing, detect_codec: bool, writer: &mut RemoteWriter,
+                        tag: u64) -> Option<~[~str]> {
+    let hello = match read_packet(stream, framing, writer) {
+        Ok(packet) => {
+            // A protobuf RemoteCommand starts with its packet type, never with whitespace
+            // or '{', which would be a group
+            let first = packet.iter().find(|&&b| !(b == ' ' as u8 || b == '\t' as u8 ||
+                                                  b == '\r' as u8 || b == '\n' as u8));
+            if detect_codec && first == Some(&('{' as u8)) {
+                writer.codec = Json;
+            }
+            parse_packet(packet, writer.codec, writer.schema.get(), tag)
+        },
+        Err(_)     => return None
+    };
+    let (reply, features) = match hello {
+        Ok(cmd) => {
+            let bare = cmd.bare();
+            match cmd.contents {
+                msg::Hello(version, wanted) if version == PROTOCOL_VERSION => {
+                    let features: ~[~str] = wanted.move_iter()
+                        .filter(|f| FEATURES.iter().any(|x| x.equiv(f))).collect();
+                    (bare.copy_with(msg::Welcome(PROTOCOL_VERSION, CORE_VERSION.to_owned(), features.clone())),
+                     Some(features))
+                },
+                msg::Hello(version, _) =>
+                    (bare.copy_with(msg::Error(format!("incompatible protocol version {}, the core speaks {}",
+                                                       version, PROTOCOL_VERSION))), None),
+                _ => (bare.copy_with(msg::Error(~"expected Hello")), None)
+            }
+        },
+        Err(error) => (error, None)
+    };
+    if writer.send_reply(pack_remote_packet(reply)) { features } else { None }
+}
+
+// The feature a remote must have negotiated to send `cmd`, if any
+fn command_feature(cmd: &msg::Command) -> Option<&'static str> {
+    match *cmd {
+        msg::Login(..) => Some("login"),
+        msg::SessionCommand(session::msg::NetworkCommand(_, ref cmd)) => match *cmd {
+            network::msg::GetBuddyList | network::msg::SetBuddyList(..) => Some("buddy-list"),
+            network::msg::GetBufferMessageRange(..) => Some("backfill"),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+// The feature a remote must have negotiated to be sent the update `msg`, if any. Replies
+// need no check, their commands having been checked.
+fn message_feature(msg: &session::msg::Message) -> Option<&'static str> {
+    match *msg {
+        session::msg::NetworkMessage(_, network::msg::Presence(..)) => Some("buddy-list"),
+        _ => None
+    }
+}
+
+// Tells a session whether any remote is still attached to it, for auto-away
+fn update_detached(sessions: &HashMap<u64, SessionData>, remotes: &[RemoteData], session_id: u64) {
+    let attached = remotes.iter().any(|r| r.session_id == Some(session_id));
+    match sessions.find(&session_id) {
+        Some(sess) => sess.tx.send(Envelope::empty(session::msg::SetDetached(!attached))),
+        None       => ()
+    }
+}
+
+// Converts a packet in the remote's codec to protobuf and parses it
+fn parse_packet(packet: ~[u8], codec: Codec, schema: &protojson::Schema,
+                tag: u64) -> Result<Envelope<msg::Command>, Envelope<msg::Message>> {
+    let packet = match codec {
+        Protobuf => packet,
+        Json => {
+            let converted = match str::from_utf8(packet) {
+                Some(text) => schema.encode("RemoteCommand", text),
+                None       => Err(~"invalid UTF-8")
+            };
+            match converted {
+                Ok(packet) => packet,
+                Err(e) => return Err(Envelope { client_tag: None, remote_tag: Some(tag),
+                                                contents: msg::Error(format!("malformed packet: {}", e)) })
+            }
+        }
+    };
+    parse_remote_packet(packet, schema, tag)
+}
+
+// Parses a packet from the remote with the given tag. Invalid packets give the Error
+// to answer with, addressed with the packet's client tag if it could be read.
+fn parse_remote_packet(packet: ~[u8], schema: &protojson::Schema,
+                       tag: u64) -> Result<Envelope<msg::Command>, Envelope<msg::Message>> {
     use session;
 
     let SC = msg::SessionCommand;
     let NC = session::msg::NetworkCommand;
 
-    //println!("raw {}", packet);
-
-    //let mut rd = std::io::MemReader::new(packet);
-    //println!("eof {}", rd.eof());
-    //let mut stream = protobuf::CodedInputStream::new(&mut rd);
-    let mut cmd: protocol::RemoteCommand = protobuf::parse_from_bytes(packet);
-    //cmd.merge_from(&mut stream);
+    // The parser fails the task on malformed input, so the packet is checked first
+    match schema.validate("RemoteCommand", packet) {
+        Ok(()) => (),
+        Err(e) => return Err(Envelope { client_tag: None, remote_tag: Some(tag),
+                                        contents: msg::Error(format!("malformed packet: {}", e)) })
+    }
+    let cmd: protocol::RemoteCommand = protobuf::parse_from_bytes(packet);
 
-    //println!("packet_type {:?}", cmd.packet_type);
-    println!("packet {:?}", cmd);
+    let cli_tag = cmd.tag;
+    let invalid = Envelope { client_tag: cli_tag, remote_tag: Some(tag), contents: msg::Error(~"invalid packet") };
 
-    if cmd.packet_type.is_none() { return None }
+    if cmd.packet_type.is_none() { return Err(invalid) }
     let packet_type = cmd.packet_type.unwrap();
 
-    let cli_tag = cmd.tag;
-
     let out_cmd = match packet_type {
+        protocol::Hello => {
+            match cmd.hello {
+                Some(protocol::HelloT { protocol_version: Some(version), features, .. }) =>
+                    Some(msg::Hello(version, features)),
+                _ => None
+            }
+        },
+        protocol::Ping => {
+            match cmd.ping {
+                Some(protocol::PingT { id: Some(id), rtt_ms }) => Some(msg::Ping(id, rtt_ms)),
+                _ => None
+            }
+        },
+        protocol::Pong => {
+            match cmd.ping {
+                Some(protocol::PingT { id: Some(id), .. }) => Some(msg::Pong(id)),
+                _ => None
+            }
+        },
+        protocol::Login => {
+            match cmd.login {
+                Some(protocol::LoginT { name: Some(name), password: Some(password) }) =>
+                    Some(msg::Login(name, password)),
+                _ => None
+            }
+        },
         protocol::AttachSession => {
             match cmd.attach_session {
                 Some(protocol::AttachSessionT { session_id: Some(session_id) }) =>
@@ -283,6 +1059,31 @@ fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>
         protocol::GetNetworkList => {
             Some(msg::SessionCommand(session::msg::GetNetworkList))
         },
+        protocol::GetIdentityList => {
+            Some(SC(session::msg::GetIdentityList))
+        },
+        protocol::CreateIdentity => {
+            cmd.identity.and_then(identity_from_pbuf).map(|identity|
+                SC(session::msg::CreateIdentity(identity)))
+        },
+        protocol::UpdateIdentity => {
+            match cmd.identity {
+                Some(identity @ protocol::IdentityT { id: Some(_), .. }) =>
+                    identity_from_pbuf(identity).map(|identity| SC(session::msg::UpdateIdentity(identity))),
+                _ => None
+            }
+        },
+        protocol::SetClientAddress => {
+            match cmd.client_address {
+                Some(protocol::ClientAddressT { hostname: Some(hostname), ip: Some(ip) }) =>
+                    if valid_hostname(hostname.as_slice()) && from_str::<IpAddr>(ip.as_slice()).is_some() {
+                        Some(SC(session::msg::SetClientAddress(Some((hostname, ip)))))
+                    } else {
+                        None
+                    },
+                _ => Some(SC(session::msg::SetClientAddress(None)))
+            }
+        },
         protocol::Connect => {
             match cmd.network_id {
                 Some(nid) => Some(SC(NC(nid, network::msg::Connect))),
@@ -297,8 +1098,15 @@ fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>
         }
         protocol::JoinChannel => {
             match (cmd.network_id, cmd.join_channel) {
-                (Some(nid), Some(protocol::JoinChannelT { channel: Some(channel) })) =>
-                    Some(SC(NC(nid, network::msg::JoinChannel(channel)))),
+                (Some(nid), Some(protocol::JoinChannelT { channel: Some(channel), key })) =>
+                    Some(SC(NC(nid, network::msg::JoinChannel(channel, key)))),
+                _ => None
+            }
+        },
+        protocol::PartChannel => {
+            match (cmd.network_id, cmd.part_channel) {
+                (Some(nid), Some(protocol::PartChannelT { channel: Some(channel), message })) =>
+                    Some(SC(NC(nid, network::msg::PartChannel(channel, message)))),
                 _ => None
             }
         },
@@ -317,8 +1125,29 @@ fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>
         },
         protocol::SetNetworkConfiguration => {
             match (cmd.network_id, cmd.set_network_configuration) {
-                (Some(nid), Some(protocol::SetNetworkConfigurationT { server: Some(server), nickname: Some(nickname) })) =>
-                    Some(SC(NC(nid, network::msg::SetConfiguration(network::Configuration { server: server, nickname: nickname })))),
+                (Some(nid), Some(protocol::SetNetworkConfigurationT { server: Some(server), nickname: Some(nickname),
+                                                                      service_auth, identity_id, proxy,
+                                                                      bind_address, webirc })) => {
+                    // An empty address means none
+                    let bind_address = match bind_address {
+                        Some(ref a) if a.is_empty() => None,
+                        Some(a) => match from_str::<IpAddr>(a.as_slice()) {
+                            Some(ip) => Some(ip),
+                            None => return Err(Envelope { client_tag: cli_tag, remote_tag: Some(tag),
+                                                          contents: msg::Error(format!("invalid bind address: {}", a)) })
+                        },
+                        None => None
+                    };
+                    Some(SC(NC(nid, network::msg::SetConfiguration(network::Configuration {
+                        server: server,
+                        nickname: nickname,
+                        service_auth: service_auth.and_then(service_auth_from_pbuf),
+                        identity_id: identity_id,
+                        proxy: proxy.and_then(proxy_from_pbuf),
+                        bind_address: bind_address,
+                        webirc: webirc.and_then(webirc_from_pbuf)
+                    }))))
+                },
                 _ => None
             }
         },
@@ -328,6 +1157,56 @@ fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>
                 _ => None
             }
         },
+        protocol::GetPerformList => {
+            match cmd.network_id {
+                Some(nid) => Some(SC(NC(nid, network::msg::GetPerform))),
+                _ => None
+            }
+        },
+        protocol::SetPerformList => {
+            match (cmd.network_id, cmd.set_perform_list) {
+                (Some(nid), Some(protocol::PerformListT { commands, delay_ms })) =>
+                    Some(SC(NC(nid, network::msg::SetPerform(network::PerformList {
+                        commands: commands,
+                        delay_ms: delay_ms.unwrap_or(0) as u64
+                    })))),
+                _ => None
+            }
+        },
+        protocol::SetAway => {
+            match (cmd.network_id, cmd.set_away) {
+                (Some(nid), Some(protocol::SetAwayT { message })) =>
+                    Some(SC(NC(nid, network::msg::SetAway(message)))),
+                _ => None
+            }
+        },
+        protocol::SendCommand => {
+            match (cmd.network_id, cmd.send_command) {
+                (Some(nid), Some(protocol::SendCommandT { line: Some(line) })) =>
+                    Some(SC(NC(nid, network::msg::SendCommand(line)))),
+                _ => None
+            }
+        },
+        protocol::GetBuddyList => {
+            match cmd.network_id {
+                Some(nid) => Some(SC(NC(nid, network::msg::GetBuddyList))),
+                _ => None
+            }
+        },
+        protocol::SetBuddyList => {
+            match (cmd.network_id, cmd.set_buddy_list) {
+                (Some(nid), Some(protocol::BuddyListT { nicks })) =>
+                    Some(SC(NC(nid, network::msg::SetBuddyList(nicks)))),
+                _ => None
+            }
+        },
+        protocol::SetLastRead => {
+            match (cmd.network_id, cmd.buffer_id, cmd.set_last_read) {
+                (Some(nid), Some(bid), Some(protocol::SetLastReadT { message_id: Some(message_id) })) =>
+                    Some(SC(NC(nid, network::msg::SetLastRead(bid, message_id)))),
+                _ => None
+            }
+        },
         protocol::GetMessageRange => {
             match (cmd.network_id, cmd.buffer_id, cmd.get_message_range) {
                 (Some(nid), Some(bid), Some(protocol::GetMessageRangeT { count: Some(count), before_id })) =>
@@ -338,11 +1217,17 @@ fn parse_remote_packet(packet: ~[u8], tag: u64) -> Option<Envelope<msg::Command>
     };
 
     match out_cmd {
-        Some(out_cmd) => Some(Envelope { client_tag: cli_tag, remote_tag: Some(tag), contents: out_cmd }),
-        None => None
+        Some(out_cmd) => Ok(Envelope { client_tag: cli_tag, remote_tag: Some(tag), contents: out_cmd }),
+        None => Err(invalid)
     }
 }
 
+// Whether `hostname` can be sent as a WEBIRC parameter
+fn valid_hostname(hostname: &str) -> bool {
+    !hostname.is_empty() && !hostname.starts_with(":") &&
+        !hostname.chars().any(|c| c.is_whitespace() || c < ' ' || c == '\x7f')
+}
+
 fn role_to_pbuf(role: buffer::Role) -> protocol::BufferRole {
     match role {
         buffer::Status =>
@@ -371,19 +1256,169 @@ fn netstate_to_pbuf(state: network::State) -> protocol::NetworkListT_NetworkStat
     }
 }
 
+fn identity_from_pbuf(identity: protocol::IdentityT) -> Option<Identity> {
+    match identity {
+        protocol::IdentityT { id, ident: Some(ident), realname: Some(realname), alternate_nicks,
+                              quit_message, part_message, away_message, auto_away } =>
+            Some(Identity {
+                id: id.unwrap_or(0),
+                ident: ident,
+                realname: realname,
+                alternate_nicks: alternate_nicks,
+                quit_message: quit_message.unwrap_or(~""),
+                part_message: part_message.unwrap_or(~""),
+                away_message: away_message.unwrap_or(~""),
+                auto_away: auto_away.unwrap_or(false)
+            }),
+        _ => None
+    }
+}
+
+fn identity_to_pbuf(identity: Identity) -> protocol::IdentityT {
+    let Identity { id, ident, realname, alternate_nicks, quit_message, part_message, away_message,
+                   auto_away } = identity;
+    protocol::IdentityT {
+        id: Some(id),
+        ident: Some(ident),
+        realname: Some(realname),
+        alternate_nicks: alternate_nicks,
+        quit_message: Some(quit_message),
+        part_message: Some(part_message),
+        away_message: Some(away_message),
+        auto_away: Some(auto_away)
+    }
+}
+
+fn service_auth_from_pbuf(auth: protocol::ServiceAuthT) -> Option<network::ServiceAuth> {
+    match auth {
+        // An unset password keeps the stored one, see Configuration::keep_passwords
+        protocol::ServiceAuthT { service: Some(service), command: Some(command), password, .. } =>
+            Some(network::ServiceAuth { service: service, command: command, password: password.unwrap_or(~"") }),
+        _ => None
+    }
+}
+
+fn webirc_from_pbuf(webirc: protocol::WebIrcT) -> Option<network::WebIrc> {
+    match webirc {
+        protocol::WebIrcT { password, gateway: Some(gateway), .. } =>
+            Some(network::WebIrc { password: password.unwrap_or(~""), gateway: gateway }),
+        _ => None
+    }
+}
+
+fn proxy_from_pbuf(proxy: protocol::ProxyT) -> Option<irc::proxy::Proxy> {
+    match proxy {
+        protocol::ProxyT { proxy_type: Some(kind), host: Some(host), port: Some(port), username, password, .. } => {
+            let credentials = username.map(|username| (username, password.unwrap_or(~"")));
+            Some(irc::proxy::Proxy {
+                kind: match kind { protocol::Socks5 => irc::proxy::Socks5, protocol::HttpConnect => irc::proxy::HttpConnect },
+                host: host,
+                port: port as u16,
+                credentials: credentials
+            })
+        },
+        _ => None
+    }
+}
+
+fn proxy_to_pbuf(proxy: irc::proxy::Proxy) -> protocol::ProxyT {
+    let (username, password_set) = match proxy.credentials {
+        Some((username, password)) => (Some(username), Some(!password.is_empty())),
+        None                       => (None, None)
+    };
+    protocol::ProxyT {
+        proxy_type: Some(match proxy.kind { irc::proxy::Socks5 => protocol::Socks5, irc::proxy::HttpConnect => protocol::HttpConnect }),
+        host: Some(proxy.host),
+        port: Some(proxy.port as u32),
+        username: username,
+        password: None,
+        password_set: password_set
+    }
+}
+
 fn netconfig_to_pbuf(config: Option<network::Configuration>) -> Option<protocol::NetworkConfigurationT> {
     match config {
         None => None,
         Some(config) => {
-            let network::Configuration { server, nickname } = config;
+            let network::Configuration { server, nickname, service_auth, identity_id, proxy, bind_address,
+                                         webirc } = config;
             Some(protocol::NetworkConfigurationT {
                 server: Some(server.to_str()),
-                nickname: Some(nickname)
+                nickname: Some(nickname),
+                service_auth: service_auth.map(|auth| protocol::ServiceAuthT {
+                    service: Some(auth.service),
+                    command: Some(auth.command),
+                    password: None,
+                    password_set: Some(!auth.password.is_empty())
+                }),
+                identity_id: identity_id,
+                proxy: proxy.map(proxy_to_pbuf),
+                bind_address: bind_address.map(|a| a.to_str()),
+                webirc: webirc.map(|w| protocol::WebIrcT { password: None, gateway: Some(w.gateway),
+                                                           password_set: Some(!w.password.is_empty()) })
             })
         }
     }
 }
 
+fn user_to_pbuf(user: User) -> protocol::UserT {
+    protocol::UserT {
+        nick: Some(user.nick),
+        ident: user.ident,
+        host: user.host,
+        realname: user.realname,
+        account: user.account,
+        away: user.away
+    }
+}
+
+fn snapshot_to_pbuf(snapshot: network::Snapshot) -> protocol::NetworkSnapshotT {
+    let network::Snapshot { id, state, configuration, nickname, away, buffers } = snapshot;
+    protocol::NetworkSnapshotT {
+        id: Some(id),
+        state: Some(netstate_to_pbuf(state)),
+        configuration: netconfig_to_pbuf(configuration),
+        nickname: nickname,
+        away: away,
+        buffers: buffers.move_iter().map(|buf| protocol::BufferSnapshotT {
+            id: Some(buf.id),
+            role: Some(role_to_pbuf(buf.role)),
+            stored_messages: Some(buf.stored_messages),
+            last_read: buf.last_read,
+            unread: Some(buf.unread),
+            topic: buf.topic,
+            members: buf.members.move_iter().map(|(user, prefixes)| protocol::ChannelMemberT {
+                user: Some(user_to_pbuf(user)),
+                prefixes: Some(prefixes)
+            }).collect()
+        }).collect()
+    }
+}
+
+fn message_to_pbuf(msg: buffer::Message) -> protocol::MessageRangeT {
+    let mut ppmsg = protocol::MessageRangeT {
+        id: Some(msg.id),
+        time: Some(msg.time_ns),
+        information: None,
+        join: None,
+        privmsg: None,
+        msgid: msg.msgid,
+        account: msg.account,
+        prefix: msg.prefix
+    };
+
+    match msg.contents {
+        buffer::Information(m) => ppmsg.information =
+            Some(protocol::InformationT { msg: Some(m) }),
+        buffer::Join(who) => ppmsg.join =
+            Some(protocol::JoinT { who: Some(who) }),
+        buffer::Privmsg(who, m) => ppmsg.privmsg =
+            Some(protocol::PrivmsgT { who: Some(who), msg: Some(m) }),
+    }
+
+    ppmsg
+}
+
 /* refactor: make trait RemotePackable and implement for all message enum types */
 
 fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
@@ -399,6 +1434,23 @@ fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
         msg::Success => {
             pmsg.set_packet_type(protocol::Success);
         },
+        msg::Welcome(version, core_version, features) => {
+            pmsg.set_packet_type(protocol::Welcome);
+            pmsg.set_welcome(protocol::WelcomeT { protocol_version: Some(version),
+                                                  core_version: Some(core_version),
+                                                  features: features });
+        },
+        msg::Resync => {
+            pmsg.set_packet_type(protocol::Resync);
+        },
+        msg::Heartbeat(id, rtt_ms) => {
+            pmsg.set_packet_type(protocol::Heartbeat);
+            pmsg.set_ping(protocol::PingT { id: Some(id), rtt_ms: rtt_ms });
+        },
+        msg::HeartbeatReply(id, rtt_ms) => {
+            pmsg.set_packet_type(protocol::HeartbeatReply);
+            pmsg.set_ping(protocol::PingT { id: Some(id), rtt_ms: rtt_ms });
+        },
         msg::SessionMessage(msg) => {
             match msg {
                 session::msg::Error(err) => {
@@ -417,6 +1469,18 @@ fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
                         });
                     }
                 },
+                session::msg::Snapshot(networks) => {
+                    pmsg.set_packet_type(protocol::Snapshot);
+                    pmsg.set_snapshot(protocol::SnapshotT {
+                        networks: networks.move_iter().map(snapshot_to_pbuf).collect()
+                    });
+                },
+                session::msg::IdentityList(identities) => {
+                    pmsg.set_packet_type(protocol::IdentityList);
+                    for identity in identities.move_iter() {
+                        pmsg.add_identity_list(identity_to_pbuf(identity));
+                    }
+                },
                 session::msg::NetworkMessage(nid, msg) => {
                     pmsg.set_network_id(nid);
                     match msg {
@@ -455,6 +1519,9 @@ fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
                             pmsg.set_buffer_id(bufid);
                             pmsg.set_message_id(msg.id);
                             pmsg.set_message_time(msg.time_ns);
+                            pmsg.message_msgid = msg.msgid;
+                            pmsg.message_account = msg.account;
+                            pmsg.message_prefix = msg.prefix;
                             match msg.contents {
                                 buffer::Information(info) => {
                                     pmsg.set_packet_type(protocol::Information);
@@ -474,29 +1541,56 @@ fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
                             pmsg.set_packet_type(protocol::NetworkConfiguration);
                             pmsg.network_configuration = netconfig_to_pbuf(config);
                         },
+                        network::msg::Perform(perform) => {
+                            pmsg.set_packet_type(protocol::PerformList);
+                            pmsg.set_perform_list(protocol::PerformListT {
+                                commands: perform.commands,
+                                delay_ms: Some(perform.delay_ms as u32)
+                            });
+                        },
+                        network::msg::AwayState(message) => {
+                            pmsg.set_packet_type(protocol::AwayState);
+                            pmsg.set_away_state(protocol::AwayStateT { message: message });
+                        },
+                        network::msg::CommandReply(lines) => {
+                            pmsg.set_packet_type(protocol::CommandReply);
+                            pmsg.set_command_reply(protocol::CommandReplyT { lines: lines });
+                        },
+                        network::msg::BuddyList(nicks) => {
+                            pmsg.set_packet_type(protocol::BuddyList);
+                            pmsg.set_buddy_list(protocol::BuddyListT { nicks: nicks });
+                        },
+                        network::msg::Presence(nick, online) => {
+                            pmsg.set_packet_type(protocol::Presence);
+                            pmsg.set_presence(protocol::PresenceT { nick: Some(nick), online: Some(online) });
+                        },
+                        network::msg::UserUpdate(user) => {
+                            pmsg.set_packet_type(protocol::UserUpdate);
+                            pmsg.set_user_update(user_to_pbuf(user));
+                        },
+                        network::msg::Topic(channel, topic) => {
+                            pmsg.set_packet_type(protocol::Topic);
+                            pmsg.set_topic(protocol::TopicT { channel: Some(channel), topic: topic });
+                        },
+                        network::msg::LastRead(bid, message_id) => {
+                            pmsg.set_packet_type(protocol::LastRead);
+                            pmsg.set_buffer_id(bid);
+                            pmsg.set_message_id(message_id);
+                        },
                         network::msg::BufferMessageRange(bid, msgs) => {
                             pmsg.set_packet_type(protocol::MessageRange);
                             pmsg.set_buffer_id(bid);
 
                             for msg in msgs.move_iter() {
-                                let mut ppmsg = protocol::MessageRangeT {
-                                    id: Some(msg.id),
-                                    time: Some(msg.time_ns),
-                                    information: None,
-                                    join: None,
-                                    privmsg: None
-                                };
-
-                                match msg.contents {
-                                    buffer::Information(m) => ppmsg.information =
-                                        Some(protocol::InformationT { msg: Some(m) }),
-                                    buffer::Join(who) => ppmsg.join =
-                                        Some(protocol::JoinT { who: Some(who) }),
-                                    buffer::Privmsg(who, m) => ppmsg.privmsg =
-                                        Some(protocol::PrivmsgT { who: Some(who), msg: Some(m) }),
-                                }
+                                pmsg.add_message_range(message_to_pbuf(msg));
+                            }
+                        },
+                        network::msg::BufferBackfill(bid, msgs) => {
+                            pmsg.set_packet_type(protocol::Backfill);
+                            pmsg.set_buffer_id(bid);
 
-                                pmsg.add_message_range(ppmsg);
+                            for msg in msgs.move_iter() {
+                                pmsg.add_message_range(message_to_pbuf(msg));
                             }
                         }
                     }
@@ -506,12 +1600,61 @@ fn pack_remote_packet(msg: Envelope<msg::Message>) -> ~[u8] {
     }
 
     if pmsg.is_initialized() {
-        println!("OUT >> {:?}", pmsg);
-        let out_str = pmsg.write_to_bytes();
-        //println!("OUTBUF {}", out_str);
-        out_str
+        pmsg.write_to_bytes()
     } else {
         println!("FAIL: output empty");
         ~[]
     }
 }
+
+#[test]
+fn test_credentials_account() {
+    let mut accounts = HashMap::new();
+    let mut account = Account::new(~"q2", "hunter2");
+    account.id = 1;
+    account.certificate_fingerprint = Some(~[1u8, 2, 3]);
+    accounts.insert(account.name.clone(), account.clone());
+    account.id = 2;
+    account.name = ~"local";
+    account.certificate_fingerprint = None;
+    account.unix_uid = Some(1000);
+    accounts.insert(account.name.clone(), account);
+
+    let credentials = |fingerprint: Option<~[u8]>, unix_uid: Option<u32>|
+        PeerCredentials { fingerprint: fingerprint, certificate_required: false, unix_uid: unix_uid };
+    assert_eq!(credentials_account(&accounts, &credentials(Some(~[1u8, 2, 3]), None)), Some(1));
+    assert_eq!(credentials_account(&accounts, &credentials(Some(~[1u8, 2, 4]), None)), None);
+    assert_eq!(credentials_account(&accounts, &credentials(None, Some(1000))), Some(2));
+    assert_eq!(credentials_account(&accounts, &credentials(None, Some(1001))), None);
+    // No certificate doesn't match accounts without one
+    assert_eq!(credentials_account(&accounts, &credentials(None, None)), None);
+}
+
+#[test]
+fn test_write_error() {
+    use std::io::net::tcp::TcpListener;
+
+    let mut acceptor = TcpListener::bind(from_str::<SocketAddr>("127.0.0.1:0").unwrap()).unwrap();
+    let addr = acceptor.socket_name().unwrap();
+    let mut acceptor = acceptor.listen().unwrap();
+    let mut client = TcpStream::connect(addr).unwrap();
+
+    let stream = Plain(acceptor.accept().unwrap());
+
+    let schema = Arc::new(protojson::Schema::parse(include_str!("protocol.proto")).unwrap());
+    let (_tx, rx) = channel();
+    let mut remote = RemoteData { rx: rx, stream: stream.clone(),
+                                  writer: RemoteWriter::spawn(stream, LengthPrefixed, schema),
+                                  lagging_since: None, session_id: None, account_id: Some(1),
+                                  login_pending: false, failed_logins: 0, features: ~[], last_activity: 0,
+                                  connected_at: 0, pending_heartbeats: ~[], missed_heartbeats: 0,
+                                  rtt_ms: None, synced: false, tag: 1 };
+    let bare = Envelope { client_tag: Some(7), remote_tag: Some(1), contents: () };
+    remote.write_error(&bare, ~"No session attached");
+
+    let len = client.read_le_u32().unwrap() as uint;
+    let reply: protocol::RemoteMessage = protobuf::parse_from_bytes(client.read_bytes(len).unwrap());
+    assert_eq!(reply.tag, Some(7));
+    assert!(reply.packet_type == Some(protocol::Error));
+    assert_eq!(reply.error.unwrap().msg, Some(~"No session attached"));
+}
@@ -3,38 +3,56 @@ use std;
 use network;
 use envelope::Envelope;
 use database;
+use identity::Identity;
 
 pub struct Session {
+    id: u64,
     db: database::Handle,
     networks: HashMap<u64, network::Network>,
+    identities: HashMap<u64, Identity>,
+    detached: bool, // no remote is attached to this session
+    client_address: Option<(~str, ~str)>, // hostname and IP forwarded to WEBIRC gateways
     message_tx: Sender<Envelope<msg::Message>>,
     command_rx: Receiver<Envelope<msg::Command>>
 }
 
 pub mod msg {
     use network;
+    use identity::Identity;
 
     pub enum Command {
         NetworkCommand(u64, network::msg::Command),
-        GetNetworkList
+        GetNetworkList,
+        GetSnapshot, // sent by remote control when a remote attaches
+        GetIdentityList,
+        CreateIdentity(Identity),
+        UpdateIdentity(Identity),
+        SetClientAddress(Option<(~str, ~str)>), // hostname, IP
+        SetDetached(bool) // sent by remote control, not remotes
     }
 
     pub enum Message {
         NetworkMessage(u64, network::msg::Message),
         NetworkList(~[(u64, network::State)]),
+        Snapshot(~[network::Snapshot]),
+        IdentityList(~[Identity]),
         Error(~str),
         Success
     }
 }
 
 impl Session {
-    pub fn new(db: database::Handle) ->
+    pub fn new(id: u64, db: database::Handle) ->
             (Session, Sender<Envelope<msg::Command>>, Receiver<Envelope<msg::Message>>) {
         let (message_tx, message_rx) = channel();
         let (command_tx, command_rx) = channel();
         (Session {
+            id: id,
             db: db,
             networks: HashMap::new(),
+            identities: HashMap::new(),
+            detached: true,
+            client_address: None,
             message_tx: message_tx,
             command_rx: command_rx
          }, command_tx, message_rx)
@@ -42,6 +60,10 @@ impl Session {
 
     pub fn run(&mut self) {
         enum MessageSource { FromNetwork(u64), FromRemote };
+        for (_, nw) in self.networks.mut_iter() {
+            nw.client_address = self.client_address.clone();
+            nw.set_detached(self.detached);
+        }
         loop {
             let source = {
                 let sel = std::comm::Select::new();
@@ -67,7 +89,7 @@ impl Session {
                 FromRemote => self.handle_command(),
                 FromNetwork(i) => {
                     let &Session { ref mut networks, ref message_tx, .. } = self;
-                    networks.get_mut(&i).handle_message(|msg| message_tx.send(Envelope::empty(msg::NetworkMessage(i, msg))))
+                    networks.get_mut(&i).handle_message(|msg| message_tx.send(msg.encapsulate(|m| msg::NetworkMessage(i, m))))
                 }
             }
         }
@@ -83,15 +105,74 @@ impl Session {
 
         match msg.contents {
             msg::NetworkCommand(id, cmd) => {
-                let &Session { ref mut networks, ref message_tx, .. } = self;
+                let &Session { ref mut networks, ref identities, ref message_tx, ref detached, .. } = self;
                 match networks.find_mut(&id) {
-                    Some(nw) => nw.handle_command(bare.copy_with(cmd), |msg| message_tx.send(msg.encapsulate(|m| msg::NetworkMessage(id, m)))),
+                    Some(nw) => {
+                        // Networks keep their own copy of their identity
+                        match cmd {
+                            network::msg::SetConfiguration(ref cfg) => {
+                                match cfg.identity_id {
+                                    Some(iid) if !identities.contains_key(&iid) => {
+                                        message_tx.send(bare.copy_with(msg::Error(~"invalid identity")));
+                                        return;
+                                    },
+                                    _ => ()
+                                }
+                                nw.identity = cfg.identity_id.map(|iid| identities.get(&iid).clone());
+                                nw.set_detached(*detached);
+                            },
+                            _ => ()
+                        }
+                        nw.handle_command(bare.copy_with(cmd), |msg| message_tx.send(msg.encapsulate(|m| msg::NetworkMessage(id, m))))
+                    },
                     None     => println!("Remote used invalid network id")
                 }
             },
             msg::GetNetworkList => {
                 let net_list = self.networks.iter().map(|(id, net)| (*id, net.state)).collect();
                 self.message_tx.send(msg.copy_with(msg::NetworkList(net_list)));
+            },
+            msg::GetSnapshot => {
+                let snapshot = self.networks.mut_iter().map(|(_, net)| net.snapshot()).collect();
+                self.message_tx.send(bare.copy_with(msg::Snapshot(snapshot)));
+            },
+            msg::GetIdentityList => {
+                let identities = self.identities.values().map(|i| i.clone()).collect();
+                self.message_tx.send(bare.copy_with(msg::IdentityList(identities)));
+            },
+            msg::CreateIdentity(mut identity) => {
+                identity.id = self.db.create_identity(self.id, &identity);
+                self.identities.insert(identity.id, identity.clone());
+                self.message_tx.send(bare.copy_with(msg::IdentityList(~[identity])));
+            },
+            msg::UpdateIdentity(identity) => {
+                if !self.identities.contains_key(&identity.id) {
+                    self.message_tx.send(bare.copy_with(msg::Error(~"invalid identity")));
+                    return;
+                }
+                self.db.update_identity(&identity);
+                for (_, nw) in self.networks.mut_iter() {
+                    if nw.config.as_ref().and_then(|c| c.identity_id) == Some(identity.id) {
+                        nw.identity = Some(identity.clone());
+                        nw.set_detached(self.detached);
+                    }
+                }
+                self.identities.insert(identity.id, identity);
+                self.message_tx.send(bare.copy_with(msg::Success));
+            },
+            msg::SetClientAddress(address) => {
+                self.db.update_session_client_address(self.id, &address);
+                for (_, nw) in self.networks.mut_iter() {
+                    nw.client_address = address.clone();
+                }
+                self.client_address = address;
+                self.message_tx.send(bare.copy_with(msg::Success));
+            },
+            msg::SetDetached(detached) => {
+                self.detached = detached;
+                for (_, nw) in self.networks.mut_iter() {
+                    nw.set_detached(detached);
+                }
             }
         }
     }
@@ -0,0 +1,273 @@
+// TLS for remote connections through OpenSSL. The SSL object talks to memory BIOs and
+// we move the bytes between them and the socket ourselves, as std's TcpStream doesn't
+// expose its descriptor.
+
+use std::io::{IoResult, IoError, OtherIoError, EndOfFile, standard_error};
+use std::io::net::tcp::TcpStream;
+use std::libc::{c_int, c_char, c_void, c_ulong};
+use std::ptr;
+use std::str;
+use sync::{Arc, MutexArc};
+use crypto;
+
+#[allow(non_camel_case_types)]
+mod ffi {
+    use std::libc::{c_int, c_char, c_void, c_ulong};
+
+    pub type SSL_CTX = c_void;
+    pub type SSL = c_void;
+    pub type SSL_METHOD = c_void;
+    pub type BIO = c_void;
+    pub type BIO_METHOD = c_void;
+    pub type X509 = c_void;
+
+    pub static SSL_FILETYPE_PEM: c_int = 1;
+    pub static SSL_VERIFY_PEER: c_int = 1;
+    pub static SSL_VERIFY_FAIL_IF_NO_PEER_CERT: c_int = 2;
+    pub static SSL_ERROR_WANT_READ: c_int = 2;
+    pub static SSL_ERROR_ZERO_RETURN: c_int = 6;
+
+    #[link(name = "ssl")]
+    #[link(name = "crypto")]
+    extern {
+        pub fn OPENSSL_init_ssl(opts: u64, settings: *c_void) -> c_int;
+        pub fn TLS_server_method() -> *SSL_METHOD;
+        pub fn SSL_CTX_new(method: *SSL_METHOD) -> *mut SSL_CTX;
+        pub fn SSL_CTX_free(ctx: *mut SSL_CTX);
+        pub fn SSL_CTX_use_certificate_chain_file(ctx: *mut SSL_CTX, file: *c_char) -> c_int;
+        pub fn SSL_CTX_use_PrivateKey_file(ctx: *mut SSL_CTX, file: *c_char, kind: c_int) -> c_int;
+        pub fn SSL_CTX_check_private_key(ctx: *mut SSL_CTX) -> c_int;
+        pub fn SSL_CTX_set_verify(ctx: *mut SSL_CTX, mode: c_int,
+                                  callback: extern "C" fn(c_int, *mut c_void) -> c_int);
+
+        pub fn SSL_new(ctx: *mut SSL_CTX) -> *mut SSL;
+        pub fn SSL_free(ssl: *mut SSL);
+        pub fn SSL_set_bio(ssl: *mut SSL, rbio: *mut BIO, wbio: *mut BIO);
+        pub fn SSL_set_accept_state(ssl: *mut SSL);
+        pub fn SSL_do_handshake(ssl: *mut SSL) -> c_int;
+        pub fn SSL_read(ssl: *mut SSL, buf: *mut c_void, num: c_int) -> c_int;
+        pub fn SSL_write(ssl: *mut SSL, buf: *c_void, num: c_int) -> c_int;
+        pub fn SSL_get_error(ssl: *mut SSL, ret: c_int) -> c_int;
+        pub fn SSL_get_peer_certificate(ssl: *mut SSL) -> *mut X509;
+
+        pub fn BIO_s_mem() -> *BIO_METHOD;
+        pub fn BIO_new(method: *BIO_METHOD) -> *mut BIO;
+        pub fn BIO_read(bio: *mut BIO, buf: *mut c_void, len: c_int) -> c_int;
+        pub fn BIO_write(bio: *mut BIO, buf: *c_void, len: c_int) -> c_int;
+
+        pub fn i2d_X509(x509: *mut X509, out: *mut *mut u8) -> c_int;
+        pub fn X509_free(x509: *mut X509);
+
+        pub fn ERR_get_error() -> c_ulong;
+        pub fn ERR_error_string_n(e: c_ulong, buf: *mut c_char, len: uint);
+    }
+}
+
+// Client certificates are checked against account fingerprints rather than a CA, so
+// any certificate is accepted during the handshake
+extern "C" fn accept_any_certificate(_preverify_ok: c_int, _ctx: *mut c_void) -> c_int {
+    1
+}
+
+fn tls_error(desc: &'static str) -> IoError {
+    let e = unsafe { ffi::ERR_get_error() };
+    let detail = if e == 0 {
+        None
+    } else {
+        let mut buf = [0 as c_char, ..256];
+        unsafe {
+            ffi::ERR_error_string_n(e as c_ulong, buf.as_mut_ptr(), buf.len());
+            Some(str::raw::from_c_str(buf.as_ptr()))
+        }
+    };
+    IoError { kind: OtherIoError, desc: desc, detail: detail }
+}
+
+struct ContextPtr {
+    ctx: *mut ffi::SSL_CTX
+}
+
+impl Drop for ContextPtr {
+    fn drop(&mut self) {
+        unsafe { ffi::SSL_CTX_free(self.ctx); }
+    }
+}
+
+/// Server-side TLS settings shared by all remote connections
+#[deriving(Clone)]
+pub struct Context {
+    priv ctx: Arc<ContextPtr>,
+    priv require_client_certificate: bool
+}
+
+impl Context {
+    /// Loads a PEM certificate chain and private key. Remotes are asked for a certificate,
+    /// whose fingerprint is available through `TlsStream::peer_fingerprint`; with
+    /// `require_client_certificate` they have to present one.
+    pub fn new(certificate: &str, key: &str, require_client_certificate: bool) -> IoResult<Context> {
+        unsafe {
+            ffi::OPENSSL_init_ssl(0, ptr::null());
+            let ctx = ffi::SSL_CTX_new(ffi::TLS_server_method());
+            if ctx.is_null() {
+                return Err(tls_error("failed to create TLS context"));
+            }
+            let context = Context { ctx: Arc::new(ContextPtr { ctx: ctx }),
+                                    require_client_certificate: require_client_certificate };
+
+            if certificate.with_c_str(|c| ffi::SSL_CTX_use_certificate_chain_file(ctx, c)) != 1 {
+                return Err(tls_error("failed to load TLS certificate"));
+            }
+            if key.with_c_str(|k| ffi::SSL_CTX_use_PrivateKey_file(ctx, k, ffi::SSL_FILETYPE_PEM)) != 1 ||
+               ffi::SSL_CTX_check_private_key(ctx) != 1 {
+                return Err(tls_error("failed to load TLS key"));
+            }
+            // Without SSL_VERIFY_PEER no certificate is requested at all, so it is set even
+            // when one is optional
+            let mode = if require_client_certificate {
+                ffi::SSL_VERIFY_PEER | ffi::SSL_VERIFY_FAIL_IF_NO_PEER_CERT
+            } else {
+                ffi::SSL_VERIFY_PEER
+            };
+            ffi::SSL_CTX_set_verify(ctx, mode, accept_any_certificate);
+            Ok(context)
+        }
+    }
+
+    pub fn requires_client_certificate(&self) -> bool {
+        self.require_client_certificate
+    }
+
+    /// Performs the server side of the handshake on `stream`.
+    pub fn accept(&self, stream: TcpStream) -> IoResult<TlsStream> {
+        let session = unsafe {
+            let ssl = ffi::SSL_new(self.ctx.get().ctx);
+            if ssl.is_null() {
+                return Err(tls_error("failed to create TLS session"));
+            }
+            let rbio = ffi::BIO_new(ffi::BIO_s_mem());
+            let wbio = ffi::BIO_new(ffi::BIO_s_mem());
+            ffi::SSL_set_bio(ssl, rbio, wbio);
+            ffi::SSL_set_accept_state(ssl);
+            Session { ssl: ssl, rbio: rbio, wbio: wbio, out: stream.clone() }
+        };
+        let mut tls = TlsStream { session: MutexArc::new(session), stream: stream };
+
+        loop {
+            let result = tls.session.access(|s| {
+                let ret = unsafe { ffi::SSL_do_handshake(s.ssl) };
+                let err = if ret == 1 { 0 } else { unsafe { ffi::SSL_get_error(s.ssl, ret) } };
+                s.flush().map(|_| (ret, err))
+            });
+            match try!(result) {
+                (1, _) => return Ok(tls),
+                (_, ffi::SSL_ERROR_WANT_READ) => try!(tls.receive()),
+                _ => return Err(tls_error("TLS handshake failed"))
+            }
+        }
+    }
+}
+
+struct Session {
+    ssl: *mut ffi::SSL, // owns the BIOs
+    rbio: *mut ffi::BIO, // ciphertext from the socket
+    wbio: *mut ffi::BIO, // ciphertext for the socket
+    out: TcpStream
+}
+
+impl Session {
+    // Sends whatever OpenSSL has queued for the peer
+    fn flush(&mut self) -> IoResult<()> {
+        let mut buf = [0u8, ..4096];
+        loop {
+            let n = unsafe { ffi::BIO_read(self.wbio, buf.as_mut_ptr() as *mut c_void, buf.len() as c_int) };
+            if n <= 0 {
+                return Ok(());
+            }
+            try!(self.out.write(buf.slice_to(n as uint)));
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        unsafe { ffi::SSL_free(self.ssl); }
+    }
+}
+
+/// A TLS connection. Clones share the session, so one task can read while others write.
+#[deriving(Clone)]
+pub struct TlsStream {
+    priv session: MutexArc<Session>,
+    priv stream: TcpStream // read side, used without holding the session lock
+}
+
+impl TlsStream {
+    // Reads ciphertext from the socket into the session
+    fn receive(&mut self) -> IoResult<()> {
+        let mut buf = [0u8, ..4096];
+        let n = try!(self.stream.read(buf));
+        self.session.access(|s| {
+            unsafe { ffi::BIO_write(s.rbio, buf.as_ptr() as *c_void, n as c_int); }
+        });
+        Ok(())
+    }
+
+    /// Makes pending and future reads fail.
+    pub fn close_read(&mut self) -> IoResult<()> {
+        self.stream.close_read()
+    }
+
+    /// SHA-256 of the peer certificate in DER form, if the peer sent one.
+    pub fn peer_fingerprint(&self) -> Option<~[u8]> {
+        self.session.access(|s| unsafe {
+            let cert = ffi::SSL_get_peer_certificate(s.ssl);
+            if cert.is_null() {
+                return None;
+            }
+            let len = ffi::i2d_X509(cert, ptr::mut_null());
+            let mut der = ~[];
+            if len > 0 {
+                der.reserve(len as uint);
+                der.set_len(len as uint);
+                let mut p = der.as_mut_ptr();
+                ffi::i2d_X509(cert, &mut p);
+            }
+            ffi::X509_free(cert);
+            if der.is_empty() { None } else { Some(crypto::sha256(der)) }
+        })
+    }
+}
+
+impl Reader for TlsStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        loop {
+            let result = self.session.access(|s| {
+                let ret = unsafe { ffi::SSL_read(s.ssl, buf.as_mut_ptr() as *mut c_void, buf.len() as c_int) };
+                let err = if ret > 0 { 0 } else { unsafe { ffi::SSL_get_error(s.ssl, ret) } };
+                s.flush().map(|_| (ret, err))
+            });
+            match try!(result) {
+                (n, _) if n > 0 => return Ok(n as uint),
+                (_, ffi::SSL_ERROR_WANT_READ) => try!(self.receive()),
+                (_, ffi::SSL_ERROR_ZERO_RETURN) => return Err(standard_error(EndOfFile)),
+                _ => return Err(tls_error("TLS read failed"))
+            }
+        }
+    }
+}
+
+impl Writer for TlsStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+        self.session.access(|s| {
+            // Memory BIOs take everything, so the write is never partial
+            let ret = unsafe { ffi::SSL_write(s.ssl, buf.as_ptr() as *c_void, buf.len() as c_int) };
+            if ret <= 0 {
+                return Err(tls_error("TLS write failed"));
+            }
+            s.flush()
+        })
+    }
+}
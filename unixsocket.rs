@@ -0,0 +1,241 @@
+// Unix domain sockets through libc, as std's UnixStream can't report the peer's
+// credentials or shut down its read side
+
+use std::io::{IoResult, IoError, EndOfFile, OtherIoError, PathAlreadyExists, standard_error};
+use std::io::fs;
+use std::io::FilePermission;
+use std::libc;
+use std::mem;
+use std::os;
+use std::ptr;
+use sync::Arc;
+
+#[allow(non_camel_case_types)]
+mod ffi {
+    use std::libc::uid_t;
+
+    pub static S_IFMT: u32 = 0xf000;
+    pub static S_IFSOCK: u32 = 0xc000;
+
+    extern {
+        pub fn getuid() -> uid_t;
+    }
+
+    #[cfg(target_os = "linux")]
+    pub mod linux {
+        use std::libc::{c_int, c_void, socklen_t, pid_t, uid_t, gid_t};
+
+        pub static SO_PEERCRED: c_int = 17;
+
+        pub struct ucred {
+            pid: pid_t,
+            uid: uid_t,
+            gid: gid_t
+        }
+
+        extern {
+            pub fn getsockopt(fd: c_int, level: c_int, name: c_int, value: *mut c_void,
+                              len: *mut socklen_t) -> c_int;
+        }
+    }
+}
+
+// Whether the last call failed because a signal arrived before it did anything
+fn interrupted() -> bool {
+    os::errno() as libc::c_int == libc::EINTR
+}
+
+struct Fd {
+    fd: libc::c_int
+}
+
+impl Drop for Fd {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.fd); }
+    }
+}
+
+/// A connected socket. Clones share the descriptor, which is closed with the last clone.
+#[deriving(Clone)]
+pub struct UnixStream {
+    priv fd: Arc<Fd>
+}
+
+impl UnixStream {
+    /// Makes pending and future reads fail.
+    pub fn close_read(&mut self) -> IoResult<()> {
+        if unsafe { libc::shutdown(self.fd.get().fd, libc::SHUT_RD) } < 0 {
+            return Err(IoError::last_error());
+        }
+        Ok(())
+    }
+
+    /// The user id of the process at the other end, as of when it connected.
+    #[cfg(target_os = "linux")]
+    pub fn peer_uid(&self) -> IoResult<u32> {
+        use self::ffi::linux;
+        unsafe {
+            let mut cred: linux::ucred = mem::init();
+            let mut len = mem::size_of::<linux::ucred>() as libc::socklen_t;
+            if linux::getsockopt(self.fd.get().fd, libc::SOL_SOCKET, linux::SO_PEERCRED,
+                                 &mut cred as *mut linux::ucred as *mut libc::c_void, &mut len) < 0 {
+                return Err(IoError::last_error());
+            }
+            Ok(cred.uid as u32)
+        }
+    }
+
+    /// Peer credentials are only read on Linux, elsewhere this always fails.
+    #[cfg(not(target_os = "linux"))]
+    pub fn peer_uid(&self) -> IoResult<u32> {
+        Err(IoError { kind: OtherIoError, desc: "peer credentials are only supported on Linux", detail: None })
+    }
+}
+
+impl Reader for UnixStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        loop {
+            let n = unsafe {
+                libc::read(self.fd.get().fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len() as libc::size_t)
+            };
+            return match n {
+                n if n < 0 && interrupted() => continue,
+                n if n < 0 => Err(IoError::last_error()),
+                0          => Err(standard_error(EndOfFile)),
+                n          => Ok(n as uint)
+            };
+        }
+    }
+}
+
+impl Writer for UnixStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        let mut written = 0;
+        while written < buf.len() {
+            let rest = buf.slice_from(written);
+            let n = unsafe {
+                libc::write(self.fd.get().fd, rest.as_ptr() as *libc::c_void, rest.len() as libc::size_t)
+            };
+            if n < 0 {
+                if interrupted() {
+                    continue;
+                }
+                return Err(IoError::last_error());
+            }
+            written += n as uint;
+        }
+        Ok(())
+    }
+}
+
+/// A listening socket. The socket file is removed when it is dropped.
+pub struct UnixAcceptor {
+    priv fd: Fd,
+    priv path: Path
+}
+
+impl UnixAcceptor {
+    /// Listens on `path`, replacing any socket file left there, with `mode` as the
+    /// file's permissions. Connecting needs write permission on it. Fails if something
+    /// other than a socket is at `path`.
+    pub fn bind(path: &Path, mode: FilePermission) -> IoResult<UnixAcceptor> {
+        unsafe {
+            let mut addr: libc::sockaddr_un = mem::init();
+            addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+            let bytes = path.as_vec();
+            if bytes.len() >= addr.sun_path.len() {
+                return Err(IoError { kind: OtherIoError, desc: "socket path too long", detail: None });
+            }
+            ptr::copy_nonoverlapping_memory(addr.sun_path.as_mut_ptr(), bytes.as_ptr() as *libc::c_char,
+                                            bytes.len());
+
+            let fd = libc::socket(libc::AF_UNIX, libc::SOCK_STREAM, 0);
+            if fd < 0 {
+                return Err(IoError::last_error());
+            }
+            // Closes the socket if we return early
+            let socket = Fd { fd: fd };
+
+            // Only a socket left by an earlier run may be replaced, not whatever file the
+            // path names by mistake
+            let mut st: libc::stat = mem::init();
+            if path.with_c_str(|p| libc::lstat(p, &mut st)) == 0 {
+                if st.st_mode as u32 & ffi::S_IFMT != ffi::S_IFSOCK {
+                    return Err(IoError { kind: PathAlreadyExists, desc: "path exists and is not a socket",
+                                         detail: Some(path.display().to_str()) });
+                }
+                try!(fs::unlink(path));
+            }
+            if libc::bind(fd, &addr as *libc::sockaddr_un as *libc::sockaddr,
+                          mem::size_of::<libc::sockaddr_un>() as libc::socklen_t) < 0 {
+                return Err(IoError::last_error());
+            }
+            // Now also removes the socket file if we return early
+            let acceptor = UnixAcceptor { fd: socket, path: path.clone() };
+            // Nobody can connect before listen, so the permissions are in place first
+            try!(fs::chmod(path, mode));
+            if libc::listen(fd, 128) < 0 {
+                return Err(IoError::last_error());
+            }
+            Ok(acceptor)
+        }
+    }
+
+    pub fn accept(&mut self) -> IoResult<UnixStream> {
+        let fd = unsafe { libc::accept(self.fd.fd, ptr::mut_null(), ptr::mut_null()) };
+        if fd < 0 {
+            return Err(IoError::last_error());
+        }
+        Ok(UnixStream { fd: Arc::new(Fd { fd: fd }) })
+    }
+}
+
+impl Drop for UnixAcceptor {
+    fn drop(&mut self) {
+        let _ = fs::unlink(&self.path);
+    }
+}
+
+#[test]
+fn test_peer_uid() {
+    use std::io::net::unix;
+    use std::io::{TempDir, UserRWX};
+
+    let dir = TempDir::new("unixsocket").unwrap();
+    let path = dir.path().join("socket");
+    let mut acceptor = UnixAcceptor::bind(&path, UserRWX).unwrap();
+    let client_path = path.clone();
+    spawn(proc() {
+        let mut s = unix::UnixStream::connect(&client_path).unwrap();
+        s.write(bytes!("hello")).unwrap();
+    });
+
+    let mut stream = acceptor.accept().unwrap();
+    assert_eq!(stream.peer_uid().unwrap(), unsafe { ffi::getuid() } as u32);
+    assert_eq!(stream.read_bytes(5).unwrap().as_slice(), bytes!("hello"));
+}
+
+#[test]
+fn test_bind_replaces_only_sockets() {
+    use std::io::{File, TempDir, UserRWX};
+
+    let dir = TempDir::new("unixsocket").unwrap();
+    let path = dir.path().join("socket");
+    // A socket file left behind, as if by a crash, is replaced
+    unsafe {
+        let fd = libc::socket(libc::AF_UNIX, libc::SOCK_STREAM, 0);
+        let mut addr: libc::sockaddr_un = mem::init();
+        addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+        let bytes = path.as_vec();
+        ptr::copy_nonoverlapping_memory(addr.sun_path.as_mut_ptr(), bytes.as_ptr() as *libc::c_char, bytes.len());
+        assert_eq!(libc::bind(fd, &addr as *libc::sockaddr_un as *libc::sockaddr,
+                              mem::size_of::<libc::sockaddr_un>() as libc::socklen_t), 0);
+        libc::close(fd);
+    }
+    assert!(UnixAcceptor::bind(&path, UserRWX).is_ok());
+
+    let file = dir.path().join("file");
+    File::create(&file).write(bytes!("keep")).unwrap();
+    assert!(UnixAcceptor::bind(&file, UserRWX).is_err());
+    assert_eq!(File::open(&file).read_to_end().unwrap().as_slice(), bytes!("keep"));
+}
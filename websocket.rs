@@ -0,0 +1,275 @@
+// Server side of the WebSocket protocol (RFC 6455), enough to carry remote packets as
+// binary messages
+
+use std::io::{IoResult, IoError, OtherIoError, InvalidInput};
+use std::ascii::StrAsciiExt;
+use std::str;
+use serialize::base64::{ToBase64, STANDARD};
+use crypto;
+
+static GUID: &'static str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+// Requests with more lines than this, or longer lines, are refused
+static MAX_HEADER_LINES: uint = 100;
+static MAX_LINE_LENGTH: uint = 8192;
+
+static OP_CONTINUATION: u8 = 0x0;
+pub static OP_TEXT: u8 = 0x1;
+pub static OP_BINARY: u8 = 0x2;
+static OP_CLOSE: u8 = 0x8;
+static OP_PING: u8 = 0x9;
+static OP_PONG: u8 = 0xa;
+
+fn ws_error(desc: &'static str, detail: Option<~str>) -> IoError {
+    IoError { kind: OtherIoError, desc: desc, detail: detail }
+}
+
+fn accept_key(key: &str) -> ~str {
+    crypto::sha1(format!("{}{}", key, GUID).as_bytes()).to_base64(STANDARD)
+}
+
+// Reads a request line, one byte at a time so no frame data is consumed
+fn read_line<R: Reader>(r: &mut R) -> IoResult<~str> {
+    let mut line = ~[];
+    loop {
+        let b = try!(r.read_byte());
+        if b == '\n' as u8 {
+            break;
+        }
+        if line.len() >= MAX_LINE_LENGTH {
+            return Err(ws_error("request line too long", None));
+        }
+        line.push(b);
+    }
+    match str::from_utf8_owned(line) {
+        Some(line) => Ok(line),
+        None => Err(ws_error("request line is not UTF-8", None))
+    }
+}
+
+/// Reads the client's HTTP upgrade request and switches the connection to WebSocket.
+/// Requests from browsers, which send an Origin, are refused unless it is one of
+/// `origins`, so other sites can't connect with their visitors' browsers.
+pub fn accept_handshake<S: Reader + Writer>(stream: &mut S, origins: &[~str]) -> IoResult<()> {
+    let mut key = None;
+    let mut upgrade = false;
+    let mut version = None;
+    let mut origin = None;
+    let request = try!(read_line(stream));
+    if !request.starts_with("GET ") {
+        return Err(ws_error("not a WebSocket request", Some(request.trim().to_owned())));
+    }
+    let mut complete = false;
+    for _ in range(0, MAX_HEADER_LINES) {
+        let line = try!(read_line(stream));
+        let line = line.trim();
+        if line.is_empty() {
+            complete = true;
+            break;
+        }
+        match line.find(':') {
+            Some(i) => {
+                let value = line.slice_from(i + 1).trim();
+                match line.slice_to(i).trim().to_ascii_lower().as_slice() {
+                    "upgrade" => upgrade = value.to_ascii_lower().contains("websocket"),
+                    "sec-websocket-key" => key = Some(value.to_owned()),
+                    "sec-websocket-version" => version = Some(value.to_owned()),
+                    "origin" => origin = Some(value.to_owned()),
+                    _ => ()
+                }
+            },
+            None => ()
+        }
+    }
+    if !complete {
+        return Err(ws_error("too many request lines", None));
+    }
+
+    match origin {
+        Some(ref origin) if !origins.iter().any(|o| o.eq_ignore_ascii_case(*origin)) => {
+            try!(stream.write(bytes!("HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n")));
+            return Err(ws_error("WebSocket origin not allowed", Some(origin.clone())));
+        },
+        _ => ()
+    }
+    if version.as_ref().map_or(true, |v| v.as_slice() != "13") {
+        try!(stream.write(bytes!("HTTP/1.1 426 Upgrade Required\r\nSec-WebSocket-Version: 13\r\n\
+                                  Content-Length: 0\r\n\r\n")));
+        return Err(ws_error("unsupported WebSocket version", version));
+    }
+    match key {
+        Some(ref key) if upgrade => {
+            let response = format!("HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\
+                                    Connection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n", accept_key(*key));
+            stream.write(response.as_bytes())
+        },
+        _ => {
+            try!(stream.write(bytes!("HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")));
+            Err(ws_error("invalid WebSocket upgrade request", None))
+        }
+    }
+}
+
+/// Builds an unmasked frame, as sent by servers.
+pub fn frame(opcode: u8, payload: &[u8]) -> ~[u8] {
+    let mut out = ~[0x80 | opcode];
+    let len = payload.len();
+    if len < 126 {
+        out.push(len as u8);
+    } else if len <= 0xffff {
+        out.push(126);
+        out.push_all([(len >> 8) as u8, len as u8]);
+    } else {
+        out.push(127);
+        for i in range(0u, 8).rev() {
+            out.push((len as u64 >> (i * 8)) as u8);
+        }
+    }
+    out.push_all(payload);
+    out
+}
+
+/// Reads the next data message, joining fragments. Pings and closes are answered by
+/// passing the reply frame to `reply`. Returns None once the client closes the
+/// connection. Messages larger than `max_size` fail with `InvalidInput`.
+pub fn read_message<R: Reader>(r: &mut R, max_size: uint, reply: |~[u8]|) -> IoResult<Option<~[u8]>> {
+    let mut message = ~[];
+    loop {
+        let b0 = try!(r.read_byte());
+        let b1 = try!(r.read_byte());
+        let fin = b0 & 0x80 != 0;
+        let opcode = b0 & 0x0f;
+        let len = match b1 & 0x7f {
+            126 => try!(r.read_be_u16()) as u64,
+            127 => try!(r.read_be_u64()),
+            n   => n as u64
+        };
+        // Clients have to mask everything they send
+        if b1 & 0x80 == 0 {
+            return Err(ws_error("unmasked WebSocket frame", None));
+        }
+        if len >> 63 != 0 {
+            return Err(ws_error("invalid WebSocket frame length", None));
+        }
+        if len > (max_size - message.len()) as u64 {
+            return Err(IoError { kind: InvalidInput, desc: "packet too large", detail: Some(format!("{} bytes", len)) });
+        }
+        let mask = try!(r.read_bytes(4));
+        let mut payload = try!(r.read_bytes(len as uint));
+        for (i, b) in payload.mut_iter().enumerate() {
+            *b ^= mask[i % 4];
+        }
+
+        match opcode {
+            OP_CONTINUATION | OP_TEXT | OP_BINARY => {
+                message.push_all_move(payload);
+                if fin {
+                    return Ok(Some(message));
+                }
+            },
+            OP_PING => reply(frame(OP_PONG, payload)),
+            OP_PONG => (),
+            OP_CLOSE => {
+                reply(frame(OP_CLOSE, payload));
+                return Ok(None);
+            },
+            _ => return Err(ws_error("unknown WebSocket opcode", Some(opcode.to_str())))
+        }
+    }
+}
+
+#[cfg(test)]
+fn client_frame(opcode: u8, fin: bool, payload: &[u8]) -> ~[u8] {
+    let mask = [0x37u8, 0xfa, 0x21, 0x3d];
+    let mut out = ~[if fin { 0x80 | opcode } else { opcode }, 0x80 | payload.len() as u8];
+    out.push_all(mask);
+    for (i, &b) in payload.iter().enumerate() {
+        out.push(b ^ mask[i % 4]);
+    }
+    out
+}
+
+#[test]
+fn test_accept_key() {
+    // RFC 6455 section 1.3
+    assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), ~"s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+}
+
+#[test]
+fn test_read_message() {
+    use std::io::MemReader;
+
+    let mut data = client_frame(OP_BINARY, false, bytes!("Hel"));
+    data.push_all(client_frame(OP_PING, true, bytes!("x")));
+    data.push_all(client_frame(OP_CONTINUATION, true, bytes!("lo")));
+    data.push_all(client_frame(OP_CLOSE, true, []));
+    let mut r = MemReader::new(data);
+
+    let mut replies = ~[];
+    assert_eq!(read_message(&mut r, 100, |f| replies.push(f)).unwrap(), Some(bytes!("Hello").to_owned()));
+    assert_eq!(replies, ~[frame(OP_PONG, bytes!("x"))]);
+    assert_eq!(read_message(&mut r, 100, |_| ()).unwrap(), None);
+    assert!(read_message(&mut MemReader::new(client_frame(OP_BINARY, true, bytes!("Hello"))), 4, |_| ()).is_err());
+    // A 64-bit length with the top bit set
+    let huge = ~[0x82u8, 0xff, 0x80, 0, 0, 0, 0, 0, 0, 5];
+    assert!(read_message(&mut MemReader::new(huge), 100, |_| ()).is_err());
+}
+
+// A request to read from, recording the response
+#[cfg(test)]
+struct TestStream {
+    input: ::std::io::MemReader,
+    output: ::std::io::MemWriter
+}
+
+#[cfg(test)]
+impl Reader for TestStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> { self.input.read(buf) }
+}
+
+#[cfg(test)]
+impl Writer for TestStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> { self.output.write(buf) }
+}
+
+#[cfg(test)]
+fn handshake(request: ~str, origins: &[~str]) -> (IoResult<()>, ~str) {
+    let mut stream = TestStream { input: ::std::io::MemReader::new(request.as_bytes().to_owned()),
+                                  output: ::std::io::MemWriter::new() };
+    let result = accept_handshake(&mut stream, origins);
+    (result, str::from_utf8(stream.output.get_ref()).unwrap().to_owned())
+}
+
+#[test]
+fn test_accept_handshake() {
+    let request = "GET / HTTP/1.1\r\nHost: example.com\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+                   Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n";
+    let origins = ~[~"https://example.com"];
+    let origins = origins.as_slice();
+
+    let (result, response) = handshake(request.to_owned() + "\r\n", origins);
+    assert!(result.is_ok());
+    assert!(response.starts_with("HTTP/1.1 101 "));
+    assert!(response.contains("Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n"));
+
+    let (result, _) = handshake(request.to_owned() + "Origin: https://EXAMPLE.com\r\n\r\n", origins);
+    assert!(result.is_ok());
+    let (result, response) = handshake(request.to_owned() + "Origin: https://evil.example\r\n\r\n", origins);
+    assert!(result.is_err());
+    assert!(response.starts_with("HTTP/1.1 403 "));
+
+    let (result, response) = handshake(request.replace("Version: 13", "Version: 8") + "\r\n", origins);
+    assert!(result.is_err());
+    assert!(response.starts_with("HTTP/1.1 426 "));
+
+    let long = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_LENGTH));
+    let (result, _) = handshake(long, origins);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_frame() {
+    assert_eq!(frame(OP_BINARY, bytes!("hi")), ~[0x82u8, 2, 'h' as u8, 'i' as u8]);
+    let long = frame(OP_BINARY, [0u8, ..300]);
+    assert_eq!(long.slice_to(4), &[0x82u8, 126, 1, 44]);
+    assert_eq!(long.len(), 304);
+}